@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use reiterate::CopyReiterate;
+use std::hint::black_box;
+
+const LEN: u64 = 10_000;
+
+fn cached_collect(c: &mut Criterion) {
+    let reiterate = CopyReiterate::new(0..LEN);
+    reiterate.into_iter().for_each(drop);
+    c.bench_function("copy reiterate collect", |b| {
+        b.iter(|| black_box(&reiterate).into_iter().collect::<Vec<_>>())
+    });
+    c.bench_function("copy reiterate collect_vec", |b| {
+        b.iter(|| black_box(&reiterate).into_iter().collect_vec())
+    });
+
+    let slice: Vec<_> = (0..LEN).collect();
+    c.bench_function("slice to_vec", |b| {
+        b.iter(|| black_box(&slice[..]).to_vec())
+    });
+}
+
+criterion_group!(benches, cached_collect);
+criterion_main!(benches);
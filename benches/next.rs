@@ -0,0 +1,80 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use reiterate::{CopyReiterate, Reiterate, ReiterateMut};
+use std::hint::black_box;
+
+const LEN: u64 = 10_000;
+
+fn first_pass(c: &mut Criterion) {
+    let items: Vec<_> = (0..LEN).map(Box::new).collect();
+    c.bench_function("reiterate first pass", |b| {
+        b.iter(|| {
+            let reiterate = Reiterate::new(items.clone());
+            reiterate.into_iter().copied().sum::<u64>()
+        })
+    });
+
+    let items: Vec<_> = (0..LEN).collect();
+    c.bench_function("copy reiterate first pass", |b| {
+        b.iter(|| {
+            let reiterate = CopyReiterate::new(items.clone());
+            reiterate.into_iter().sum::<u64>()
+        })
+    });
+}
+
+fn cached_replay(c: &mut Criterion) {
+    let reiterate = Reiterate::new((0..LEN).map(Box::new));
+    reiterate.into_iter().for_each(drop);
+    c.bench_function("reiterate cached replay", |b| {
+        b.iter(|| black_box(&reiterate).into_iter().sum::<u64>())
+    });
+
+    let reiterate = CopyReiterate::new(0..LEN);
+    reiterate.into_iter().for_each(drop);
+    c.bench_function("copy reiterate cached replay", |b| {
+        b.iter(|| black_box(&reiterate).into_iter().sum::<u64>())
+    });
+}
+
+fn single_pass(c: &mut Criterion) {
+    let items: Vec<_> = (0..LEN).collect();
+    c.bench_function("raw iterator single pass", |b| {
+        b.iter(|| items.clone().into_iter().sum::<u64>())
+    });
+
+    c.bench_function("lazy caching copy reiterate single pass", |b| {
+        b.iter(|| {
+            let reiterate = CopyReiterate::lazy_caching(items.clone());
+            reiterate.into_iter().sum::<u64>()
+        })
+    });
+}
+
+fn fill_then_replay(c: &mut Criterion) {
+    // References, so that allocating and dropping the items doesn't dominate
+    let values: Vec<u64> = (0..LEN).collect();
+    c.bench_function("reiterate fill then replay", |b| {
+        b.iter(|| {
+            let reiterate = Reiterate::new(values.iter());
+            reiterate.into_iter().for_each(drop);
+            reiterate.into_iter().sum::<u64>()
+        })
+    });
+
+    c.bench_function("reiterate mut fill then replay", |b| {
+        b.iter(|| {
+            let mut reiterate = ReiterateMut::new(values.iter());
+            reiterate.prime(usize::MAX);
+            reiterate.cached().sum::<u64>()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    first_pass,
+    cached_replay,
+    single_pass,
+    fill_then_replay
+);
+criterion_main!(benches);
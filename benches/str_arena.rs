@@ -0,0 +1,48 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use reiterate::{Reiterate, StrArenaReiterate};
+use std::hint::black_box;
+
+const LEN: usize = 100_000;
+
+fn words() -> impl Iterator<Item = String> {
+    (0..LEN).map(|i| format!("word{}", i % 1000))
+}
+
+fn fill(c: &mut Criterion) {
+    c.bench_function("reiterate string fill", |b| {
+        b.iter(|| {
+            let reiterate = Reiterate::new(words());
+            reiterate.into_iter().for_each(drop);
+            black_box(reiterate)
+        })
+    });
+    c.bench_function("str arena fill", |b| {
+        b.iter(|| {
+            let reiterate = StrArenaReiterate::new(words());
+            reiterate.into_iter().for_each(drop);
+            black_box(reiterate)
+        })
+    });
+}
+
+fn replay(c: &mut Criterion) {
+    let reiterate = Reiterate::new(words());
+    reiterate.into_iter().for_each(drop);
+    c.bench_function("reiterate string replay", |b| {
+        b.iter(|| {
+            black_box(&reiterate)
+                .into_iter()
+                .map(str::len)
+                .sum::<usize>()
+        })
+    });
+
+    let arena = StrArenaReiterate::new(words());
+    arena.into_iter().for_each(drop);
+    c.bench_function("str arena replay", |b| {
+        b.iter(|| black_box(&arena).into_iter().map(str::len).sum::<usize>())
+    });
+}
+
+criterion_group!(benches, fill, replay);
+criterion_main!(benches);
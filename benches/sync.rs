@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use reiterate::sync::{SyncCopyReiterate, SyncReiterate};
+use std::thread;
+
+const LEN: u64 = 10_000;
+const THREADS: usize = 16;
+
+fn contended_replay(c: &mut Criterion) {
+    let reiterate = SyncReiterate::new((0..LEN).map(Box::new));
+    reiterate.into_iter().for_each(drop);
+    c.bench_function("sync reiterate contended replay", |b| {
+        b.iter(|| {
+            thread::scope(|s| {
+                for _ in 0..THREADS {
+                    s.spawn(|| reiterate.into_iter().sum::<u64>());
+                }
+            })
+        })
+    });
+
+    let reiterate = SyncCopyReiterate::new(0..LEN);
+    reiterate.into_iter().for_each(drop);
+    c.bench_function("sync copy reiterate contended replay", |b| {
+        b.iter(|| {
+            thread::scope(|s| {
+                for _ in 0..THREADS {
+                    s.spawn(|| reiterate.into_iter().sum::<u64>());
+                }
+            })
+        })
+    });
+}
+
+// Every thread races for the lock at the end of the cache, so this is where the lock
+// backend shows: compare runs with and without `--features parking_lot`
+fn contended_fill(c: &mut Criterion) {
+    c.bench_function("sync reiterate contended fill", |b| {
+        b.iter(|| {
+            let reiterate = SyncReiterate::new((0..LEN).map(Box::new));
+            thread::scope(|s| {
+                for _ in 0..THREADS {
+                    s.spawn(|| reiterate.into_iter().sum::<u64>());
+                }
+            })
+        })
+    });
+
+    c.bench_function("sync copy reiterate contended fill", |b| {
+        b.iter(|| {
+            let reiterate = SyncCopyReiterate::new(0..LEN);
+            thread::scope(|s| {
+                for _ in 0..THREADS {
+                    s.spawn(|| reiterate.into_iter().sum::<u64>());
+                }
+            })
+        })
+    });
+}
+
+criterion_group!(benches, contended_replay, contended_fill);
+criterion_main!(benches);
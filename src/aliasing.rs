@@ -0,0 +1,94 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::collections::HashMap;
+use std::mem;
+
+/// The cache index of each cached item, by address, for `Reiterate::detect_aliasing()`
+#[derive(Default)]
+pub(crate) struct AliasDetector {
+    indices: HashMap<usize, usize>,
+}
+
+impl AliasDetector {
+    /// Record that the item at `idx` of the cache points to `target`, reporting it if
+    /// another item already does
+    pub(crate) fn record<T: ?Sized>(&mut self, target: &T, idx: usize) {
+        // Zero-sized targets, like those of empty strings, can share any address
+        if mem::size_of_val(target) == 0 {
+            return;
+        }
+        let addr = target as *const T as *const u8 as usize;
+        let first = *self.indices.entry(addr).or_insert(idx);
+        if first != idx {
+            let msg = format!(
+                "reiterate: the items at positions {} and {} point to the same target, \
+                 so references to them can't tell the positions apart",
+                first, idx
+            );
+            if cfg!(debug_assertions) {
+                panic!("{}", msg);
+            }
+            eprintln!("{}", msg);
+        }
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Check that no two cached items point to the same target, as clones of an `Rc` do.
+    ///
+    /// Such items break anything that tells positions apart by reference, like
+    /// `index_of()`. Once this is turned on, each item is checked as it gets cached, and
+    /// an aliased one is reported along with both of its positions: by panicking with
+    /// debug assertions on, and on standard error otherwise. The item is cached either
+    /// way, so the stream stays usable. Items with zero-sized targets aren't checked.
+    ///
+    /// This costs a hash set entry per cached item; a `Reiterate` that doesn't turn it
+    /// on pays nothing.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::panic::{catch_unwind, AssertUnwindSafe};
+    /// use std::rc::Rc;
+    ///
+    /// let shared = Rc::new("shared");
+    /// let items = vec![shared.clone(), Rc::new("own"), shared];
+    /// let reiterate = Reiterate::new(items).detect_aliasing();
+    /// let result = catch_unwind(AssertUnwindSafe(|| reiterate.into_iter().count()));
+    /// if cfg!(debug_assertions) {
+    ///     let msg = result.unwrap_err().downcast::<String>().unwrap();
+    ///     assert!(msg.contains("positions 0 and 2"));
+    /// }
+    /// assert_eq!(reiterate.into_iter().count(), 3);
+    ///
+    /// // distinct allocations are fine
+    /// let boxed = Reiterate::new((0..100).map(Box::new)).detect_aliasing();
+    /// assert_eq!(boxed.into_iter().count(), 100);
+    /// ```
+    pub fn detect_aliasing(mut self) -> Self
+    where
+        I: Sized,
+    {
+        let mut detector = AliasDetector::default();
+        for (idx, item) in self.cache.as_mut().iter().enumerate() {
+            detector.record(&**item, idx);
+        }
+        self.aliasing = Some(detector.into());
+        self
+    }
+
+    /// Record the addresses of the cached items again after some were dropped, if
+    /// `detect_aliasing()` is on
+    pub(crate) fn reset_aliasing(&mut self) {
+        if let Some(aliasing) = self.aliasing.as_mut() {
+            let detector = aliasing.get_mut();
+            detector.indices.clear();
+            for (idx, item) in self.cache.as_mut().iter().enumerate() {
+                detector.record(&**item, idx);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::fill::Fill;
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Add an item to the end of the cache, for every handle to see after the items
+    /// cached so far.
+    ///
+    /// The item goes right after the last item pulled, so the underlying iterator carries
+    /// on after it; `exhaust()` first for an item that comes after the whole stream, like
+    /// a sentinel. This can be called during a pull, and items pushed past the logical
+    /// length set by `truncate()` stay hidden like pulled ones.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let rows = Reiterate::new(vec!["a,1".to_string(), "b,2".to_string()]);
+    /// let mut iter = rows.into_iter();
+    /// assert_eq!(iter.next(), Some("a,1"));
+    ///
+    /// rows.exhaust();
+    /// rows.push("END".to_string());
+    /// assert_eq!(iter.collect::<Vec<_>>(), ["b,2", "END"]);
+    /// assert_eq!(rows.into_iter().last(), Some("END"));
+    ///
+    /// // pushed before the rest of the stream is pulled
+    /// let numbers = Reiterate::new((1..=3).map(Box::new));
+    /// numbers.push(Box::new(0));
+    /// assert_eq!(numbers.into_iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// ```
+    pub fn push(&self, item: I::Item) {
+        let mut cache = self;
+        cache.store_item(item);
+    }
+}
+
+/// Push every item, like calling `push()` for each of them.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let mut log = Reiterate::new(vec![Box::new(1), Box::new(2)]);
+/// log.exhaust();
+/// log.extend(vec![Box::new(-1), Box::new(-2)]);
+/// assert_eq!(log.into_iter().copied().collect::<Vec<_>>(), [1, 2, -1, -2]);
+/// ```
+impl<I> Extend<I::Item> for Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    fn extend<T: IntoIterator<Item = I::Item>>(&mut self, items: T) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Add an item to the end of the cache, for every handle to see after the items
+    /// cached so far.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::push()`. Like pulling, it panics if
+    /// called during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let bytes = CopyReiterate::new(b"abc".iter().copied());
+    /// bytes.exhaust();
+    /// bytes.push(0);
+    /// assert_eq!(bytes.into_iter().collect_vec(), b"abc\0");
+    /// ```
+    pub fn push(&self, item: I::Item) {
+        self.stop_streaming();
+        self.inner.borrow_mut().cache.push(item);
+    }
+}
+
+/// Push every item, like calling `push()` for each of them.
+impl<I> Extend<I::Item> for CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    fn extend<T: IntoIterator<Item = I::Item>>(&mut self, items: T) {
+        self.stop_streaming();
+        self.inner.get_mut().cache.extend(items);
+    }
+}
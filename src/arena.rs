@@ -0,0 +1,186 @@
+use crate::{poison_on_unwind, Reiterable};
+
+use std::cell::{Cell, RefCell};
+use std::ptr::NonNull;
+
+// The capacity of the first chunk items are moved into, doubled for each following one
+const FIRST_CHUNK: usize = 16;
+
+/// An adaptor around an iterator of any item type that can produce multiple iterators
+/// sharing an underlying cache, yielding references to the cached items.
+///
+/// `Reiterate` needs StableDeref items so that its cache can grow without moving them.
+/// This moves each item into a chunk allocated up front, that is never reallocated, so
+/// items with inline data, like large arrays, can be replayed by reference too without
+/// being boxed one by one.
+///
+/// ```rust
+/// use reiterate::ArenaReiterate;
+///
+/// let frames = ArenaReiterate::new((0..100u8).map(|i| [i; 4096]));
+/// let mut iter = frames.into_iter();
+/// let first: &[u8; 4096] = iter.next().unwrap();
+/// // references stay valid while the cache grows
+/// assert_eq!(iter.map(|frame| frame[0] as u32).sum::<u32>(), 4950);
+/// assert_eq!(first[4095], 0);
+/// assert_eq!(frames.into_iter().nth(42).map(|frame| frame[7]), Some(42));
+/// assert_eq!(frames.chunk_count(), 3);
+/// ```
+pub struct ArenaReiterate<I>
+where
+    I: Iterator,
+{
+    poisoned: Cell<bool>,
+    arena: RefCell<Arena<I::Item>>,
+    iter: RefCell<I>,
+}
+
+struct Arena<T> {
+    // Chunks are never pushed to past their capacity, so their items are never moved,
+    // and are only dropped with the arena
+    chunks: Vec<Vec<T>>,
+    // Each cached item, in the chunk it was moved into
+    items: Vec<NonNull<T>>,
+}
+
+impl<T> Arena<T> {
+    fn store(&mut self, val: T) -> NonNull<T> {
+        let chunk = match self.chunks.last_mut() {
+            Some(chunk) if chunk.len() < chunk.capacity() => chunk,
+            _ => {
+                let capacity = FIRST_CHUNK << self.chunks.len().min(16);
+                self.chunks.push(Vec::with_capacity(capacity));
+                self.chunks.last_mut().unwrap()
+            }
+        };
+        chunk.push(val);
+        let item = NonNull::from(chunk.last_mut().unwrap());
+        self.items.push(item);
+        item
+    }
+}
+
+// The arena owns its items, like a `Vec<Vec<T>>` would
+unsafe impl<I> Send for ArenaReiterate<I>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+{
+}
+
+impl<I> ArenaReiterate<I>
+where
+    I: Iterator,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        ArenaReiterate {
+            poisoned: Cell::new(false),
+            arena: RefCell::new(Arena {
+                chunks: Vec::new(),
+                items: Vec::new(),
+            }),
+            iter: RefCell::new(iter.into_iter()),
+        }
+    }
+
+    /// Whether the underlying iterator has panicked while an item was being pulled from
+    /// it, like for `CopyReiterate::is_poisoned()`
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.get()
+    }
+
+    /// The number of items pulled from the underlying iterator so far
+    pub fn cached_len(&self) -> usize {
+        self.arena.borrow().items.len()
+    }
+
+    /// The number of chunks holding the cached items
+    pub fn chunk_count(&self) -> usize {
+        self.arena.borrow().chunks.len()
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<&I::Item> {
+        let cached = self.arena.borrow().items.get(idx).copied();
+        let item = match cached {
+            Some(item) => item,
+            None => {
+                // The arena isn't borrowed while pulling, so the source can read it
+                let val = poison_on_unwind(&self.poisoned, || self.iter.borrow_mut().next())?;
+                self.arena.borrow_mut().store(val)
+            }
+        };
+        // SAFETY: the item is never moved, written to or dropped before the arena is
+        // dropped, which borrowing `self` prevents
+        Some(unsafe { item.as_ref() })
+    }
+}
+
+impl<'a, I> IntoIterator for &'a ArenaReiterate<I>
+where
+    I: Iterator,
+{
+    type IntoIter = ArenaReiterator<'a, I>;
+    type Item = &'a I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArenaReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I> Reiterable for ArenaReiterate<I>
+where
+    I: Iterator,
+{
+    type Item<'a>
+        = &'a I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = ArenaReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> ArenaReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&ArenaReiterate` instance
+pub struct ArenaReiterator<'a, I>
+where
+    I: Iterator,
+{
+    iterable: &'a ArenaReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for ArenaReiterator<'a, I>
+where
+    I: Iterator,
+{
+    type Item = &'a I::Item;
+
+    fn next(&mut self) -> Option<&'a I::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a I::Item> {
+        // Skip over cached items without going through them
+        let skip = n.min(self.iterable.cached_len().saturating_sub(self.curr));
+        self.curr += skip;
+        for _ in skip..n {
+            self.next()?;
+        }
+        self.next()
+    }
+}
@@ -0,0 +1,429 @@
+use crate::lock::Mutex;
+use crate::ReiterableItem;
+
+use elsa::sync::FrozenVec;
+use futures_core::Stream;
+use std::future;
+#[cfg(feature = "tokio")]
+use std::io;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, Lines};
+
+/// An adaptor around a `Stream` that can produce multiple asynchronous iterators
+/// sharing an underlying cache.
+///
+/// Like `Reiterate`, the underlying stream must produce heap-allocated StableDeref values.
+/// Handles waiting at the end of the cache are all woken when the stream makes progress,
+/// and every item is pulled exactly once no matter how many handles are waiting for it.
+///
+/// This needs the `futures` feature. The `tokio` feature also enables `from_async_lines()`.
+pub struct AsyncReiterate<S>
+where
+    S: Stream,
+{
+    source: Mutex<Source<S>>,
+    cache: FrozenVec<S::Item>,
+    wakers: Arc<WakerSet>,
+}
+
+/// The underlying stream, which must not be polled again once it has ended
+struct Source<S> {
+    stream: Pin<Box<S>>,
+    ended: bool,
+}
+
+/// The wakers of every handle waiting on the underlying stream
+#[derive(Default)]
+struct WakerSet {
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl WakerSet {
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.wakers.lock();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+}
+
+impl Wake for WakerSet {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let wakers = std::mem::take(&mut *self.wakers.lock());
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<S> AsyncReiterate<S>
+where
+    S: Stream,
+    S::Item: ReiterableItem,
+{
+    pub fn new(stream: S) -> Self {
+        AsyncReiterate {
+            source: Mutex::new(Source {
+                stream: Box::pin(stream),
+                ended: false,
+            }),
+            cache: FrozenVec::new(),
+            wakers: Arc::default(),
+        }
+    }
+
+    /// Get an asynchronous iterator over the items, reusing cached values.
+    ///
+    /// Once the underlying stream has ended, handles that reach the end of the cache
+    /// return `None` without polling it again.
+    ///
+    /// ```rust
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use reiterate::AsyncReiterate;
+    ///
+    /// // panics if polled after it ended
+    /// let countdown = stream::unfold(3, |n| async move { (n > 0).then(|| (Box::new(n), n - 1)) });
+    /// let reiterate = AsyncReiterate::new(countdown);
+    /// block_on(async {
+    ///     for _ in 0..2 {
+    ///         let mut iter = reiterate.iter();
+    ///         let mut seen = vec![];
+    ///         while let Some(&n) = iter.next().await {
+    ///             seen.push(n);
+    ///         }
+    ///         assert_eq!(seen, [3, 2, 1]);
+    ///     }
+    /// });
+    /// ```
+    pub fn iter(&self) -> AsyncReiterator<'_, S> {
+        AsyncReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+
+    /// Get the item at `idx`, polling the underlying stream if the handle asking
+    /// for it has reached the end of the cache
+    fn poll_get(
+        &self,
+        idx: usize,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<&<S::Item as Deref>::Target>> {
+        if let Some(val) = self.cache.get(idx) {
+            return Poll::Ready(Some(val));
+        }
+        let mut source = self.source.lock();
+        // Another handle may have pulled while we were waiting for the lock
+        if let Some(val) = self.cache.get(idx) {
+            return Poll::Ready(Some(val));
+        }
+        if source.ended {
+            return Poll::Ready(None);
+        }
+        // The stream only remembers the last waker it was polled with, so poll it with
+        // one that wakes every waiting handle
+        self.wakers.register(cx.waker());
+        let waker = Waker::from(self.wakers.clone());
+        let mut shared = Context::from_waker(&waker);
+        match source.stream.as_mut().poll_next(&mut shared) {
+            Poll::Ready(Some(val)) => {
+                // The item is cached before this poll returns, so a handle whose future
+                // is dropped from here on can't take it along
+                let val = self.cache.push_get(val);
+                // Handles waiting behind this one can go on
+                self.wakers.wake_by_ref();
+                Poll::Ready(Some(val))
+            }
+            Poll::Ready(None) => {
+                source.ended = true;
+                self.wakers.wake_by_ref();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// An individual asynchronous iterator, produced by calling `.iter()` on an `AsyncReiterate`
+pub struct AsyncReiterator<'a, S>
+where
+    S: Stream,
+{
+    iterable: &'a AsyncReiterate<S>,
+    curr: usize,
+}
+
+impl<'a, S> AsyncReiterator<'a, S>
+where
+    S: Stream,
+    S::Item: ReiterableItem,
+{
+    /// Get the next item, waiting for the underlying stream if needed.
+    ///
+    /// This is cancellation safe: every item the underlying stream yields goes into the
+    /// cache within the same poll, and the handle only moves past it when the future
+    /// completes. Dropping the future at any point loses nothing, neither for this handle
+    /// nor for the others.
+    ///
+    /// ```rust
+    /// use futures_core::Stream;
+    /// use reiterate::AsyncReiterate;
+    /// use std::cell::Cell;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, Wake, Waker};
+    ///
+    /// // Yields `0..4`, but is only ready every other poll
+    /// struct Flaky<'a> {
+    ///     next: u32,
+    ///     ready: bool,
+    ///     pulls: &'a Cell<u32>,
+    /// }
+    ///
+    /// impl Stream for Flaky<'_> {
+    ///     type Item = Box<u32>;
+    ///
+    ///     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Box<u32>>> {
+    ///         self.ready = !self.ready;
+    ///         if !self.ready {
+    ///             cx.waker().wake_by_ref();
+    ///             return Poll::Pending;
+    ///         }
+    ///         if self.next == 4 {
+    ///             return Poll::Ready(None);
+    ///         }
+    ///         self.pulls.set(self.pulls.get() + 1);
+    ///         self.next += 1;
+    ///         Poll::Ready(Some(Box::new(self.next - 1)))
+    ///     }
+    /// }
+    ///
+    /// struct Noop;
+    ///
+    /// impl Wake for Noop {
+    ///     fn wake(self: Arc<Self>) {}
+    /// }
+    ///
+    /// let waker = Waker::from(Arc::new(Noop));
+    /// let mut cx = Context::from_waker(&waker);
+    /// let mut block_on = |mut fut: Pin<&mut dyn Future<Output = Option<&u32>>>| {
+    ///     loop {
+    ///         if let Poll::Ready(val) = fut.as_mut().poll(&mut cx) {
+    ///             return val.copied();
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// // Drop the future of the first handle after each possible number of polls
+    /// for drop_at in 0..12 {
+    ///     let pulls = Cell::new(0);
+    ///     let reiterate = AsyncReiterate::new(Flaky { next: 0, ready: false, pulls: &pulls });
+    ///     let mut first = reiterate.iter();
+    ///     let mut seen = vec![];
+    ///     let mut polls = 0;
+    ///     'polling: loop {
+    ///         let mut fut = Box::pin(first.next());
+    ///         loop {
+    ///             if polls == drop_at {
+    ///                 break 'polling;
+    ///             }
+    ///             polls += 1;
+    ///             match fut.as_mut().poll(&mut Context::from_waker(&waker)) {
+    ///                 Poll::Ready(Some(&val)) => {
+    ///                     seen.push(val);
+    ///                     break;
+    ///                 }
+    ///                 Poll::Ready(None) => break 'polling,
+    ///                 Poll::Pending => {}
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     // Another handle sees every item
+    ///     let mut second = reiterate.iter();
+    ///     let mut all = vec![];
+    ///     while let Some(val) = block_on(Box::pin(second.next()).as_mut()) {
+    ///         all.push(val);
+    ///     }
+    ///     assert_eq!(all, [0, 1, 2, 3]);
+    ///
+    ///     // and the first one picks up where it was
+    ///     while let Some(val) = block_on(Box::pin(first.next()).as_mut()) {
+    ///         seen.push(val);
+    ///     }
+    ///     assert_eq!(seen, [0, 1, 2, 3]);
+    ///     assert_eq!(pulls.get(), 4);
+    /// }
+    /// ```
+    pub async fn next(&mut self) -> Option<&'a <S::Item as Deref>::Target> {
+        future::poll_fn(|cx| self.poll_next_item(cx)).await
+    }
+
+    fn poll_next_item(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<&'a <S::Item as Deref>::Target>> {
+        let val = self.iterable.poll_get(self.curr, cx);
+        if let Poll::Ready(Some(_)) = val {
+            self.curr += 1;
+        }
+        val
+    }
+}
+
+impl<'a, S> Stream for AsyncReiterator<'a, S>
+where
+    S: Stream,
+    S::Item: ReiterableItem,
+{
+    type Item = &'a <S::Item as Deref>::Target;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_next_item(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+/// A stream of the lines of an asynchronous reader, used as the source of
+/// `AsyncReiterate::from_async_lines`.
+///
+/// The stream ends at the first I/O error, which is recorded.
+pub struct AsyncLines<R> {
+    lines: Lines<R>,
+    done: bool,
+    error: Option<Arc<io::Error>>,
+}
+
+#[cfg(feature = "tokio")]
+impl<R> AsyncLines<R> {
+    /// The I/O error that ended the stream, if any
+    pub fn error(&self) -> Option<Arc<io::Error>> {
+        self.error.clone()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R> Stream for AsyncLines<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    type Item = Box<str>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Box<str>>> {
+        let this = &mut *self;
+        if this.done {
+            return Poll::Ready(None);
+        }
+        let line = match Pin::new(&mut this.lines).poll_next_line(cx) {
+            Poll::Ready(line) => line,
+            Poll::Pending => return Poll::Pending,
+        };
+        match line {
+            Ok(Some(line)) => Poll::Ready(Some(line.into_boxed_str())),
+            Ok(None) => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+            Err(err) => {
+                this.done = true;
+                this.error = Some(Arc::new(err));
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R> AsyncReiterate<AsyncLines<R>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// Create an `AsyncReiterate` over the lines of `reader`, without their line endings.
+    ///
+    /// The stream ends at the first I/O error, or at the first line that isn't valid UTF-8.
+    /// Use `io_error()` to tell whether that happened.
+    ///
+    /// ```rust
+    /// use reiterate::AsyncReiterate;
+    /// use tokio::io::{AsyncWriteExt, BufReader};
+    /// use tokio::task::yield_now;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let (mut writer, reader) = tokio::io::duplex(64);
+    /// let log = AsyncReiterate::from_async_lines(BufReader::new(reader));
+    ///
+    /// let write = async move {
+    ///     for i in 0..5 {
+    ///         writer.write_all(format!("line {}\n", i).as_bytes()).await.unwrap();
+    ///         yield_now().await;
+    ///     }
+    /// };
+    /// let tail = async {
+    ///     let mut live = log.iter();
+    ///     let mut lines = vec![];
+    ///     while let Some(line) = live.next().await {
+    ///         lines.push(line);
+    ///     }
+    ///     lines
+    /// };
+    /// let late = async {
+    ///     for _ in 0..3 {
+    ///         yield_now().await;
+    ///     }
+    ///     // replays what the live consumer has seen, then waits for the rest
+    ///     let mut late = log.iter();
+    ///     let mut lines = vec![];
+    ///     while let Some(line) = late.next().await {
+    ///         lines.push(line);
+    ///     }
+    ///     lines
+    /// };
+    ///
+    /// let ((), tail, late) = tokio::join!(write, tail, late);
+    /// let expected = ["line 0", "line 1", "line 2", "line 3", "line 4"];
+    /// assert_eq!(tail, expected);
+    /// assert_eq!(late, expected);
+    /// assert!(log.io_error().is_none());
+    /// # }
+    /// ```
+    pub fn from_async_lines(reader: R) -> Self {
+        AsyncReiterate::new(AsyncLines {
+            lines: reader.lines(),
+            done: false,
+            error: None,
+        })
+    }
+
+    /// The I/O error that ended the stream, if any.
+    ///
+    /// ```rust
+    /// use reiterate::AsyncReiterate;
+    /// use std::io::ErrorKind;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let log = AsyncReiterate::from_async_lines(&b"ok\n\xff\xfe\nnever seen\n"[..]);
+    /// let mut iter = log.iter();
+    /// assert_eq!(iter.next().await, Some("ok"));
+    /// assert_eq!(iter.next().await, None);
+    /// assert_eq!(log.io_error().unwrap().kind(), ErrorKind::InvalidData);
+    ///
+    /// // the cached lines are still there
+    /// assert_eq!(log.iter().next().await, Some("ok"));
+    /// # }
+    /// ```
+    pub fn io_error(&self) -> Option<Arc<io::Error>> {
+        self.source.lock().stream.error()
+    }
+}
@@ -0,0 +1,177 @@
+use crate::Reiterable;
+
+use elsa::FrozenVec;
+
+use stable_deref_trait::StableDeref;
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+
+/// An adaptor around a double-ended iterator that can produce multiple iterators
+/// walking it from either end, sharing underlying caches.
+///
+/// Items pulled from the front and from the back are cached separately. Once the two ends
+/// meet, iterators continue through the items cached by the opposite end, so every item of
+/// the underlying iterator is produced exactly once no matter which direction asked for it.
+///
+/// ```rust
+/// use reiterate::BiReiterate;
+///
+/// let pulls = std::cell::Cell::new(0);
+/// let x = vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()];
+/// let reiterate = BiReiterate::new(x.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+///
+/// let mut front = reiterate.iter();
+/// let mut back = reiterate.iter_back();
+/// assert_eq!(front.next(), Some("a"));
+/// assert_eq!(back.next(), Some("e"));
+/// assert_eq!(front.next(), Some("b"));
+/// assert_eq!(back.next(), Some("d"));
+/// assert_eq!(back.next(), Some("c"));
+/// // the ends have met, the rest comes from the other side's cache
+/// assert_eq!(back.collect::<Vec<_>>(), ["b", "a"]);
+/// assert_eq!(front.collect::<Vec<_>>(), ["c", "d", "e"]);
+/// assert_eq!(pulls.get(), 5);
+///
+/// assert_eq!(reiterate.iter().collect::<Vec<_>>(), ["a", "b", "c", "d", "e"]);
+/// assert_eq!(reiterate.iter_back().collect::<Vec<_>>(), ["e", "d", "c", "b", "a"]);
+/// assert_eq!(pulls.get(), 5);
+/// ```
+///
+/// If only one direction is ever used, the other cache stays empty:
+///
+/// ```rust
+/// use reiterate::BiReiterate;
+///
+/// let reiterate = BiReiterate::new(vec![1, 2, 3].into_iter().map(Box::new));
+/// assert_eq!(reiterate.iter_back().cloned().collect::<Vec<_>>(), [3, 2, 1]);
+/// assert_eq!(reiterate.iter_back().cloned().collect::<Vec<_>>(), [3, 2, 1]);
+/// assert_eq!(reiterate.iter().cloned().collect::<Vec<_>>(), [1, 2, 3]);
+///
+/// let empty = BiReiterate::new(Vec::<Box<u8>>::new());
+/// assert_eq!(empty.iter().next(), None);
+/// assert_eq!(empty.iter_back().next(), None);
+/// ```
+pub struct BiReiterate<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: StableDeref,
+{
+    iter: RefCell<I>,
+    exhausted: Cell<bool>,
+    front: FrozenVec<I::Item>,
+    back: FrozenVec<I::Item>,
+}
+
+impl<I> BiReiterate<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: StableDeref,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        BiReiterate {
+            iter: RefCell::new(iter.into_iter()),
+            exhausted: Cell::new(false),
+            front: FrozenVec::new(),
+            back: FrozenVec::new(),
+        }
+    }
+
+    /// Get an iterator walking the sequence from the front
+    pub fn iter(&self) -> BiReiterator<'_, I> {
+        BiReiterator {
+            iterable: self,
+            side: Side::Front,
+            curr: 0,
+        }
+    }
+
+    /// Get an iterator walking the sequence from the back
+    pub fn iter_back(&self) -> BiReiterator<'_, I> {
+        BiReiterator {
+            iterable: self,
+            side: Side::Back,
+            curr: 0,
+        }
+    }
+
+    /// Get the item `idx` positions away from the given end, pulling from that end if needed
+    fn get(&self, side: Side, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        let (near, far) = match side {
+            Side::Front => (&self.front, &self.back),
+            Side::Back => (&self.back, &self.front),
+        };
+        if let Some(item) = near.get(idx) {
+            return Some(item);
+        }
+        if !self.exhausted.get() {
+            let mut iter = self.iter.borrow_mut();
+            let val = match side {
+                Side::Front => iter.next(),
+                Side::Back => iter.next_back(),
+            };
+            drop(iter);
+            match val {
+                // Handles only ever ask for the item right after the cached ones
+                Some(val) => return Some(near.push_get(val)),
+                None => self.exhausted.set(true),
+            }
+        }
+        // The ends have met, so the far cache holds the rest of the sequence in reverse
+        let far_idx = idx - near.len();
+        far.len().checked_sub(far_idx + 1).and_then(|i| far.get(i))
+    }
+}
+
+impl<I> Reiterable for BiReiterate<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: StableDeref,
+{
+    type Item<'a>
+        = &'a <I::Item as Deref>::Target
+    where
+        Self: 'a;
+    type Iter<'a>
+        = BiReiterator<'a, I>
+    where
+        Self: 'a;
+
+    /// Walks the sequence from the front, like the inherent `iter()`
+    fn iter(&self) -> BiReiterator<'_, I> {
+        BiReiterate::iter(self)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Front,
+    Back,
+}
+
+/// An individual iterator, produced by calling `.iter()` or `.iter_back()` on a `BiReiterate`
+pub struct BiReiterator<'a, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: StableDeref,
+{
+    iterable: &'a BiReiterate<I>,
+    side: Side,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for BiReiterator<'a, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: StableDeref,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterable.get(self.side, self.curr)?;
+        self.curr += 1;
+        Some(item)
+    }
+}
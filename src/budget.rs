@@ -0,0 +1,140 @@
+use crate::{lazy, CopyReiterator, ReiterableItem, Reiterator};
+
+use std::ops::Deref;
+
+/// A number of pulls from the underlying iterator that `next_budgeted()` may make, shared
+/// by any number of handles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PullBudget {
+    remaining: usize,
+}
+
+impl PullBudget {
+    pub fn new(pulls: usize) -> Self {
+        PullBudget { remaining: pulls }
+    }
+
+    /// The number of pulls left
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Allow `pulls` more pulls
+    pub fn refill(&mut self, pulls: usize) {
+        self.remaining = self.remaining.saturating_add(pulls);
+    }
+
+    /// Use up one pull, if there are any left
+    fn take(&mut self) -> bool {
+        match self.remaining.checked_sub(1) {
+            Some(remaining) => {
+                self.remaining = remaining;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The result of `next_budgeted()`
+#[derive(Debug, PartialEq, Eq)]
+pub enum BudgetedNext<T> {
+    /// The next item
+    Ready(T),
+    /// The stream has ended
+    Exhausted,
+    /// The next item has to be pulled, but the budget is spent
+    BudgetExceeded,
+}
+
+impl<T> From<Option<T>> for BudgetedNext<T> {
+    fn from(item: Option<T>) -> Self {
+        item.map_or(BudgetedNext::Exhausted, BudgetedNext::Ready)
+    }
+}
+
+impl<'a, I> Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Advance the iterator, unless that takes a pull from the underlying iterator and
+    /// `budget` has none left.
+    ///
+    /// Cached items are free. Each pull takes one from `budget`, including one that finds
+    /// the end of the stream. When the budget is spent, the iterator stays where it is, so
+    /// the call can be retried once the budget is refilled.
+    ///
+    /// ```rust
+    /// use reiterate::{BudgetedNext, PullBudget, Reiterate};
+    ///
+    /// let reiterate = Reiterate::new((0..4).map(Box::new));
+    /// let (mut a, mut b) = (reiterate.into_iter(), reiterate.into_iter());
+    /// let mut budget = PullBudget::new(3);
+    ///
+    /// assert_eq!(a.next_budgeted(&mut budget), BudgetedNext::Ready(&0));
+    /// assert_eq!(a.next_budgeted(&mut budget), BudgetedNext::Ready(&1));
+    /// // `b` catches up for free, and does a share of the pulling
+    /// assert_eq!(b.nth(1), Some(&1));
+    /// assert_eq!(b.next_budgeted(&mut budget), BudgetedNext::Ready(&2));
+    /// assert_eq!(budget.remaining(), 0);
+    /// assert_eq!(a.next_budgeted(&mut budget), BudgetedNext::Ready(&2));
+    ///
+    /// assert_eq!(a.next_budgeted(&mut budget), BudgetedNext::BudgetExceeded);
+    /// assert_eq!(b.next_budgeted(&mut budget), BudgetedNext::BudgetExceeded);
+    /// budget.refill(2);
+    /// assert_eq!(a.next_budgeted(&mut budget), BudgetedNext::Ready(&3));
+    /// assert_eq!(a.next_budgeted(&mut budget), BudgetedNext::Exhausted);
+    /// assert_eq!(b.next_budgeted(&mut budget), BudgetedNext::Ready(&3));
+    /// ```
+    pub fn next_budgeted(
+        &mut self,
+        budget: &mut PullBudget,
+    ) -> BudgetedNext<&'a <I::Item as Deref>::Target> {
+        let cached = self.curr < self.iterable.visible_len();
+        if !cached && self.curr < self.iterable.limit.get() && !budget.take() {
+            return BudgetedNext::BudgetExceeded;
+        }
+        self.next().into()
+    }
+}
+
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Advance the iterator, unless that takes a pull from the underlying iterator and
+    /// `budget` has none left.
+    ///
+    /// This is the `Copy` counterpart of `Reiterator::next_budgeted()`. Items streamed by
+    /// the first handle of a `lazy_caching()` `CopyReiterate` are always pulled.
+    ///
+    /// ```rust
+    /// use reiterate::{BudgetedNext, CopyReiterate, PullBudget};
+    ///
+    /// let reiterate = CopyReiterate::new("ab".chars());
+    /// let mut iter = reiterate.into_iter();
+    /// let mut budget = PullBudget::new(1);
+    /// assert_eq!(iter.next_budgeted(&mut budget), BudgetedNext::Ready('a'));
+    /// for _ in 0..3 {
+    ///     assert_eq!(iter.next_budgeted(&mut budget), BudgetedNext::BudgetExceeded);
+    /// }
+    /// // the position didn't move
+    /// assert_eq!(iter.next(), Some('b'));
+    ///
+    /// // replays are free
+    /// let mut replay = reiterate.into_iter();
+    /// assert_eq!(replay.next_budgeted(&mut budget), BudgetedNext::Ready('a'));
+    /// assert_eq!(replay.next_budgeted(&mut budget), BudgetedNext::Ready('b'));
+    /// ```
+    pub fn next_budgeted(&mut self, budget: &mut PullBudget) -> BudgetedNext<I::Item> {
+        let iterable = self.iterable;
+        let cached = iterable.lazy.get() != lazy::LazyCaching::Streaming
+            && self.curr < iterable.visible_len();
+        if !cached && self.curr < iterable.limit.get() && !budget.take() {
+            return BudgetedNext::BudgetExceeded;
+        }
+        self.next().into()
+    }
+}
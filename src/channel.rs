@@ -0,0 +1,167 @@
+use crate::{ContentionAware, ReiterableItem, Reiterate, Reiterator};
+
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+/// An iterator over the messages of a `Receiver`, used as the source of
+/// `Reiterate::from_receiver` and `Reiterate::from_receiver_nonblocking`.
+pub struct ChannelSource<T> {
+    rx: Receiver<T>,
+    blocking: bool,
+    disconnected: bool,
+}
+
+impl<T> ChannelSource<T> {
+    /// Whether all senders have been dropped and every sent message has been received
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected
+    }
+}
+
+impl<T> Iterator for ChannelSource<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.disconnected {
+            return None;
+        }
+        let msg = if self.blocking {
+            self.rx.recv().map_err(|_| TryRecvError::Disconnected)
+        } else {
+            self.rx.try_recv()
+        };
+        match msg {
+            Ok(msg) => Some(msg),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                self.disconnected = true;
+                None
+            }
+        }
+    }
+}
+
+/// The result of `try_next` on an iterator whose stream may grow later, such as one over
+/// a channel-backed `Reiterate` or a `PushReiterate`
+#[derive(Debug, PartialEq, Eq)]
+pub enum TryNext<T> {
+    /// The next item
+    Ready(T),
+    /// No item is ready yet, but more may arrive later
+    Empty,
+    /// The stream has ended and every item has been seen
+    Disconnected,
+}
+
+impl<T> Reiterate<ChannelSource<T>>
+where
+    T: ReiterableItem,
+{
+    /// Create a `Reiterate` over the messages arriving on `rx`.
+    ///
+    /// Pulling a new item blocks until a message arrives. The stream ends once
+    /// the channel is disconnected.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::sync::mpsc::channel;
+    /// use std::thread;
+    ///
+    /// let (tx, rx) = channel();
+    /// let producer = thread::spawn(move || {
+    ///     for i in 0..5 {
+    ///         tx.send(Box::new(i)).unwrap();
+    ///     }
+    /// });
+    ///
+    /// let reiterate = Reiterate::from_receiver(rx);
+    /// let mut live = reiterate.into_iter();
+    /// assert_eq!(live.next(), Some(&0));
+    /// assert_eq!(live.next(), Some(&1));
+    /// // a late consumer replays history before waiting for new messages
+    /// assert_eq!(reiterate.into_iter().cloned().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    /// assert_eq!(live.cloned().collect::<Vec<_>>(), [2, 3, 4]);
+    /// producer.join().unwrap();
+    /// ```
+    pub fn from_receiver(rx: Receiver<T>) -> Self {
+        Reiterate::new(ChannelSource {
+            rx,
+            blocking: true,
+            disconnected: false,
+        })
+    }
+
+    /// Create a `Reiterate` over the messages arriving on `rx`, without blocking.
+    ///
+    /// When no message is ready, iterators return `None` at the end of the cache, but
+    /// the stream isn't over: later calls pick up messages that arrived in the meantime.
+    /// Use `Reiterator::try_next` to tell a temporarily empty channel from a
+    /// disconnected one.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, Reiterate, TryNext};
+    /// use std::sync::mpsc::channel;
+    /// use std::thread;
+    ///
+    /// let (tx, rx) = channel();
+    /// let reiterate = Reiterate::from_receiver_nonblocking(rx);
+    ///
+    /// thread::spawn({
+    ///     let tx = tx.clone();
+    ///     move || {
+    ///         tx.send("a".to_string()).unwrap();
+    ///         tx.send("b".to_string()).unwrap();
+    ///     }
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.try_next(), TryNext::Ready("a"));
+    /// assert_eq!(iter.try_next(), TryNext::Ready("b"));
+    /// assert_eq!(iter.try_next(), TryNext::Empty);
+    ///
+    /// thread::spawn({
+    ///     let tx = tx.clone();
+    ///     move || tx.send("c".to_string()).unwrap()
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// // a later pass replays history and picks up the new message
+    /// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+    /// assert_eq!(reiterate.is_disconnected(), ContentionAware::Ready(false));
+    /// assert_eq!(iter.try_next(), TryNext::Ready("c"));
+    /// assert_eq!(iter.try_next(), TryNext::Empty);
+    ///
+    /// drop(tx);
+    /// assert_eq!(iter.try_next(), TryNext::Disconnected);
+    /// assert_eq!(reiterate.is_disconnected(), ContentionAware::Ready(true));
+    /// ```
+    pub fn from_receiver_nonblocking(rx: Receiver<T>) -> Self {
+        Reiterate::new(ChannelSource {
+            rx,
+            blocking: false,
+            disconnected: false,
+        })
+    }
+
+    /// Whether the channel has been seen to be disconnected, meaning no more
+    /// items will arrive after the cached ones, or `Contended` during a pull
+    pub fn is_disconnected(&self) -> ContentionAware<bool> {
+        ContentionAware::borrow(&self.iter).map(|iter| iter.is_disconnected())
+    }
+}
+
+impl<'a, T> Reiterator<'a, ChannelSource<T>>
+where
+    T: ReiterableItem,
+{
+    /// Get the next item, distinguishing a temporarily empty channel from a disconnected one
+    pub fn try_next(&mut self) -> TryNext<&'a T::Target> {
+        match self.next() {
+            Some(item) => TryNext::Ready(item),
+            None if self.iterable.is_disconnected().ready() == Some(true) => TryNext::Disconnected,
+            None => TryNext::Empty,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{ContentionAware, CopyReiterate};
+
+#[cfg(feature = "unicode-segmentation")]
+use crate::CopyReiterator;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator<Item = char> + ?Sized,
+{
+    /// The cached chars from position `a` up to position `b`, as a `String`.
+    ///
+    /// This never pulls: positions past the cached chars, or past the logical length set
+    /// by `truncate()`, are left out, and `a >= b` gives an empty string. Like the other
+    /// read-only methods, it's `Contended` during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, CopyReiterate};
+    ///
+    /// let input = CopyReiterate::new("let x = 42;".chars());
+    /// let mut cursor = input.into_iter();
+    /// let start = cursor.position(|c| c == '=').unwrap() + 2;
+    /// let end = start + cursor.skip(1).take_while(|c| c.is_ascii_digit()).count();
+    /// assert_eq!(input.collect_string_range(start, end), ContentionAware::Ready("42".into()));
+    ///
+    /// // only what is cached
+    /// assert_eq!(input.collect_string_range(0, 100).ready().unwrap(), "let x = 42;");
+    /// let fresh = CopyReiterate::new("abc".chars());
+    /// assert_eq!(fresh.collect_string_range(0, 3).ready().unwrap(), "");
+    /// ```
+    pub fn collect_string_range(&self, a: usize, b: usize) -> ContentionAware<String> {
+        let limit = self.limit.get();
+        ContentionAware::borrow(&self.inner).map(|inner| {
+            let end = b.min(inner.cache.len()).min(limit);
+            inner.cache[a.min(end)..end].iter().collect()
+        })
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator<Item = char> + ?Sized,
+{
+    /// Advance over the next extended grapheme cluster, returning it.
+    ///
+    /// Finding the end of a cluster takes looking at the char after it, which is pulled
+    /// and cached as needed but not consumed, so clusters are never split across calls,
+    /// even when they span cached and uncached chars. A cluster cut short by the end of
+    /// the stream is returned as it is. Handles should start on a cluster boundary, like
+    /// the start of the stream.
+    ///
+    /// This needs the `unicode-segmentation` feature, and it stops the first handle of a
+    /// `lazy_caching()` `CopyReiterate` from streaming.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// // a family emoji joined with ZWJs, then an `e` with a combining acute accent
+    /// let text = "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}e\u{301}!";
+    /// let reiterate = CopyReiterate::new(text.chars());
+    ///
+    /// // pull the first two chars of the family only
+    /// assert_eq!(reiterate.into_iter().take(2).count(), 2);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next_grapheme().unwrap(), "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}");
+    /// assert_eq!(iter.next_grapheme().unwrap(), "e\u{301}");
+    /// assert_eq!(iter.next(), Some('!'));
+    /// assert_eq!(iter.next_grapheme(), None);
+    ///
+    /// // the end of the stream cuts a cluster short
+    /// let cut = CopyReiterate::new("a\u{1F469}\u{200D}".chars());
+    /// let mut iter = cut.into_iter();
+    /// assert_eq!(iter.next_grapheme().unwrap(), "a");
+    /// assert_eq!(iter.next_grapheme().unwrap(), "\u{1F469}\u{200D}");
+    /// assert_eq!(iter.next_grapheme(), None);
+    /// ```
+    pub fn next_grapheme(&mut self) -> Option<String> {
+        let iterable = self.iterable;
+        iterable.stop_streaming();
+        let mut cluster = String::from(self.next()?);
+        while let Some(c) = iterable.get_or_pull(self.curr) {
+            cluster.push(c);
+            // Whether a char starts a new cluster only depends on the chars before it
+            if cluster.graphemes(true).nth(1).is_some() {
+                cluster.pop();
+                break;
+            }
+            self.curr += 1;
+        }
+        Some(cluster)
+    }
+
+    /// Turn this handle into an iterator over the extended grapheme clusters, as given by
+    /// `next_grapheme()`.
+    ///
+    /// This needs the `unicode-segmentation` feature.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("\u{1F1EB}\u{1F1F7}\u{1F1EE}\u{1F1F9}a\u{308}\u{323}".chars());
+    /// let clusters: Vec<_> = reiterate.into_iter().graphemes().collect();
+    /// assert_eq!(clusters, ["\u{1F1EB}\u{1F1F7}", "\u{1F1EE}\u{1F1F9}", "a\u{308}\u{323}"]);
+    /// assert_eq!(reiterate.into_iter().count(), 7);
+    /// ```
+    pub fn graphemes(self) -> GraphemeReiterator<'a, I> {
+        GraphemeReiterator { iter: self }
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a `CopyReiterate` over chars, produced
+/// by `graphemes()`
+#[cfg(feature = "unicode-segmentation")]
+pub struct GraphemeReiterator<'a, I>
+where
+    I: Iterator<Item = char> + ?Sized,
+{
+    iter: CopyReiterator<'a, I>,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'a, I> Iterator for GraphemeReiterator<'a, I>
+where
+    I: Iterator<Item = char> + ?Sized,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.iter.next_grapheme()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every cluster has at least one char
+        let (lower, upper) = self.iter.size_hint();
+        ((lower > 0) as usize, upper)
+    }
+}
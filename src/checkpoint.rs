@@ -0,0 +1,209 @@
+use crate::queue::SourceQueue;
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::error::Error;
+use std::fmt;
+
+/// A snapshot of how far the underlying iterator had been pulled, taken by `checkpoint()`
+/// and restored by `rollback()`
+pub struct Checkpoint<I>
+where
+    I: Iterator,
+{
+    pulled: usize,
+    generation: usize,
+    // Clones of the underlying iterator and of the queued sources as they were when the
+    // checkpoint was taken
+    iter: I,
+    queued: SourceQueue<I::Item>,
+}
+
+impl<I> Clone for Checkpoint<I>
+where
+    I: Iterator + Clone,
+{
+    fn clone(&self) -> Self {
+        Checkpoint {
+            pulled: self.pulled,
+            generation: self.generation,
+            iter: self.iter.clone(),
+            // SAFETY: the queued sources have the type of the underlying iterator
+            queued: unsafe { self.queued.clone_as::<I>() },
+        }
+    }
+}
+
+impl<I> Checkpoint<I>
+where
+    I: Iterator,
+{
+    /// How many items had been pulled when the checkpoint was taken
+    pub fn pulled(&self) -> usize {
+        self.pulled
+    }
+}
+
+/// The error returned by `rollback()` for a checkpoint taken before the cache was last
+/// rewritten, by another `rollback()` or by `retain_cached()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaleCheckpoint;
+
+impl fmt::Display for StaleCheckpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the checkpoint was taken before the cache was last rewritten")
+    }
+}
+
+impl Error for StaleCheckpoint {}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + Clone,
+    I::Item: ReiterableItem,
+{
+    /// Take a snapshot of how far the underlying iterator has been pulled, to `rollback()`
+    /// to later.
+    ///
+    /// This keeps a clone of the underlying iterator, so a source that reads its data
+    /// lazily, e.g. from shared state, reads it again after a rollback.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let prices = Rc::new(RefCell::new(vec![10, 20, 30, 40]));
+    /// let source = {
+    ///     let prices = prices.clone();
+    ///     (0..4).map(move |i| Box::new(prices.borrow()[i]))
+    /// };
+    /// let mut reiterate = Reiterate::new(source);
+    /// assert_eq!(reiterate.into_iter().take(2).count(), 2);
+    ///
+    /// let checkpoint = reiterate.checkpoint();
+    /// assert_eq!(checkpoint.pulled(), 2);
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [10, 20, 30, 40]);
+    ///
+    /// // the speculative run is thrown away, and the source sees the new data
+    /// prices.borrow_mut()[2] = 35;
+    /// reiterate.rollback(checkpoint).unwrap();
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [10, 20, 35, 40]);
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint<I> {
+        Checkpoint {
+            pulled: self.cache.len(),
+            generation: self.generation,
+            iter: self.iter.borrow().clone(),
+            // SAFETY: the queued sources have the type of the underlying iterator
+            queued: unsafe { self.queued.clone_as::<I>() },
+        }
+    }
+
+    /// Drop every item cached since `checkpoint` was taken, and put the underlying
+    /// iterator back the way it was, so that they are pulled again.
+    ///
+    /// Unlike the handles' own positions, this rewrites the shared cache, which is why it
+    /// takes `&mut self`. That makes every other checkpoint stale: they are rejected, as
+    /// are checkpoints taken before a `retain_cached()` that dropped items. This also
+    /// clears the poisoned flag, since the iterator that panicked is replaced.
+    ///
+    /// ```rust
+    /// use reiterate::{Reiterate, StaleCheckpoint};
+    ///
+    /// let mut reiterate = Reiterate::new((0..10).map(Box::new));
+    /// let start = reiterate.checkpoint();
+    /// assert_eq!(reiterate.into_iter().nth(4), Some(&4));
+    /// let middle = reiterate.checkpoint();
+    ///
+    /// reiterate.rollback(start.clone()).unwrap();
+    /// assert_eq!(reiterate.into_iter().count(), 10);
+    /// assert_eq!(reiterate.rollback(middle), Err(StaleCheckpoint));
+    /// assert_eq!(reiterate.rollback(start), Err(StaleCheckpoint));
+    ///
+    /// let settled = reiterate.checkpoint();
+    /// assert_eq!(reiterate.retain_cached(|&i| i % 2 == 0), 5);
+    /// assert_eq!(reiterate.rollback(settled), Err(StaleCheckpoint));
+    /// ```
+    ///
+    /// Sources queued with `queue_source()` are part of the checkpoint:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let mut reiterate = Reiterate::new(vec![Box::new(1)]);
+    /// reiterate.queue_source(vec![Box::new(2), Box::new(3)]);
+    /// let checkpoint = reiterate.checkpoint();
+    /// assert_eq!(reiterate.into_iter().count(), 3);
+    ///
+    /// reiterate.rollback(checkpoint).unwrap();
+    /// reiterate.queue_source(vec![Box::new(4)]);
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    pub fn rollback(&mut self, checkpoint: Checkpoint<I>) -> Result<(), StaleCheckpoint> {
+        if checkpoint.generation != self.generation {
+            return Err(StaleCheckpoint);
+        }
+        self.discard_cached_from(checkpoint.pulled);
+        *self.poisoned.get_mut() = false;
+        *self.iter.get_mut() = checkpoint.iter;
+        self.queued = checkpoint.queued;
+        Ok(())
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + Clone,
+    I::Item: Copy,
+{
+    /// Take a snapshot of how far the underlying iterator has been pulled, to `rollback()`
+    /// to later.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::checkpoint()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let offset = Rc::new(Cell::new(0));
+    /// let source = {
+    ///     let offset = offset.clone();
+    ///     (0..5).map(move |i| i + offset.get())
+    /// };
+    /// let mut reiterate = CopyReiterate::new(source);
+    /// let checkpoint = reiterate.checkpoint();
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [0, 1, 2, 3, 4]);
+    ///
+    /// offset.set(100);
+    /// reiterate.rollback(checkpoint).unwrap();
+    /// assert_eq!(reiterate.cache_size_bytes().ready(), Some(0));
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [100, 101, 102, 103, 104]);
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint<I> {
+        let inner = self.inner.borrow();
+        Checkpoint {
+            pulled: inner.cache.len(),
+            generation: self.generation,
+            iter: inner.iter.clone(),
+            // SAFETY: the queued sources have the type of the underlying iterator
+            queued: unsafe { self.queued.clone_as::<I>() },
+        }
+    }
+
+    /// Drop every item cached since `checkpoint` was taken, and put the underlying
+    /// iterator back the way it was, so that they are pulled again.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::rollback()`, and rejects stale
+    /// checkpoints the same way.
+    pub fn rollback(&mut self, checkpoint: Checkpoint<I>) -> Result<(), StaleCheckpoint> {
+        if checkpoint.generation != self.generation {
+            return Err(StaleCheckpoint);
+        }
+        self.discard_cached_from(checkpoint.pulled);
+        self.inner.get_mut().iter = checkpoint.iter;
+        self.queued = checkpoint.queued;
+        *self.poisoned.get_mut() = false;
+        Ok(())
+    }
+}
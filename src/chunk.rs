@@ -0,0 +1,486 @@
+use crate::{CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::array;
+use std::cell::{Cell, RefCell};
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::vec;
+
+/// The items left at the end of the stream when there weren't enough for a whole chunk,
+/// returned by `next_chunk()`
+#[derive(Clone, Debug)]
+pub struct ArrayChunkRemainder<T> {
+    items: vec::IntoIter<T>,
+}
+
+/// The result of `next_chunk()`
+pub type ChunkResult<T, const N: usize> = Result<[T; N], ArrayChunkRemainder<T>>;
+
+impl<T> ArrayChunkRemainder<T> {
+    fn new<const N: usize>(chunk: [Option<T>; N]) -> Self {
+        ArrayChunkRemainder {
+            items: IntoIterator::into_iter(chunk)
+                .flatten()
+                .collect::<Vec<_>>()
+                .into_iter(),
+        }
+    }
+
+    /// The remaining items
+    pub fn as_slice(&self) -> &[T] {
+        self.items.as_slice()
+    }
+}
+
+impl<T> Iterator for ArrayChunkRemainder<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.items.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for ArrayChunkRemainder<T> {}
+
+/// Pull `N` items from `next`, stopping at the first `None`
+fn next_chunk<T, const N: usize>(mut next: impl FnMut() -> Option<T>) -> ChunkResult<T, N> {
+    let mut ended = false;
+    let chunk: [Option<T>; N] = array::from_fn(|_| {
+        if ended {
+            return None;
+        }
+        let item = next();
+        ended = item.is_none();
+        item
+    });
+    if ended {
+        Err(ArrayChunkRemainder::new(chunk))
+    } else {
+        Ok(chunk.map(|item| item.expect("chunk is full")))
+    }
+}
+
+impl<'a, I> Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Advance the iterator by `N` items and return them as an array, like the unstable
+    /// `Iterator::next_chunk`.
+    ///
+    /// If the stream ends first, the items that were left are returned as an error.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c", "d", "e", "f"]);
+    /// reiterate.into_iter().take(3).for_each(drop);
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next_chunk::<1>().unwrap(), ["a"]);
+    /// // straddles the end of the cache
+    /// assert_eq!(iter.next_chunk().unwrap(), ["b", "c", "d"]);
+    /// // ends exactly at the end of the stream
+    /// assert_eq!(iter.next_chunk().unwrap(), ["e", "f"]);
+    /// assert_eq!(iter.next_chunk::<2>().unwrap_err().len(), 0);
+    ///
+    /// let short = Reiterate::new(vec!["x", "y"]);
+    /// let rest = short.into_iter().next_chunk::<3>().unwrap_err();
+    /// assert_eq!(rest.as_slice(), ["x", "y"]);
+    /// assert_eq!(rest.collect::<String>(), "xy");
+    /// ```
+    pub fn next_chunk<const N: usize>(&mut self) -> ChunkResult<&'a <I::Item as Deref>::Target, N> {
+        next_chunk(|| self.next())
+    }
+}
+
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Advance the iterator by `N` items and return them as an array, like the unstable
+    /// `Iterator::next_chunk`.
+    ///
+    /// Chunks that are already cached are copied in bulk. If the stream ends first, the
+    /// items that were left are returned as an error.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(0..8u8);
+    /// reiterate.into_iter().take(3).for_each(drop);
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next_chunk().unwrap(), [0, 1]);
+    /// assert_eq!(iter.next_chunk().unwrap(), [2, 3, 4]);
+    /// assert_eq!(iter.next_chunk::<1>().unwrap(), [5]);
+    /// // everything is cached now
+    /// assert_eq!(reiterate.into_iter().next_chunk().unwrap(), [0, 1, 2, 3, 4, 5]);
+    ///
+    /// let rest = iter.next_chunk::<4>().unwrap_err();
+    /// assert_eq!(rest.as_slice(), [6, 7]);
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let short = CopyReiterate::new(0..2);
+    /// assert_eq!(short.into_iter().next_chunk::<3>().unwrap_err().as_slice(), [0, 1]);
+    /// assert_eq!(short.into_iter().next_chunk::<2>().unwrap(), [0, 1]);
+    /// ```
+    pub fn next_chunk<const N: usize>(&mut self) -> ChunkResult<I::Item, N> {
+        let cached = {
+            let iterable = self.iterable.inner.borrow();
+            let end = self.curr.saturating_add(N);
+            if end <= self.iterable.limit.get() {
+                iterable.cache.get(self.curr..end).map(|items| {
+                    <[I::Item; N]>::try_from(items).expect("slice has the chunk's length")
+                })
+            } else {
+                None
+            }
+        };
+        match cached {
+            Some(chunk) => {
+                self.curr += N;
+                Ok(chunk)
+            }
+            None => next_chunk(|| self.next()),
+        }
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get an iterator over chunks of `n` consecutive items, pulled from the underlying
+    /// iterator as each chunk is reached.
+    ///
+    /// Chunks start at multiples of `n`, so every pass over the chunks, e.g. a retry,
+    /// sees the same boundaries. The last chunk is shorter if the stream ends within it,
+    /// and there are no chunks at all for an empty stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let rows = (0..7).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1));
+    /// let reiterate = Reiterate::new(rows);
+    ///
+    /// let write = |attempt| -> Result<Vec<Vec<i32>>, ()> {
+    ///     let mut written = Vec::new();
+    ///     for chunk in reiterate.buffered_chunks(3) {
+    ///         if attempt == 0 && written.len() == 1 {
+    ///             return Err(());
+    ///         }
+    ///         written.push(chunk.iter().copied().collect());
+    ///     }
+    ///     Ok(written)
+    /// };
+    /// assert!(write(0).is_err());
+    /// assert_eq!(pulls.get(), 6);
+    /// let written = write(1).unwrap();
+    /// assert_eq!(written, [vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    /// assert_eq!(written, write(2).unwrap());
+    /// assert_eq!(pulls.get(), 7);
+    ///
+    /// let empty = Reiterate::new(Vec::<Box<i32>>::new());
+    /// assert_eq!(empty.buffered_chunks(3).count(), 0);
+    /// ```
+    pub fn buffered_chunks(&self, n: usize) -> BufferedChunks<'_, I> {
+        assert!(n != 0, "chunk size must be non-zero");
+        BufferedChunks {
+            iter: self.into_iter(),
+            n,
+        }
+    }
+}
+
+/// An iterator over chunks of the items of a `Reiterate`, produced by `buffered_chunks()`
+pub struct BufferedChunks<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: Reiterator<'a, I>,
+    n: usize,
+}
+
+impl<'a, I> Iterator for BufferedChunks<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item = ChunkView<'a, I>;
+
+    fn next(&mut self) -> Option<ChunkView<'a, I>> {
+        let start = self.iter.curr;
+        let len = self.iter.by_ref().take(self.n).count();
+        if len == 0 {
+            return None;
+        }
+        Some(ChunkView {
+            iterable: self.iter.iterable,
+            start,
+            len,
+        })
+    }
+}
+
+/// A chunk of consecutive cached items, produced by `buffered_chunks()`
+pub struct ChunkView<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    start: usize,
+    len: usize,
+}
+
+impl<'a, I> ChunkView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// The number of items in the chunk
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the chunk is empty, which it never is
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the item at `idx` within the chunk
+    pub fn get(&self, idx: usize) -> Option<&'a <I::Item as Deref>::Target> {
+        if idx < self.len {
+            self.iterable.cache.get(self.start + idx)
+        } else {
+            None
+        }
+    }
+
+    /// The position of the first item of the chunk in the stream
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Iterate over the items of the chunk
+    pub fn iter(&self) -> impl Iterator<Item = &'a <I::Item as Deref>::Target> + '_ {
+        let iterable = self.iterable;
+        (self.start..self.start + self.len).map(move |idx| &iterable.cache[idx])
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a view of the items in chunks whose total weight, as given by `weigh`, is at
+    /// most `limit`, like batches of payloads bounded by their size in bytes.
+    ///
+    /// Each chunk takes as many items as fit, so its boundaries depend on the ones before
+    /// it; they are found as the chunks are reached, and remembered, so that every pass
+    /// over the chunks sees the same ones and `weigh` runs once per item. An item heavier
+    /// than `limit` gets a chunk of its own.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let (pulls, weighed) = (Cell::new(0), Cell::new(0));
+    /// let sizes = [300, 500, 200, 1500, 100, 900, 50];
+    /// let payloads = sizes.iter().map(|&n| vec![0u8; n].into_boxed_slice());
+    /// let reiterate = Reiterate::new(payloads.inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let batches = reiterate.chunk_by_weight(1000, |payload: &[u8]| {
+    ///     weighed.set(weighed.get() + 1);
+    ///     payload.len()
+    /// });
+    ///
+    /// let lens = || -> Vec<Vec<usize>> {
+    ///     batches.iter().map(|chunk| chunk.iter().map(|p| p.len()).collect()).collect()
+    /// };
+    /// let first = lens();
+    /// assert_eq!(first, [vec![300, 500, 200], vec![1500], vec![100, 900], vec![50]]);
+    /// assert_eq!(lens(), first);
+    /// let weights: Vec<_> = batches.iter().map(|chunk| chunk.total_weight()).collect();
+    /// assert_eq!(weights, [1000, 1500, 1000, 50]);
+    /// assert_eq!((pulls.get(), weighed.get()), (7, 7));
+    ///
+    /// let empty = Reiterate::new(Vec::<Box<[u8]>>::new());
+    /// assert_eq!(empty.chunk_by_weight(10, |p: &[u8]| p.len()).iter().count(), 0);
+    /// ```
+    pub fn chunk_by_weight<F>(&self, limit: usize, weigh: F) -> WeightedChunks<'_, I, F>
+    where
+        F: Fn(&<I::Item as Deref>::Target) -> usize,
+    {
+        WeightedChunks {
+            iterable: self,
+            limit,
+            weigh,
+            chunks: RefCell::new(Vec::new()),
+            carry: Cell::new(None),
+        }
+    }
+}
+
+/// A view of the items of a `Reiterate` in chunks bounded by weight, produced by
+/// `chunk_by_weight()`
+pub struct WeightedChunks<'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    limit: usize,
+    weigh: F,
+    // The end and total weight of each chunk found so far
+    chunks: RefCell<Vec<(usize, usize)>>,
+    // The weight of the item after the last chunk found, if it has been weighed
+    carry: Cell<Option<usize>>,
+}
+
+impl<'a, I, F> WeightedChunks<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&<I::Item as Deref>::Target) -> usize,
+{
+    /// Iterate over the chunks
+    pub fn iter(&self) -> WeightedChunksReiterator<'_, 'a, I, F> {
+        WeightedChunksReiterator {
+            view: self,
+            chunk: 0,
+        }
+    }
+
+    /// Get the `(end, total weight)` of the chunk after the last one found, pulling its
+    /// items from the underlying iterator if needed
+    fn find_next(&self) -> Option<(usize, usize)> {
+        let start = self.chunks.borrow().last().map_or(0, |&(end, _)| end);
+        let (mut weight, mut len) = self.carry.take().map_or((0, 0), |weight| (weight, 1));
+        while let Some(item) = self.iterable.get_or_pull(start + len) {
+            let item_weight = (self.weigh)(item);
+            if len > 0 && weight.saturating_add(item_weight) > self.limit {
+                self.carry.set(Some(item_weight));
+                break;
+            }
+            weight = weight.saturating_add(item_weight);
+            len += 1;
+        }
+        if len == 0 {
+            return None;
+        }
+        let chunk = (start + len, weight);
+        self.chunks.borrow_mut().push(chunk);
+        Some(chunk)
+    }
+}
+
+impl<'v, 'a, I, F> IntoIterator for &'v WeightedChunks<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&<I::Item as Deref>::Target) -> usize,
+{
+    type IntoIter = WeightedChunksReiterator<'v, 'a, I, F>;
+    type Item = WeightedChunk<'a, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `WeightedChunks`, produced by calling `.iter()`
+pub struct WeightedChunksReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v WeightedChunks<'a, I, F>,
+    chunk: usize,
+}
+
+impl<'v, 'a, I, F> Iterator for WeightedChunksReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&<I::Item as Deref>::Target) -> usize,
+{
+    type Item = WeightedChunk<'a, I>;
+
+    fn next(&mut self) -> Option<WeightedChunk<'a, I>> {
+        let view = self.view;
+        let found = view.chunks.borrow().get(self.chunk).copied();
+        let (end, weight) = match found {
+            Some(chunk) => chunk,
+            None => view.find_next()?,
+        };
+        let start = match self.chunk {
+            0 => 0,
+            chunk => view.chunks.borrow()[chunk - 1].0,
+        };
+        // A chunk cut short by `truncate()` is dropped rather than shortened
+        if end > view.iterable.limit.get() {
+            return None;
+        }
+        self.chunk += 1;
+        Some(WeightedChunk {
+            chunk: ChunkView {
+                iterable: view.iterable,
+                start,
+                len: end - start,
+            },
+            weight,
+        })
+    }
+}
+
+/// A chunk of consecutive cached items bounded by weight, produced by
+/// `chunk_by_weight()`
+pub struct WeightedChunk<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    chunk: ChunkView<'a, I>,
+    weight: usize,
+}
+
+impl<'a, I> WeightedChunk<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// The total weight of the items in the chunk
+    pub fn total_weight(&self) -> usize {
+        self.weight
+    }
+
+    /// The number of items in the chunk
+    pub fn len(&self) -> usize {
+        self.chunk.len()
+    }
+
+    /// Whether the chunk is empty, which it never is
+    pub fn is_empty(&self) -> bool {
+        self.chunk.is_empty()
+    }
+
+    /// The position of the first item of the chunk in the stream
+    pub fn start(&self) -> usize {
+        self.chunk.start()
+    }
+
+    /// Iterate over the items of the chunk
+    pub fn iter(&self) -> impl Iterator<Item = &'a <I::Item as Deref>::Target> + '_ {
+        self.chunk.iter()
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{poison_on_unwind, remaining_hint, ContentionAware, Reiterable};
+
+use std::cell::{Cell, RefCell};
+use std::iter::Map;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// An adaptor around an iterator over Clone items that can produce multiple iterators
+/// sharing an underlying cache, for items that are cheap to clone but aren't Copy.
+///
+/// The items are cached by value, with no allocation of their own, and every handle yields
+/// clones of them. For items that own large heap allocations, `Reiterate` yields
+/// references instead.
+///
+/// ```rust
+/// use reiterate::CloneReiterate;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Point {
+///     label: &'static str,
+///     coords: [f32; 3],
+/// }
+///
+/// let points = vec![Point { label: "a", coords: [0., 1., 2.] }, Point { label: "b", coords: [3., 4., 5.] }];
+/// let reiterate = CloneReiterate::new(points.clone());
+/// let mut iter = reiterate.into_iter();
+/// assert_eq!(iter.next().unwrap().label, "a");
+/// assert_eq!(reiterate.cached_len().ready(), Some(1));
+/// for _ in 0..2 {
+///     assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), points);
+/// }
+/// assert_eq!(iter.size_hint(), (1, Some(1)));
+/// ```
+pub struct CloneReiterate<I>
+where
+    I: Iterator,
+{
+    poisoned: Cell<bool>,
+    cache: RefCell<Vec<I::Item>>,
+    iter: RefCell<I>,
+}
+
+impl<I> CloneReiterate<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        CloneReiterate {
+            poisoned: Cell::new(false),
+            cache: RefCell::new(Vec::new()),
+            iter: RefCell::new(iter.into_iter()),
+        }
+    }
+
+    /// Create a `CloneReiterate` from items cached earlier, and the iterator to pull the
+    /// following items from, like `Reiterate::from_parts()`.
+    ///
+    /// ```rust
+    /// use reiterate::CloneReiterate;
+    ///
+    /// let reiterate = CloneReiterate::from_parts(vec![(1, 'a')], vec![(2, 'b')]);
+    /// assert_eq!(reiterate.cached_len().ready(), Some(1));
+    /// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), [(1, 'a'), (2, 'b')]);
+    /// ```
+    pub fn from_parts<T>(cache: Vec<I::Item>, iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let mut reiterate = CloneReiterate::new(iter);
+        *reiterate.cache.get_mut() = cache;
+        reiterate
+    }
+
+    /// Whether the underlying iterator has panicked while an item was being pulled from
+    /// it, like for `CopyReiterate::is_poisoned()`
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.get()
+    }
+
+    /// The number of items pulled from the underlying iterator so far, or `Contended`
+    /// during a pull
+    pub fn cached_len(&self) -> ContentionAware<usize> {
+        ContentionAware::borrow(&self.cache).map(|cache| cache.len())
+    }
+
+    /// Get a clone of the item at `idx`, pulling from the underlying iterator if the handle
+    /// asking for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<I::Item> {
+        if let Some(val) = self.cache.borrow().get(idx) {
+            return Some(val.clone());
+        }
+        let val = poison_on_unwind(&self.poisoned, || self.iter.borrow_mut().next())?;
+        self.cache.borrow_mut().push(val.clone());
+        Some(val)
+    }
+}
+
+/// The underlying iterator of a `CloneReiterate` produced by `CloneReiterate::new_rc()`
+pub type RcSource<I> = Map<I, fn(<I as Iterator>::Item) -> Rc<<I as Iterator>::Item>>;
+
+/// The underlying iterator of a `CloneReiterate` produced by `CloneReiterate::new_arc()`
+pub type ArcSource<I> = Map<I, fn(<I as Iterator>::Item) -> Arc<<I as Iterator>::Item>>;
+
+/// A `CloneReiterate` yielding reference-counted items, created by
+/// `CloneReiterate::new_rc()`
+pub type SharedReiterate<I> = CloneReiterate<RcSource<I>>;
+
+impl<I> CloneReiterate<RcSource<I>>
+where
+    I: Iterator,
+{
+    /// Create a `CloneReiterate` that wraps each item in an `Rc` as it gets pulled, and
+    /// yields clones of the `Rc`.
+    ///
+    /// Unlike the references yielded by `Reiterate`, the items can be held onto after the
+    /// handle and the `CloneReiterate` are gone, and replaying only bumps reference counts.
+    ///
+    /// ```rust
+    /// use reiterate::{CloneReiterate, SharedReiterate};
+    /// use std::rc::Rc;
+    ///
+    /// let kept: Vec<Rc<[u8; 1024]>> = {
+    ///     let blocks: SharedReiterate<_> = CloneReiterate::new_rc((0..4).map(|i| [i; 1024]));
+    ///     let first: Vec<_> = blocks.into_iter().collect();
+    ///     let second: Vec<_> = blocks.into_iter().collect();
+    ///     assert!(first.iter().zip(&second).all(|(a, b)| Rc::ptr_eq(a, b)));
+    ///     first
+    /// };
+    /// assert_eq!(kept[3][0], 3);
+    /// assert_eq!(Rc::strong_count(&kept[3]), 1);
+    /// ```
+    pub fn new_rc<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        CloneReiterate::new(iter.into_iter().map(Rc::new as fn(I::Item) -> Rc<I::Item>))
+    }
+}
+
+impl<I> CloneReiterate<ArcSource<I>>
+where
+    I: Iterator,
+{
+    /// Create a `CloneReiterate` that wraps each item in an `Arc` as it gets pulled, like
+    /// `new_rc()`, for items to be sent to other threads.
+    ///
+    /// ```rust
+    /// use reiterate::CloneReiterate;
+    /// use std::thread;
+    ///
+    /// let rows = CloneReiterate::new_arc((0..3).map(|i| vec![i; 100]));
+    /// let workers: Vec<_> = rows
+    ///     .into_iter()
+    ///     .map(|row| thread::spawn(move || row.iter().sum::<i32>()))
+    ///     .collect();
+    /// let sums: Vec<_> = workers.into_iter().map(|w| w.join().unwrap()).collect();
+    /// assert_eq!(sums, [0, 100, 200]);
+    /// ```
+    pub fn new_arc<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        CloneReiterate::new(
+            iter.into_iter()
+                .map(Arc::new as fn(I::Item) -> Arc<I::Item>),
+        )
+    }
+}
+
+impl<'a, I> IntoIterator for &'a CloneReiterate<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type IntoIter = CloneReiterator<'a, I>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CloneReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I> Reiterable for CloneReiterate<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = CloneReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> CloneReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&CloneReiterate` instance
+pub struct CloneReiterator<'a, I>
+where
+    I: Iterator,
+{
+    iterable: &'a CloneReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for CloneReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Neither can be looked at during a pull, like from a closure of the source
+        let (cache, iter) = match (
+            self.iterable.cache.try_borrow(),
+            self.iterable.iter.try_borrow(),
+        ) {
+            (Ok(cache), Ok(iter)) => (cache, iter),
+            _ => return (0, None),
+        };
+        let cached = cache.len().saturating_sub(self.curr);
+        remaining_hint(cached, iter.size_hint(), usize::MAX, self.curr)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        // Skip over cached items without cloning them
+        let cached = self.iterable.cache.borrow().len();
+        let skip = n.min(cached.saturating_sub(self.curr));
+        self.curr += skip;
+        for _ in skip..n {
+            self.next()?;
+        }
+        self.next()
+    }
+}
+
+/// Serialize the cached items as a sequence, to be resumed with
+/// `CloneReiterate::from_serialized()`. Like for `CopyReiterate`, serializing fails during
+/// a pull.
+#[cfg(feature = "serde")]
+impl<I> serde::Serialize for CloneReiterate<I>
+where
+    I: Iterator,
+    I::Item: Clone + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+
+        let cache = self
+            .cache
+            .try_borrow()
+            .map_err(|_| S::Error::custom("the CloneReiterate is being pulled from"))?;
+        serializer.collect_seq(cache.iter())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+/// An iterator that can be dropped before the value holding it, used as the source of
+/// `Reiterate::closable`
+pub struct Closable<I> {
+    // `None` once closed
+    iter: Option<I>,
+}
+
+impl<I> Closable<I> {
+    /// Wrap `iter`, for instance to queue it as another source of a closable `Reiterate`
+    pub fn new(iter: I) -> Self {
+        Closable { iter: Some(iter) }
+    }
+
+    /// Drop the underlying iterator, which ends this one
+    fn close(&mut self) {
+        // Taken out first, so that it stays closed if dropping the iterator panics
+        let iter = self.iter.take();
+        drop(iter);
+    }
+}
+
+impl<I: Iterator> Iterator for Closable<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.as_mut()?.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.iter {
+            Some(iter) => iter.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<I> Reiterate<Closable<I>>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Create a `Reiterate` whose source can be dropped with `close_source()` as soon as
+    /// it is no longer needed, for sources that hold a resource such as a file or a
+    /// database cursor.
+    pub fn closable<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Reiterate::new(Closable::new(iter.into_iter()))
+    }
+
+    /// Drop the source and any queued sources now, rather than with the `Reiterate`,
+    /// keeping the cached items for replays.
+    ///
+    /// If the source hadn't ended, the stream ends at the items cached so far, as with
+    /// `truncate()`. This works on a poisoned `Reiterate` too, and if dropping the source
+    /// panics, the `Reiterate` is closed all the same and isn't poisoned, since the
+    /// source was taken out of it first.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// struct Cursor(Rc<Cell<bool>>, u32);
+    /// impl Iterator for Cursor {
+    ///     type Item = Box<u32>;
+    ///     fn next(&mut self) -> Option<Box<u32>> {
+    ///         self.1 += 1;
+    ///         Some(Box::new(self.1))
+    ///     }
+    /// }
+    /// impl Drop for Cursor {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(true);
+    ///     }
+    /// }
+    ///
+    /// let dropped = Rc::new(Cell::new(false));
+    /// let mut rows = Reiterate::closable(Cursor(dropped.clone(), 0));
+    /// assert_eq!(rows.into_iter().take(3).copied().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert!(!rows.is_closed());
+    ///
+    /// rows.close_source();
+    /// assert!(dropped.get());
+    /// assert!(rows.is_closed());
+    /// // the stream ends at what was cached
+    /// for _ in 0..2 {
+    ///     assert_eq!(rows.into_iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    /// }
+    /// ```
+    pub fn close_source(&mut self) {
+        self.truncate(self.cache.len());
+        self.iter.get_mut().close();
+        self.queued.clear();
+    }
+
+    /// Whether `close_source()` was called
+    pub fn is_closed(&self) -> bool {
+        // Nothing is pulled from a closed source, so it is open while it is being pulled
+        self.iter.try_borrow().is_ok_and(|iter| iter.iter.is_none())
+    }
+}
+
+impl<I> CopyReiterate<Closable<I>>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// Create a `CopyReiterate` whose source can be dropped with `close_source()`, like
+    /// `Reiterate::closable()`.
+    pub fn closable<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        CopyReiterate::new(Closable::new(iter.into_iter()))
+    }
+
+    /// Drop the source and any queued sources now, keeping the cached items for replays.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::close_source()`, and ends the stream
+    /// at the items cached so far in the same way.
+    ///
+    /// ```rust
+    /// use reiterate::{Closable, CopyReiterate};
+    /// use std::cell::Cell;
+    ///
+    /// struct Counted<'a>(&'a Cell<u32>, std::ops::Range<u8>);
+    /// impl Iterator for Counted<'_> {
+    ///     type Item = u8;
+    ///     fn next(&mut self) -> Option<u8> {
+    ///         self.1.next()
+    ///     }
+    /// }
+    /// impl Drop for Counted<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Cell::new(0);
+    /// let mut bytes = CopyReiterate::closable(Counted(&drops, 0..10));
+    /// bytes.queue_source(Closable::new(Counted(&drops, 10..20)));
+    /// assert_eq!(bytes.into_iter().nth(4), Some(4));
+    ///
+    /// bytes.close_source();
+    /// assert_eq!(drops.get(), 2);
+    /// assert!(bytes.is_closed());
+    /// assert_eq!(bytes.into_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    /// assert_eq!(bytes.into_iter().size_hint(), (5, Some(5)));
+    ///
+    /// // closing again, or after the source has ended, keeps every cached item
+    /// bytes.close_source();
+    /// assert_eq!(drops.get(), 2);
+    /// assert_eq!(bytes.into_iter().count(), 5);
+    /// ```
+    pub fn close_source(&mut self) {
+        let cached = self.inner.get_mut().cache.len();
+        self.truncate(cached);
+        self.inner.get_mut().iter.close();
+        self.queued.clear();
+    }
+
+    /// Whether `close_source()` was called
+    pub fn is_closed(&self) -> bool {
+        // Nothing is pulled from a closed source, so it is open while it is being pulled
+        self.inner
+            .try_borrow()
+            .is_ok_and(|inner| inner.iter.iter.is_none())
+    }
+}
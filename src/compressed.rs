@@ -0,0 +1,223 @@
+use crate::Reiterable;
+
+use lz4_flex::{compress_prepend_size, decompress_size_prepended};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+// How many decompressed chunks are kept around by default
+const LRU_CAPACITY: usize = 4;
+
+/// An adaptor around an iterator of byte chunks that can produce multiple iterators
+/// sharing an underlying cache, keeping the cached chunks compressed with LZ4.
+///
+/// Since chunks have to be decompressed to be read, iterators can't borrow them from
+/// the cache. Instead they yield `Rc<[u8]>`s holding decompressed copies. The most
+/// recently used chunks are kept decompressed, so that iterators reading the same part
+/// of the stream close together only decompress each chunk once.
+///
+/// ```rust
+/// use reiterate::CompressedReiterate;
+///
+/// let chunks = vec![vec![0u8; 4096].into_boxed_slice(), b"hello".to_vec().into_boxed_slice()];
+/// let reiterate = CompressedReiterate::new(chunks.clone());
+///
+/// let replayed: Vec<_> = reiterate.iter().map(|chunk| chunk.to_vec().into_boxed_slice()).collect();
+/// assert_eq!(replayed, chunks);
+/// assert_eq!(reiterate.uncompressed_bytes(), 4101);
+/// // a page of zeros compresses very well
+/// assert!(reiterate.compressed_bytes() < 100);
+/// ```
+///
+/// Chunks that don't compress are still stored correctly, with a small overhead:
+///
+/// ```rust
+/// use reiterate::CompressedReiterate;
+///
+/// // xorshift, for bytes without any patterns to exploit
+/// let mut state = 0x2545_f491_4f6c_dd1du64;
+/// let mut noise = || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state as u8
+/// };
+/// let chunks: Vec<Vec<u8>> = (0..8).map(|_| (0..1024).map(|_| noise()).collect()).collect();
+///
+/// let reiterate = CompressedReiterate::new(chunks.clone());
+/// for (chunk, original) in reiterate.iter().zip(&chunks) {
+///     assert_eq!(&*chunk, &original[..]);
+/// }
+/// assert_eq!(reiterate.uncompressed_bytes(), 8 * 1024);
+/// assert!(reiterate.compressed_bytes() >= reiterate.uncompressed_bytes());
+/// ```
+pub struct CompressedReiterate<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    iter: RefCell<I>,
+    chunks: RefCell<Vec<Box<[u8]>>>,
+    uncompressed_bytes: Cell<usize>,
+    compressed_bytes: Cell<usize>,
+    // Most recently used first
+    lru: RefCell<VecDeque<(usize, Rc<[u8]>)>>,
+    lru_capacity: usize,
+}
+
+impl<I> CompressedReiterate<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Self::with_lru_capacity(iter, LRU_CAPACITY)
+    }
+
+    /// Create a `CompressedReiterate` keeping up to `capacity` decompressed chunks around.
+    ///
+    /// `new()` keeps 4. With a capacity of 0, every access decompresses.
+    ///
+    /// ```rust
+    /// use reiterate::CompressedReiterate;
+    /// use std::rc::Rc;
+    ///
+    /// let reiterate = CompressedReiterate::with_lru_capacity(vec![b"a", b"b", b"c"], 2);
+    /// let (mut first, mut second) = (reiterate.iter(), reiterate.iter());
+    /// let a = first.next().unwrap();
+    /// // the second access shares the decompressed chunk
+    /// assert!(Rc::ptr_eq(&a, &second.next().unwrap()));
+    ///
+    /// first.by_ref().for_each(drop);
+    /// // "a" has been evicted by "b" and "c", and gets decompressed again
+    /// let again = reiterate.iter().next().unwrap();
+    /// assert_eq!(&*again, b"a");
+    /// assert!(!Rc::ptr_eq(&a, &again));
+    /// ```
+    pub fn with_lru_capacity<T>(iter: T, capacity: usize) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        CompressedReiterate {
+            iter: RefCell::new(iter.into_iter()),
+            chunks: RefCell::new(Vec::new()),
+            uncompressed_bytes: Cell::new(0),
+            compressed_bytes: Cell::new(0),
+            lru: RefCell::new(VecDeque::with_capacity(capacity)),
+            lru_capacity: capacity,
+        }
+    }
+
+    /// The total size of the cached chunks before compression
+    pub fn uncompressed_bytes(&self) -> usize {
+        self.uncompressed_bytes.get()
+    }
+
+    /// The total size of the cached chunks after compression
+    pub fn compressed_bytes(&self) -> usize {
+        self.compressed_bytes.get()
+    }
+
+    /// Get an iterator over the decompressed chunks
+    pub fn iter(&self) -> CompressedReiterator<'_, I> {
+        CompressedReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+
+    /// Get the chunk at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<Rc<[u8]>> {
+        let mut lru = self.lru.borrow_mut();
+        if let Some(pos) = lru.iter().position(|&(i, _)| i == idx) {
+            let entry = lru.remove(pos).unwrap();
+            let chunk = entry.1.clone();
+            lru.push_front(entry);
+            return Some(chunk);
+        }
+
+        let cached = self.chunks.borrow().get(idx).map(|compressed| {
+            decompress_size_prepended(compressed).expect("cached chunks are valid LZ4")
+        });
+        let chunk: Rc<[u8]> = match cached {
+            Some(chunk) => chunk.into(),
+            None => {
+                let val = self.iter.borrow_mut().next()?;
+                let val = val.as_ref();
+                let compressed = compress_prepend_size(val).into_boxed_slice();
+                self.uncompressed_bytes
+                    .set(self.uncompressed_bytes.get() + val.len());
+                self.compressed_bytes
+                    .set(self.compressed_bytes.get() + compressed.len());
+                self.chunks.borrow_mut().push(compressed);
+                val.into()
+            }
+        };
+
+        if self.lru_capacity > 0 {
+            lru.truncate(self.lru_capacity - 1);
+            lru.push_front((idx, chunk.clone()));
+        }
+        Some(chunk)
+    }
+}
+
+impl<'a, I> IntoIterator for &'a CompressedReiterate<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type IntoIter = CompressedReiterator<'a, I>;
+    type Item = Rc<[u8]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I> Reiterable for CompressedReiterate<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item<'a>
+        = Rc<[u8]>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = CompressedReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> CompressedReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.iter()` on a `CompressedReiterate` instance
+pub struct CompressedReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    iterable: &'a CompressedReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for CompressedReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Rc<[u8]>;
+
+    fn next(&mut self) -> Option<Rc<[u8]>> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
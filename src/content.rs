@@ -0,0 +1,369 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Pull the rest of the items, then feed their count and each of them in order into
+    /// `state`.
+    ///
+    /// Only the items matter, so reiterates over the same items hash the same whatever
+    /// their source, and the same as a `CopyReiterate` of the targets.
+    ///
+    /// ```rust
+    /// use reiterate::{CopyReiterate, Reiterate};
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// fn hash_of(f: impl FnOnce(&mut DefaultHasher)) -> u64 {
+    ///     let mut state = DefaultHasher::new();
+    ///     f(&mut state);
+    ///     state.finish()
+    /// }
+    ///
+    /// let from_vec = Reiterate::new(vec![Box::new(1), Box::new(2)]);
+    /// let from_range = Reiterate::new((1..3).map(Box::new));
+    /// let copied = CopyReiterate::new(1..3);
+    /// let hash = hash_of(|state| from_vec.content_hash(state));
+    /// assert_eq!(hash, hash_of(|state| from_range.content_hash(state)));
+    /// assert_eq!(hash, hash_of(|state| copied.content_hash(state)));
+    ///
+    /// // the count is part of the hash, so empty items aren't lost
+    /// let empty = Reiterate::new(Vec::<String>::new());
+    /// let one_empty = Reiterate::new(vec![String::new()]);
+    /// let two_empty = Reiterate::new(vec![String::new(), String::new()]);
+    /// let hashes = [&empty, &one_empty, &two_empty].map(|r| hash_of(|state| r.content_hash(state)));
+    /// assert_ne!(hashes[0], hashes[1]);
+    /// assert_ne!(hashes[1], hashes[2]);
+    /// assert_eq!(hashes[0], hash_of(|state| Reiterate::new(Vec::<String>::new()).content_hash(state)));
+    /// ```
+    pub fn content_hash<H: Hasher>(&self, state: &mut H)
+    where
+        <I::Item as Deref>::Target: Hash,
+    {
+        state.write_usize(self.into_iter().count());
+        for item in self {
+            item.hash(state);
+        }
+    }
+
+    /// Pull the rest of the items of both reiterates, as far as needed, and compare them
+    /// in order.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let words = Reiterate::new(vec!["a".to_string(), "b".to_string()]);
+    /// let chars = Reiterate::new("ab".chars().map(String::from));
+    /// assert!(words.content_eq(&chars));
+    /// words.queue_source(vec!["c".to_string()]);
+    /// assert!(!words.content_eq(&chars));
+    /// ```
+    pub fn content_eq<J>(&self, other: &Reiterate<J>) -> bool
+    where
+        J: Iterator + ?Sized,
+        J::Item: ReiterableItem,
+        <I::Item as Deref>::Target: PartialEq<<J::Item as Deref>::Target>,
+    {
+        self.into_iter().eq(other)
+    }
+
+    /// Compare the items of both reiterates in order, pulling them only as far as the
+    /// first difference.
+    ///
+    /// Both stay usable afterwards, with the items pulled cached as usual, and a
+    /// reiterate can be compared with itself.
+    ///
+    /// ```rust
+    /// use reiterate::{Divergence, Reiterate};
+    ///
+    /// let golden = Reiterate::new(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// let output = Reiterate::new("abxy".chars().map(String::from));
+    /// assert_eq!(golden.diff(&output), Divergence::FirstDiffersAt(2));
+    /// assert_eq!(output.into_iter().count(), 4);
+    ///
+    /// let prefix = Reiterate::new(vec!["a".to_string()]);
+    /// assert_eq!(golden.diff(&prefix), Divergence::LengthMismatch { shorter_len: 1 });
+    /// assert_eq!(prefix.diff(&golden), Divergence::LengthMismatch { shorter_len: 1 });
+    /// assert_eq!(golden.diff(&golden), Divergence::Equal);
+    ///
+    /// // comparing a stream with itself pulls it through both sides
+    /// let fresh = Reiterate::new((0..5).map(Box::new));
+    /// assert_eq!(fresh.diff(&fresh), Divergence::Equal);
+    /// ```
+    ///
+    /// Only the items needed are pulled:
+    ///
+    /// ```rust
+    /// use reiterate::{Divergence, Reiterate};
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let endless = Reiterate::new((0..).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let other = Reiterate::new([0, 1, 2, 7].map(Box::new));
+    /// assert_eq!(endless.diff(&other), Divergence::FirstDiffersAt(3));
+    /// assert_eq!(pulls.get(), 4);
+    /// ```
+    pub fn diff<J>(&self, other: &Reiterate<J>) -> Divergence
+    where
+        J: Iterator + ?Sized,
+        J::Item: ReiterableItem,
+        <I::Item as Deref>::Target: PartialEq<<J::Item as Deref>::Target>,
+    {
+        self.diff_by(other, |a, b| a == b)
+    }
+
+    /// Compare the items of both reiterates in order with `eq`, pulling them only as far
+    /// as the first difference, like `diff()`.
+    ///
+    /// ```rust
+    /// use reiterate::{Divergence, Reiterate};
+    ///
+    /// let lines = Reiterate::new(vec!["Hello", "World", "again"]);
+    /// let shouted = Reiterate::new(vec!["HELLO", "WORLD"]);
+    /// let diff = lines.diff_by(&shouted, |a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(diff, Divergence::LengthMismatch { shorter_len: 2 });
+    /// ```
+    pub fn diff_by<J>(
+        &self,
+        other: &Reiterate<J>,
+        eq: impl FnMut(&<I::Item as Deref>::Target, &<J::Item as Deref>::Target) -> bool,
+    ) -> Divergence
+    where
+        J: Iterator + ?Sized,
+        J::Item: ReiterableItem,
+    {
+        Divergence::between(self.into_iter(), other.into_iter(), eq)
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Pull the rest of the items, then feed their count and each of them in order into
+    /// `state`.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::content_hash()`.
+    pub fn content_hash<H: Hasher>(&self, state: &mut H)
+    where
+        I::Item: Hash,
+    {
+        // Both passes have to see every item
+        self.stop_streaming();
+        state.write_usize(self.into_iter().count());
+        for item in self {
+            item.hash(state);
+        }
+    }
+
+    /// Pull the rest of the items of both reiterates, as far as needed, and compare them
+    /// in order.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::content_eq()`.
+    pub fn content_eq<J>(&self, other: &CopyReiterate<J>) -> bool
+    where
+        J: Iterator + ?Sized,
+        J::Item: Copy,
+        I::Item: PartialEq<J::Item>,
+    {
+        self.into_iter().eq(other)
+    }
+
+    /// Compare the items of both reiterates in order, pulling them only as far as the
+    /// first difference.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::diff()`.
+    ///
+    /// ```rust
+    /// use reiterate::{CopyReiterate, Divergence};
+    ///
+    /// let expected = CopyReiterate::new("abc".chars());
+    /// assert_eq!(expected.diff(&CopyReiterate::new("abd".chars())), Divergence::FirstDiffersAt(2));
+    /// assert_eq!(expected.diff(&expected), Divergence::Equal);
+    /// ```
+    pub fn diff<J>(&self, other: &CopyReiterate<J>) -> Divergence
+    where
+        J: Iterator + ?Sized,
+        J::Item: Copy,
+        I::Item: PartialEq<J::Item>,
+    {
+        self.diff_by(other, |a, b| a == b)
+    }
+
+    /// Compare the items of both reiterates in order with `eq`, pulling them only as far
+    /// as the first difference.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::diff_by()`.
+    pub fn diff_by<J>(
+        &self,
+        other: &CopyReiterate<J>,
+        mut eq: impl FnMut(&I::Item, &J::Item) -> bool,
+    ) -> Divergence
+    where
+        J: Iterator + ?Sized,
+        J::Item: Copy,
+    {
+        Divergence::between(self.into_iter(), other.into_iter(), |a, b| eq(&a, &b))
+    }
+}
+
+/// Where two streams stop having the same items, as found by `diff()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Divergence {
+    /// Both have the same items
+    Equal,
+    /// Both have an item at this index, and they differ
+    FirstDiffersAt(usize),
+    /// One is a strict prefix of the other
+    LengthMismatch { shorter_len: usize },
+}
+
+impl Divergence {
+    /// Compare `a` and `b` item by item, stopping at the first difference
+    fn between<A, B>(
+        mut a: impl Iterator<Item = A>,
+        mut b: impl Iterator<Item = B>,
+        mut eq: impl FnMut(A, B) -> bool,
+    ) -> Self {
+        let mut idx = 0;
+        loop {
+            match (a.next(), b.next()) {
+                (Some(a), Some(b)) => {
+                    if !eq(a, b) {
+                        return Divergence::FirstDiffersAt(idx);
+                    }
+                    idx += 1;
+                }
+                (None, None) => return Divergence::Equal,
+                _ => return Divergence::LengthMismatch { shorter_len: idx },
+            }
+        }
+    }
+}
+
+/// A reiterate keyed by its items, to deduplicate whole sequences in a `HashMap` or a
+/// `HashSet`.
+///
+/// `Hash` and `Eq` are implemented with `content_hash()` and `content_eq()`, so every item
+/// is pulled the first time the key is hashed or compared. Changing the items afterwards,
+/// e.g. with `queue_source()`, is a logic error, like for any other key with interior
+/// mutability.
+///
+/// ```rust
+/// use reiterate::{ContentKey, CopyReiterate};
+/// use std::collections::HashSet;
+///
+/// let sequences: Vec<Box<dyn Iterator<Item = u8>>> = vec![
+///     Box::new(vec![1, 2, 3].into_iter()),
+///     Box::new(1..4),
+///     Box::new(std::iter::empty()),
+///     Box::new(b"\x01\x02\x03".iter().copied()),
+///     Box::new(std::iter::empty()),
+/// ];
+/// let mut seen = HashSet::new();
+/// let fresh: Vec<bool> = sequences
+///     .into_iter()
+///     .map(|seq| seen.insert(ContentKey::new(CopyReiterate::new(seq))))
+///     .collect();
+/// assert_eq!(fresh, [true, false, true, false, false]);
+/// assert_eq!(seen.len(), 2);
+/// ```
+///
+/// Keys over different sources still compare by their items:
+///
+/// ```rust
+/// use reiterate::{ContentKey, Reiterate};
+///
+/// let from_vec = ContentKey::new(Reiterate::new(vec![Box::new(1), Box::new(2)]));
+/// let from_range = ContentKey::new(Reiterate::new((1..3).map(Box::new)));
+/// assert!(from_vec == from_range);
+/// let empty = ContentKey::new(Reiterate::new(Vec::<Box<i32>>::new()));
+/// assert!(empty == ContentKey::new(Reiterate::new(std::iter::empty::<Box<i32>>())));
+/// assert!(empty != from_vec);
+/// ```
+pub struct ContentKey<R: ?Sized>(R);
+
+impl<R> ContentKey<R> {
+    pub fn new(reiterate: R) -> Self {
+        ContentKey(reiterate)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R: ?Sized> Deref for ContentKey<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.0
+    }
+}
+
+impl<I, J> PartialEq<ContentKey<Reiterate<J>>> for ContentKey<Reiterate<I>>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    J: Iterator + ?Sized,
+    J::Item: ReiterableItem,
+    <I::Item as Deref>::Target: PartialEq<<J::Item as Deref>::Target>,
+{
+    fn eq(&self, other: &ContentKey<Reiterate<J>>) -> bool {
+        self.0.content_eq(&other.0)
+    }
+}
+
+impl<I> Eq for ContentKey<Reiterate<I>>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Eq,
+{
+}
+
+impl<I> Hash for ContentKey<Reiterate<I>>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.content_hash(state)
+    }
+}
+
+impl<I, J> PartialEq<ContentKey<CopyReiterate<J>>> for ContentKey<CopyReiterate<I>>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + PartialEq<J::Item>,
+    J: Iterator + ?Sized,
+    J::Item: Copy,
+{
+    fn eq(&self, other: &ContentKey<CopyReiterate<J>>) -> bool {
+        self.0.content_eq(&other.0)
+    }
+}
+
+impl<I> Eq for ContentKey<CopyReiterate<I>>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Eq,
+{
+}
+
+impl<I> Hash for ContentKey<CopyReiterate<I>>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.content_hash(state)
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{remaining_hint, CopyReiterate, CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::cell::{Ref, RefCell};
+use std::fmt;
+use std::ops::Deref;
+
+/// The answer of a read-only method that can't be given while an item is being pulled.
+///
+/// Read-only methods never pull and never panic, even when called during a pull from
+/// the same thread, like from a closure of the underlying iterator. Those that can
+/// always answer, like `Reiterate::cached_len()` or `is_poisoned()`, return their
+/// answer directly. Those that need to look at the underlying iterator, or at the
+/// cache of a `CopyReiterate`, which is kept next to it, return this, with
+/// `Contended` standing for "ask again once the pull is over".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentionAware<T> {
+    /// The answer
+    Ready(T),
+    /// An item is being pulled, and the answer depends on it
+    Contended,
+}
+
+impl<T> ContentionAware<T> {
+    /// The answer, if there is one
+    pub fn ready(self) -> Option<T> {
+        match self {
+            ContentionAware::Ready(val) => Some(val),
+            ContentionAware::Contended => None,
+        }
+    }
+
+    pub fn is_contended(&self) -> bool {
+        matches!(self, ContentionAware::Contended)
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ContentionAware<U> {
+        match self {
+            ContentionAware::Ready(val) => ContentionAware::Ready(f(val)),
+            ContentionAware::Contended => ContentionAware::Contended,
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        self.ready().unwrap_or(default)
+    }
+}
+
+impl<'b, T: ?Sized> ContentionAware<Ref<'b, T>> {
+    /// Borrow `cell`, unless an item is being pulled with it borrowed mutably
+    pub(crate) fn borrow(cell: &'b RefCell<T>) -> Self {
+        cell.try_borrow()
+            .map_or(ContentionAware::Contended, ContentionAware::Ready)
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// The number of cached items, within the logical length set by `truncate()`.
+    ///
+    /// This never pulls, and is always available, even during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(Box::new));
+    /// reiterate.into_iter().nth(3);
+    /// assert_eq!(reiterate.cached_len(), 4);
+    /// reiterate.truncate(2);
+    /// assert_eq!(reiterate.cached_len(), 2);
+    /// ```
+    pub fn cached_len(&self) -> usize {
+        self.visible_len()
+    }
+
+    /// Whether the stream has ended: the underlying iterator and the queued sources
+    /// returned `None` on the last pull, or the logical length set by `truncate()` is
+    /// cached.
+    ///
+    /// This never pulls, so the end is only known once a handle has run into it, even if
+    /// the size hint of the source says there is nothing left. Queuing a source makes the
+    /// stream go on again.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b"]);
+    /// assert_eq!(reiterate.into_iter().take(2).count(), 2);
+    /// assert!(!reiterate.is_exhausted());
+    /// assert_eq!(reiterate.into_iter().count(), 2);
+    /// assert!(reiterate.is_exhausted());
+    ///
+    /// reiterate.queue_source(vec!["c"]);
+    /// assert!(!reiterate.is_exhausted());
+    /// reiterate.truncate(2);
+    /// assert!(reiterate.is_exhausted());
+    /// ```
+    pub fn is_exhausted(&self) -> bool {
+        self.queued.ended() || self.visible_len() >= self.limit.get()
+    }
+
+    /// The size hint of the items left to pull, which only comes from the sources, or
+    /// `Contended` during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, Reiterate};
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(Box::new));
+    /// reiterate.into_iter().nth(3);
+    /// assert_eq!(reiterate.remaining_hint(), ContentionAware::Ready((6, Some(6))));
+    /// reiterate.truncate(5);
+    /// assert_eq!(reiterate.remaining_hint(), ContentionAware::Ready((1, Some(1))));
+    ///
+    /// let unknown = Reiterate::new((0..10).filter(|i| i % 3 == 0).map(Box::new));
+    /// assert_eq!(unknown.remaining_hint(), ContentionAware::Ready((0, Some(10))));
+    /// ```
+    pub fn remaining_hint(&self) -> ContentionAware<(usize, Option<usize>)> {
+        if self.queued.ended() {
+            return ContentionAware::Ready((0, Some(0)));
+        }
+        let (limit, cached) = (self.limit.get(), self.cache.len());
+        ContentionAware::borrow(&self.iter).map(|iter| {
+            let source = self.queued.chain_size_hint(iter.size_hint());
+            remaining_hint(0, source, limit, cached)
+        })
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// The number of cached items, within the logical length set by `truncate()`.
+    ///
+    /// This never pulls. The cache is kept next to the underlying iterator, so it's
+    /// `Contended` during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, CopyReiterate};
+    ///
+    /// let reiterate = CopyReiterate::new(0..10);
+    /// reiterate.into_iter().nth(3);
+    /// assert_eq!(reiterate.cached_len(), ContentionAware::Ready(4));
+    /// ```
+    pub fn cached_len(&self) -> ContentionAware<usize> {
+        self.try_visible_len()
+    }
+
+    /// Whether the stream has ended, like for `Reiterate::is_exhausted()`, or `Contended`
+    /// during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, CopyReiterate};
+    ///
+    /// let reiterate = CopyReiterate::new(0..3);
+    /// assert_eq!(reiterate.is_exhausted(), ContentionAware::Ready(false));
+    /// assert_eq!(reiterate.exhaust(), 3);
+    /// assert_eq!(reiterate.is_exhausted(), ContentionAware::Ready(true));
+    /// ```
+    pub fn is_exhausted(&self) -> ContentionAware<bool> {
+        if self.queued.ended() {
+            return ContentionAware::Ready(true);
+        }
+        let limit = self.limit.get();
+        self.try_visible_len().map(|len| len >= limit)
+    }
+
+    /// The size hint of the items left to pull, like for `Reiterate::remaining_hint()`,
+    /// or `Contended` during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, CopyReiterate};
+    ///
+    /// let reiterate = CopyReiterate::new(0..10);
+    /// reiterate.into_iter().nth(3);
+    /// reiterate.queue_source(10..12);
+    /// assert_eq!(reiterate.remaining_hint(), ContentionAware::Ready((8, Some(8))));
+    /// ```
+    pub fn remaining_hint(&self) -> ContentionAware<(usize, Option<usize>)> {
+        if self.queued.ended() {
+            return ContentionAware::Ready((0, Some(0)));
+        }
+        let limit = self.limit.get();
+        ContentionAware::borrow(&self.inner).map(|inner| {
+            let source = self.queued.chain_size_hint(inner.iter.size_hint());
+            remaining_hint(0, source, limit, inner.cache.len())
+        })
+    }
+
+    /// `visible_len()`, for read-only methods that can be called during a pull
+    pub(crate) fn try_visible_len(&self) -> ContentionAware<usize> {
+        let limit = self.limit.get();
+        ContentionAware::borrow(&self.inner).map(|inner| inner.cache.len().min(limit))
+    }
+}
+
+/// Shows the cached items and whether the stream has ended, without pulling.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let reiterate = Reiterate::new(vec![Box::new(1), Box::new(2)]);
+/// let mut iter = reiterate.into_iter();
+/// iter.next();
+/// assert_eq!(
+///     format!("{:?}", reiterate),
+///     "Reiterate { cached: [1], exhausted: false, poisoned: false, pulling: false }"
+/// );
+/// assert_eq!(format!("{:?}", iter), "Reiterator { position: 1 }");
+///
+/// #[derive(Debug)]
+/// struct Parser {
+///     tokens: Reiterate<std::vec::IntoIter<String>>,
+/// }
+///
+/// let parser = Parser { tokens: Reiterate::new(vec!["fn".to_string()]) };
+/// parser.tokens.exhaust();
+/// assert_eq!(
+///     format!("{:?}", parser),
+///     r#"Parser { tokens: Reiterate { cached: ["fn"], exhausted: true, poisoned: false, pulling: false } }"#
+/// );
+/// ```
+impl<I> fmt::Debug for Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cached: Vec<_> = (0..self.visible_len())
+            .map(|idx| &self.cache[idx])
+            .collect();
+        f.debug_struct("Reiterate")
+            .field("cached", &cached)
+            .field("exhausted", &self.is_exhausted())
+            .field("poisoned", &self.poisoned.get())
+            .field("pulling", &self.iter.try_borrow_mut().is_err())
+            .finish()
+    }
+}
+
+/// Shows the cached items, without pulling, or `Contended` in their place during a pull.
+///
+/// ```rust
+/// use reiterate::CopyReiterate;
+///
+/// let reiterate = CopyReiterate::new("abc".chars());
+/// let mut iter = reiterate.into_iter();
+/// iter.nth(1);
+/// assert_eq!(
+///     format!("{:?}", reiterate),
+///     "CopyReiterate { cached: Ready(['a', 'b']), exhausted: Ready(false), poisoned: false }"
+/// );
+/// assert_eq!(format!("{:?}", iter), "CopyReiterator { position: 2 }");
+/// ```
+impl<I> fmt::Debug for CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = self.limit.get();
+        let cached = ContentionAware::borrow(&self.inner)
+            .map(|inner| inner.cache[..inner.cache.len().min(limit)].to_vec());
+        f.debug_struct("CopyReiterate")
+            .field("cached", &cached)
+            .field("exhausted", &self.is_exhausted())
+            .field("poisoned", &self.poisoned.get())
+            .finish()
+    }
+}
+
+/// Shows the position of the handle: the number of items it has gone past
+impl<'a, I> fmt::Debug for Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reiterator")
+            .field("position", &self.curr)
+            .finish()
+    }
+}
+
+/// Shows the position of the handle, like for `Reiterator`
+impl<'a, I> fmt::Debug for CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyReiterator")
+            .field("position", &self.curr)
+            .finish()
+    }
+}
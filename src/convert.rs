@@ -0,0 +1,247 @@
+use crate::{CopyReiterate, CopyReiterateInner, ReiterableItem, Reiterate};
+
+use elsa::FrozenVec;
+use std::cell::{Cell, RefCell};
+use std::iter::FromIterator;
+use std::ops::Deref;
+use std::vec;
+
+/// The underlying iterator of a `Reiterate` produced by `CopyReiterate::into_reiterate()`
+pub type BoxedSource<I> =
+    std::iter::Map<I, fn(<I as Iterator>::Item) -> Box<<I as Iterator>::Item>>;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Copy,
+{
+    /// Exhaust the underlying iterator and copy the items into a fresh `CopyReiterate`.
+    ///
+    /// The new cache holds the dereferenced values directly, which makes replaying cheaper.
+    /// `self` stays usable; use `into_copy()` to free the original items instead.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..5).map(Box::new));
+    /// let copy = reiterate.to_copy();
+    /// assert_eq!(copy.into_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    /// assert_eq!(reiterate.into_iter().count(), 5);
+    /// ```
+    pub fn to_copy(&self) -> CopyReiterate<vec::IntoIter<<I::Item as Deref>::Target>> {
+        self.iter_tail().for_each(drop);
+        let items: Vec<_> = self
+            .cache
+            .iter()
+            .take(self.visible_len())
+            .copied()
+            .collect();
+        CopyReiterate::new(items)
+    }
+
+    /// Exhaust the underlying iterator and move the items into a fresh `CopyReiterate`,
+    /// dropping the original heap-allocated items.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::rc::Rc;
+    ///
+    /// let first = Rc::new(1);
+    /// let reiterate = Reiterate::new(vec![first.clone(), Rc::new(2)]);
+    /// assert_eq!(Rc::strong_count(&first), 2);
+    ///
+    /// let copy = reiterate.into_copy();
+    /// assert_eq!(Rc::strong_count(&first), 1);
+    /// assert_eq!(copy.into_iter().collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    pub fn into_copy(self) -> CopyReiterate<vec::IntoIter<<I::Item as Deref>::Target>>
+    where
+        I: Sized,
+    {
+        self.iter_tail().for_each(drop);
+        let len = self.visible_len();
+        let items: Vec<_> = self
+            .cache
+            .into_vec()
+            .into_iter()
+            .take(len)
+            .map(|item| *item)
+            .collect();
+        CopyReiterate::new(items)
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Convert into a `Reiterate` of boxed items, for APIs that expect one.
+    ///
+    /// The cached items are boxed right away, while the rest are boxed as they get
+    /// pulled, so this doesn't exhaust the underlying iterator.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let copy = CopyReiterate::new(0..5);
+    /// copy.into_iter().take(2).for_each(drop);
+    ///
+    /// let reiterate = copy.into_reiterate();
+    /// assert_eq!(reiterate.iter_tail().next(), Some(&2));
+    /// assert_eq!(reiterate.into_iter().cloned().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    /// ```
+    pub fn into_reiterate(self) -> Reiterate<BoxedSource<I>>
+    where
+        I: Sized,
+    {
+        let CopyReiterateInner { cache, iter } = self.inner.into_inner();
+        let boxed = Box::new as fn(I::Item) -> Box<I::Item>;
+        // SAFETY: the queued sources have the type of `iter`, and are converted like it
+        let queued = unsafe { self.queued.map_as(|queued: I| queued.map(boxed)) };
+        let cache: Vec<_> = cache.into_iter().map(Box::new).collect();
+        Reiterate {
+            cache: FrozenVec::from(cache),
+            poisoned: Cell::new(self.poisoned.get()),
+            sorted: Default::default(),
+            measurer: None,
+            heap_size: Cell::new(0),
+            addresses: RefCell::default(),
+            aliasing: None,
+            limit: Cell::new(self.limit.get()),
+            generation: 0,
+            primary: Cell::new(self.primary.get()),
+            stats: None,
+            slow_path: Cell::new(self.limit.get() != usize::MAX),
+            queued,
+            iter: RefCell::new(iter.map(boxed)),
+        }
+    }
+}
+
+/// A `Reiterate` whose underlying iterator type is erased, as produced by `.into()`, for
+/// when it has to be named.
+///
+/// ```rust
+/// use reiterate::{BoxedReiterate, Reiterate};
+///
+/// struct Config {
+///     lines: BoxedReiterate<'static, String>,
+/// }
+///
+/// fn parse(text: &'static str) -> Config {
+///     let lines = text.lines().map(str::trim).filter(|l| !l.is_empty()).map(String::from);
+///     Config { lines: Reiterate::new(lines).into() }
+/// }
+///
+/// let config = parse("a = 1\n\n  b = 2\n");
+/// assert_eq!(config.lines.into_iter().collect::<Vec<_>>(), ["a = 1", "b = 2"]);
+/// assert_eq!(config.lines.cached_len(), 2);
+/// ```
+pub type BoxedReiterate<'a, T> = Box<Reiterate<dyn Iterator<Item = T> + 'a>>;
+
+/// A `CopyReiterate` whose underlying iterator type is erased, as produced by `.into()`,
+/// for when it has to be named
+pub type BoxedCopyReiterate<'a, T> = Box<CopyReiterate<dyn Iterator<Item = T> + 'a>>;
+
+/// Erase the type of the underlying iterator, e.g. to keep it out of a public API.
+///
+/// Every method that doesn't move the underlying iterator is available on the result.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// pub struct Events {
+///     events: Box<Reiterate<dyn Iterator<Item = Box<u32>>>>,
+/// }
+///
+/// let evens = Events { events: Reiterate::new((0..10).filter(|i| i % 2 == 0).map(Box::new)).into() };
+/// let odds = Events { events: Reiterate::new(vec![Box::new(1), Box::new(3)]).into() };
+/// for events in [evens, odds] {
+///     let first: Vec<u32> = events.events.into_iter().copied().collect();
+///     assert_eq!(events.events.into_iter().copied().collect::<Vec<_>>(), first);
+/// }
+/// ```
+impl<'a, I> From<Reiterate<I>> for Box<Reiterate<dyn Iterator<Item = I::Item> + 'a>>
+where
+    I: Iterator + 'a,
+{
+    fn from(reiterate: Reiterate<I>) -> Self {
+        let raw = Box::into_raw(Box::new(reiterate));
+        // The coercion only builds `I`'s vtable, the address stays that of the whole struct
+        let erased = raw as *mut I as *mut (dyn Iterator<Item = I::Item> + 'a);
+        // SAFETY: `Reiterate` is `repr(C)` with the source last, so `Reiterate<I>` is laid
+        // out like `Reiterate<dyn Iterator>` with `I`'s vtable, and the other fields have
+        // the same types since `I::Item` is the same
+        unsafe { Box::from_raw(erased as *mut Reiterate<dyn Iterator<Item = I::Item> + 'a>) }
+    }
+}
+
+/// Erase the type of the underlying iterator, e.g. to keep it out of a public API.
+///
+/// ```rust
+/// use reiterate::CopyReiterate;
+///
+/// let sources: Vec<Box<CopyReiterate<dyn Iterator<Item = u32>>>> = vec![
+///     CopyReiterate::new(0..3).into(),
+///     CopyReiterate::new(vec![7, 8]).into(),
+/// ];
+/// for _ in 0..2 {
+///     let items: Vec<u32> = sources.iter().flat_map(|s| s.into_iter()).collect();
+///     assert_eq!(items, [0, 1, 2, 7, 8]);
+/// }
+/// ```
+impl<'a, I> From<CopyReiterate<I>> for Box<CopyReiterate<dyn Iterator<Item = I::Item> + 'a>>
+where
+    I: Iterator + 'a,
+{
+    fn from(reiterate: CopyReiterate<I>) -> Self {
+        let raw = Box::into_raw(Box::new(reiterate));
+        let erased = raw as *mut I as *mut (dyn Iterator<Item = I::Item> + 'a);
+        // SAFETY: like for `Reiterate`, `CopyReiterate` and `CopyReiterateInner` are both
+        // `repr(C)` with the source last
+        unsafe { Box::from_raw(erased as *mut CopyReiterate<dyn Iterator<Item = I::Item> + 'a>) }
+    }
+}
+
+/// Collect the items into the cache right away, so that handles only replay them.
+///
+/// The underlying iterator is an empty `vec::IntoIter`, and `queue_source()` can add more
+/// items after the collected ones.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let names: Reiterate<_> = ["ada", "grace"].iter().map(|name| name.to_uppercase()).collect();
+/// assert_eq!(names.cached_len(), 2);
+/// for _ in 0..2 {
+///     assert_eq!(names.into_iter().collect::<Vec<_>>(), ["ADA", "GRACE"]);
+/// }
+/// ```
+impl<T> FromIterator<T> for Reiterate<vec::IntoIter<T>>
+where
+    T: ReiterableItem,
+{
+    fn from_iter<S: IntoIterator<Item = T>>(iter: S) -> Self {
+        Reiterate::from_parts(iter.into_iter().collect(), Vec::new())
+    }
+}
+
+/// Collect the items into the cache right away, like for `Reiterate`.
+///
+/// ```rust
+/// use reiterate::CopyReiterate;
+///
+/// let squares: CopyReiterate<_> = (0..5).map(|i| i * i).collect();
+/// assert_eq!(squares.cached_len().ready(), Some(5));
+/// assert_eq!(squares.into_iter().sum::<i32>(), 30);
+/// ```
+impl<T> FromIterator<T> for CopyReiterate<vec::IntoIter<T>>
+where
+    T: Copy,
+{
+    fn from_iter<S: IntoIterator<Item = T>>(iter: S) -> Self {
+        CopyReiterate::from_parts(iter.into_iter().collect(), Vec::new())
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{CopyReiterate, CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get an iterator that repeats the items endlessly.
+    ///
+    /// The first lap pulls from the underlying iterator as usual, and every later lap
+    /// replays the cache, so unlike `Iterator::cycle` the source runs only once and
+    /// doesn't need to be `Clone`. Once the end of the stream has been seen, `nth()`
+    /// wraps around in constant time.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let workers = vec!["alice", "bob", "carol"];
+    /// let reiterate = Reiterate::new(workers.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+    ///
+    /// let assigned: Vec<_> = reiterate.cycle_view().take(10).collect();
+    /// assert_eq!(
+    ///     assigned,
+    ///     ["alice", "bob", "carol", "alice", "bob", "carol", "alice", "bob", "carol", "alice"]
+    /// );
+    /// assert_eq!(pulls.get(), 3);
+    ///
+    /// let mut cycle = reiterate.cycle_view();
+    /// assert_eq!(cycle.nth(3_000_000_002), Some("carol"));
+    /// assert_eq!(cycle.next(), Some("alice"));
+    /// assert_eq!(pulls.get(), 3);
+    /// ```
+    ///
+    /// An empty stream stays empty:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(Vec::<String>::new());
+    /// let mut cycle = reiterate.cycle_view();
+    /// assert_eq!(cycle.next(), None);
+    /// assert_eq!(cycle.next(), None);
+    /// assert_eq!(cycle.nth(10), None);
+    /// ```
+    pub fn cycle_view(&self) -> CycleReiterator<'_, I> {
+        CycleReiterator {
+            iter: self.into_iter(),
+            len: None,
+        }
+    }
+}
+
+/// An endlessly repeating iterator, produced by calling `.cycle_view()` on a `Reiterate`
+pub struct CycleReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: Reiterator<'a, I>,
+    // The length of the stream, once a lap has reached its end
+    len: Option<usize>,
+}
+
+impl<'a, I> Iterator for CycleReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            Some(0) => None,
+            Some(len) => {
+                if self.iter.curr == len {
+                    self.iter.curr = 0;
+                }
+                self.iter.next()
+            }
+            None => self.iter.next().or_else(|| {
+                self.len = Some(self.iter.curr);
+                // Don't pull from the source again if it was empty
+                if self.iter.curr == 0 {
+                    return None;
+                }
+                self.iter.curr = 0;
+                self.iter.next()
+            }),
+        }
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        loop {
+            if let Some(len) = self.len {
+                if len == 0 {
+                    return None;
+                }
+                self.iter.curr = (self.iter.curr + n % len) % len;
+                return self.next();
+            }
+            if n == 0 {
+                return self.next();
+            }
+            self.next()?;
+            n -= 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len {
+            Some(0) => (0, Some(0)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get an iterator that repeats the items endlessly.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::cycle_view()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = CopyReiterate::new((0..3).inspect(|_| pulls.set(pulls.get() + 1)));
+    ///
+    /// let laps: Vec<_> = reiterate.cycle_view().take(10).collect();
+    /// assert_eq!(laps, [0, 1, 2, 0, 1, 2, 0, 1, 2, 0]);
+    /// assert_eq!(pulls.get(), 3);
+    ///
+    /// let mut cycle = reiterate.cycle_view();
+    /// assert_eq!(cycle.nth(usize::MAX), Some(0));
+    /// assert_eq!(cycle.next(), Some(1));
+    ///
+    /// let empty = CopyReiterate::new(0..0);
+    /// assert_eq!(empty.cycle_view().nth(2), None);
+    /// ```
+    pub fn cycle_view(&self) -> CopyCycleReiterator<'_, I> {
+        self.stop_streaming();
+        CopyCycleReiterator {
+            iter: self.into_iter(),
+            len: None,
+        }
+    }
+}
+
+/// An endlessly repeating iterator, produced by calling `.cycle_view()` on a `CopyReiterate`
+pub struct CopyCycleReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: CopyReiterator<'a, I>,
+    // The length of the stream, once a lap has reached its end
+    len: Option<usize>,
+}
+
+impl<'a, I> Iterator for CopyCycleReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.len {
+            Some(0) => None,
+            Some(len) => {
+                if self.iter.curr == len {
+                    self.iter.curr = 0;
+                }
+                self.iter.next()
+            }
+            None => self.iter.next().or_else(|| {
+                self.len = Some(self.iter.curr);
+                // Don't pull from the source again if it was empty
+                if self.iter.curr == 0 {
+                    return None;
+                }
+                self.iter.curr = 0;
+                self.iter.next()
+            }),
+        }
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<I::Item> {
+        loop {
+            if let Some(len) = self.len {
+                if len == 0 {
+                    return None;
+                }
+                self.iter.curr = (self.iter.curr + n % len) % len;
+                return self.next();
+            }
+            if n == 0 {
+                return self.next();
+            }
+            self.next()?;
+            n -= 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len {
+            Some(0) => (0, Some(0)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
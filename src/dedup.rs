@@ -0,0 +1,314 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+
+/// The comparison used by `dedup_view()`
+pub type TargetEq<T> = fn(&T, &T) -> bool;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a view of the items with runs of equal items collapsed, like `slice::dedup`.
+    ///
+    /// The indices of the kept items are remembered, so each item is compared once no
+    /// matter how many times the view is iterated. Skipped items are still cached for
+    /// other handles.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "a", "a", "a", "b", "a", "b", "c", "c"]);
+    /// let dedup = reiterate.dedup_view();
+    /// for _ in 0..2 {
+    ///     assert_eq!(dedup.iter().collect::<Vec<_>>(), ["a", "b", "a", "b", "c"]);
+    /// }
+    /// assert_eq!(reiterate.into_iter().count(), 9);
+    /// ```
+    ///
+    /// A view that has reached the end of the cache picks up new items, skipping those
+    /// equal to the last kept one even if another handle pulled them:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["x", "y", "y", "y", "z"]);
+    /// let dedup = reiterate.dedup_view();
+    /// let mut iter = dedup.iter();
+    /// assert_eq!(iter.next(), Some("x"));
+    /// assert_eq!(iter.next(), Some("y"));
+    ///
+    /// let mut other = reiterate.into_iter();
+    /// assert_eq!(other.nth(3), Some("y"));
+    /// assert_eq!(iter.next(), Some("z"));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(dedup.iter().collect::<Vec<_>>(), ["x", "y", "z"]);
+    /// ```
+    pub fn dedup_view(&self) -> DedupView<'_, I, TargetEq<<I::Item as Deref>::Target>>
+    where
+        <I::Item as Deref>::Target: PartialEq,
+    {
+        self.dedup_by_view(PartialEq::eq)
+    }
+
+    /// Get a view of the items with runs of items that `same` considers equal collapsed,
+    /// like `slice::dedup_by`.
+    ///
+    /// Each item is compared to the last kept item, as `same(kept, item)`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["Foo", "foo", "FOO", "bar", "Bar", "foo"]);
+    /// let dedup = reiterate.dedup_by_view(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(dedup.iter().collect::<Vec<_>>(), ["Foo", "bar", "foo"]);
+    /// ```
+    pub fn dedup_by_view<F>(&self, same: F) -> DedupView<'_, I, F>
+    where
+        F: Fn(&<I::Item as Deref>::Target, &<I::Item as Deref>::Target) -> bool,
+    {
+        DedupView {
+            iterable: self,
+            same,
+            scanned: Cell::new(0),
+            kept: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+/// A view of a `Reiterate` with runs of equal items collapsed,
+/// produced by `dedup_view()` or `dedup_by_view()`
+pub struct DedupView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    same: F,
+    // How many items have been compared
+    scanned: Cell<usize>,
+    // The indices of the kept items among the compared ones
+    kept: RefCell<Vec<usize>>,
+}
+
+impl<'a, I, F> DedupView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&<I::Item as Deref>::Target, &<I::Item as Deref>::Target) -> bool,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> DedupReiterator<'_, 'a, I, F> {
+        DedupReiterator {
+            view: self,
+            curr: 0,
+        }
+    }
+
+    /// Compare the next item, pulling it if needed.
+    /// Returns `false` if the stream has ended.
+    fn scan(&self) -> bool {
+        let idx = self.scanned.get();
+        let item = match self.iterable.get_or_pull(idx) {
+            Some(item) => item,
+            None => return false,
+        };
+        let mut kept = self.kept.borrow_mut();
+        let last = kept.last().map(|&last| &self.iterable.cache[last]);
+        if !last.is_some_and(|last| (self.same)(last, item)) {
+            kept.push(idx);
+        }
+        self.scanned.set(idx + 1);
+        true
+    }
+}
+
+impl<'v, 'a, I, F> IntoIterator for &'v DedupView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&<I::Item as Deref>::Target, &<I::Item as Deref>::Target) -> bool,
+{
+    type IntoIter = DedupReiterator<'v, 'a, I, F>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `DedupView`, produced by calling `.iter()`
+pub struct DedupReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v DedupView<'a, I, F>,
+    // The position in the kept items
+    curr: usize,
+}
+
+impl<'v, 'a, I, F> Iterator for DedupReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&<I::Item as Deref>::Target, &<I::Item as Deref>::Target) -> bool,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let view = self.view;
+        while view.kept.borrow().len() <= self.curr {
+            if !view.scan() {
+                return None;
+            }
+        }
+        let idx = view.kept.borrow()[self.curr];
+        self.curr += 1;
+        Some(&view.iterable.cache[idx])
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get a view of the items with runs of equal items collapsed, like `slice::dedup`.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::dedup_view()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let compared = Cell::new(0);
+    /// let reiterate = CopyReiterate::new(vec![1, 2, 1, 2, 2, 2, 2, 3, 3, 3]);
+    /// let dedup = reiterate.dedup_by_view(|a, b| {
+    ///     compared.set(compared.get() + 1);
+    ///     a == b
+    /// });
+    /// for _ in 0..3 {
+    ///     assert_eq!(dedup.iter().collect::<Vec<_>>(), [1, 2, 1, 2, 3]);
+    /// }
+    /// assert_eq!(compared.get(), 9);
+    ///
+    /// assert_eq!(reiterate.dedup_view().iter().collect::<Vec<_>>(), [1, 2, 1, 2, 3]);
+    /// ```
+    pub fn dedup_view(&self) -> CopyDedupView<'_, I, TargetEq<I::Item>>
+    where
+        I::Item: PartialEq,
+    {
+        self.dedup_by_view(PartialEq::eq)
+    }
+
+    /// Get a view of the items with runs of items that `same` considers equal collapsed,
+    /// like `slice::dedup_by`.
+    ///
+    /// Each item is compared to the last kept item, as `same(kept, item)`.
+    pub fn dedup_by_view<F>(&self, same: F) -> CopyDedupView<'_, I, F>
+    where
+        F: Fn(&I::Item, &I::Item) -> bool,
+    {
+        CopyDedupView {
+            iterable: self,
+            same,
+            scanned: Cell::new(0),
+            kept: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+/// A view of a `CopyReiterate` with runs of equal items collapsed,
+/// produced by `dedup_view()` or `dedup_by_view()`
+pub struct CopyDedupView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a CopyReiterate<I>,
+    same: F,
+    // How many items have been compared
+    scanned: Cell<usize>,
+    // The indices of the kept items among the compared ones
+    kept: RefCell<Vec<usize>>,
+}
+
+impl<'a, I, F> CopyDedupView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(&I::Item, &I::Item) -> bool,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> CopyDedupReiterator<'_, 'a, I, F> {
+        CopyDedupReiterator {
+            view: self,
+            curr: 0,
+        }
+    }
+
+    /// Compare the next item, pulling it if needed.
+    /// Returns `false` if the stream has ended.
+    fn scan(&self) -> bool {
+        let idx = self.scanned.get();
+        let item = match self.iterable.get_or_pull(idx) {
+            Some(item) => item,
+            None => return false,
+        };
+        let mut kept = self.kept.borrow_mut();
+        let last = kept
+            .last()
+            .map(|&last| self.iterable.inner.borrow().cache[last]);
+        if !last.is_some_and(|last| (self.same)(&last, &item)) {
+            kept.push(idx);
+        }
+        self.scanned.set(idx + 1);
+        true
+    }
+}
+
+impl<'v, 'a, I, F> IntoIterator for &'v CopyDedupView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(&I::Item, &I::Item) -> bool,
+{
+    type IntoIter = CopyDedupReiterator<'v, 'a, I, F>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `CopyDedupView`, produced by calling `.iter()`
+pub struct CopyDedupReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v CopyDedupView<'a, I, F>,
+    // The position in the kept items
+    curr: usize,
+}
+
+impl<'v, 'a, I, F> Iterator for CopyDedupReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let view = self.view;
+        while view.kept.borrow().len() <= self.curr {
+            if !view.scan() {
+                return None;
+            }
+        }
+        let idx = view.kept.borrow()[self.curr];
+        self.curr += 1;
+        let item = view.iterable.inner.borrow().cache[idx];
+        Some(item)
+    }
+}
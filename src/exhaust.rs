@@ -0,0 +1,373 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::iter::FromIterator;
+use std::ops::{ControlFlow, Deref};
+use std::time::{Duration, Instant};
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Pull the rest of the items into the cache, returning the length of the stream.
+    ///
+    /// This pays for the whole iteration up front, after which every handle only reads
+    /// from the cache. Handles that already exist carry on from where they were.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = Reiterate::new((0..100).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(reiterate.exhaust(), 100);
+    /// assert_eq!(pulls.get(), 100);
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(reiterate.into_iter().count(), 100);
+    /// assert_eq!(pulls.get(), 100);
+    ///
+    /// // the stream ends at `truncate()`
+    /// reiterate.truncate(10);
+    /// assert_eq!(reiterate.exhaust(), 10);
+    /// ```
+    pub fn exhaust(&self) -> usize {
+        self.iter_tail().for_each(drop);
+        self.visible_len()
+    }
+
+    /// Pull the rest of the items, then collect references to all of them into a
+    /// collection.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let words = Reiterate::new("to be or not to be".split(' ').map(String::from));
+    /// let distinct: BTreeSet<&str> = words.collect_cached();
+    /// assert_eq!(distinct.into_iter().collect::<Vec<_>>(), ["be", "not", "or", "to"]);
+    /// let joined: String = words.collect_cached::<Vec<_>>().join(" ");
+    /// assert_eq!(joined, "to be or not to be");
+    /// ```
+    pub fn collect_cached<'a, C>(&'a self) -> C
+    where
+        C: FromIterator<&'a <I::Item as Deref>::Target>,
+    {
+        self.exhaust();
+        self.iter_cached().collect()
+    }
+
+    /// Pull up to `n` more items into the cache in one call, returning how many were
+    /// pulled, which is less than `n` once the stream ends.
+    ///
+    /// This fills the cache ahead of the handles in batches of whatever size suits the
+    /// source, like the buffer size of a reader, without creating a handle to step. The
+    /// items it pulled start at the index `cached_len()` had before the call, and can be
+    /// read back with `get_cached()` or `iter_cached()`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let lines = Reiterate::new((1..=10).map(|i| format!("line {}", i)));
+    /// assert_eq!(lines.advance_cache_by(4), 4);
+    /// assert_eq!(lines.advance_cache_by(4), 4);
+    /// assert_eq!(lines.iter_cached().skip(4).next(), Some("line 5"));
+    /// assert_eq!(lines.advance_cache_by(4), 2);
+    /// assert_eq!(lines.advance_cache_by(4), 0);
+    /// assert_eq!(lines.cached_len(), 10);
+    /// ```
+    pub fn advance_cache_by(&self, n: usize) -> usize {
+        let mut tail = self.iter_tail();
+        let start = tail.curr;
+        (&mut tail).take(n).for_each(drop);
+        tail.curr - start
+    }
+
+    /// Pull the rest of the items one at a time, calling `f` with the number of cached
+    /// items after each one.
+    ///
+    /// Returns `Break` if `f` did, which leaves the rest of the items to be pulled later,
+    /// and `Continue` once the underlying iterator has ended. Nothing is borrowed while `f`
+    /// runs, so it can use the `Reiterate` freely.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let source = (0..1000).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1));
+    /// let reiterate = Reiterate::new(source);
+    ///
+    /// let stop_at = |n| if n == 100 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) };
+    /// assert_eq!(reiterate.exhaust_with(stop_at), ControlFlow::Break(()));
+    /// assert_eq!(pulls.get(), 100);
+    /// assert_eq!(reiterate.into_iter().take(100).last(), Some(&99));
+    ///
+    /// // resuming carries on from where it stopped
+    /// let mut seen = Vec::new();
+    /// let result = reiterate.exhaust_with(|n| {
+    ///     seen.push(n);
+    ///     // the last item is already cached
+    ///     assert_eq!(reiterate.into_iter().nth(n - 1), Some(&(n as i32 - 1)));
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!(result, ControlFlow::Continue(()));
+    /// assert_eq!(seen, (101..=1000).collect::<Vec<_>>());
+    /// assert_eq!(pulls.get(), 1000);
+    /// assert!(reiterate.into_iter().copied().eq(0..1000));
+    /// ```
+    pub fn exhaust_with(&self, mut f: impl FnMut(usize) -> ControlFlow<()>) -> ControlFlow<()> {
+        let mut tail = self.iter_tail();
+        while tail.next().is_some() {
+            f(tail.curr)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Pull the rest of the items for up to `duration`, checking the time after each one.
+    ///
+    /// Returns `Break` if it ran out of time, like `exhaust_with()`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::ops::ControlFlow;
+    /// use std::time::Duration;
+    ///
+    /// let reiterate = Reiterate::new((0..).map(Box::new));
+    /// let result = reiterate.exhaust_for(Duration::from_millis(10));
+    /// assert_eq!(result, ControlFlow::Break(()));
+    ///
+    /// let finite = Reiterate::new(vec!["a", "b"]);
+    /// assert_eq!(finite.exhaust_for(Duration::from_secs(60)), ControlFlow::Continue(()));
+    /// ```
+    pub fn exhaust_for(&self, duration: Duration) -> ControlFlow<()> {
+        let deadline = Instant::now() + duration;
+        self.exhaust_with(|_| until(deadline))
+    }
+
+    /// Pull until `target` items are cached, stopping early once `deadline` has passed.
+    ///
+    /// The time is checked before each pull, so this only overruns the deadline by the
+    /// pull in flight, and every item pulled is cached as usual, whenever it stops. Once
+    /// `target` items are cached this returns right away without pulling or checking the
+    /// time, so it can be called on every frame of an interactive loop. Items past the
+    /// logical length set by `truncate()` count as an exhausted source.
+    ///
+    /// ```rust
+    /// use reiterate::{PrimeOutcome, Reiterate};
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let slow = (0..).map(|i| {
+    ///     thread::sleep(Duration::from_millis(5));
+    ///     Box::new(i)
+    /// });
+    /// let reiterate = Reiterate::new(slow);
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(50);
+    /// let outcome = reiterate.try_prime_within(1000, deadline);
+    /// let pulled = match outcome {
+    ///     PrimeOutcome::DeadlineReached { pulled } => pulled,
+    ///     other => panic!("{:?}", other),
+    /// };
+    /// assert!(0 < pulled && pulled < 1000);
+    /// assert_eq!(reiterate.cached_len(), pulled);
+    ///
+    /// // nothing left to pull, even with the deadline long gone
+    /// let past = Instant::now() - Duration::from_secs(1);
+    /// assert_eq!(reiterate.try_prime_within(pulled, past), PrimeOutcome::Primed { pulled: 0 });
+    /// assert_eq!(reiterate.try_prime_within(pulled + 1, past), PrimeOutcome::DeadlineReached { pulled: 0 });
+    ///
+    /// let later = Instant::now() + Duration::from_secs(60);
+    /// assert_eq!(reiterate.try_prime_within(pulled + 2, later), PrimeOutcome::Primed { pulled: 2 });
+    /// assert_eq!(reiterate.cached_len(), pulled + 2);
+    ///
+    /// let finite = Reiterate::new(vec!["a", "b"]);
+    /// assert_eq!(finite.try_prime_within(5, later), PrimeOutcome::Exhausted { pulled: 2 });
+    /// ```
+    pub fn try_prime_within(&self, target: usize, deadline: Instant) -> PrimeOutcome {
+        if self.visible_len() >= target {
+            return PrimeOutcome::Primed { pulled: 0 };
+        }
+        let mut tail = self.iter_tail();
+        prime(|| tail.next().map(|_| tail.curr), target, deadline)
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Pull the rest of the items into the cache, returning the length of the stream.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::exhaust()`. A `lazy_caching()` one
+    /// starts caching, so the items streamed by its first handle aren't counted.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("abc".chars());
+    /// assert_eq!(reiterate.exhaust(), 3);
+    /// assert_eq!(reiterate.cached_len().ready(), Some(3));
+    /// assert_eq!(reiterate.exhaust(), 3);
+    /// ```
+    pub fn exhaust(&self) -> usize {
+        self.iter_tail().for_each(drop);
+        self.visible_len()
+    }
+
+    /// Pull the rest of the items, then copy all of them into a `Vec`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new((1..=4).map(|i| i * 10));
+    /// assert_eq!(reiterate.into_iter().next(), Some(10));
+    /// assert_eq!(reiterate.to_vec(), [10, 20, 30, 40]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<I::Item> {
+        let len = self.exhaust();
+        self.inner.borrow().cache[..len].to_vec()
+    }
+
+    /// Pull up to `n` more items into the cache in one call, returning how many were
+    /// pulled.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::advance_cache_by()`. The items it
+    /// pulled are at the end of `as_slice()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let bytes = CopyReiterate::new(b"a batched read".iter().copied());
+    /// assert_eq!(bytes.advance_cache_by(2), 2);
+    /// let pulled = bytes.advance_cache_by(8);
+    /// let cached = bytes.as_slice().ready().unwrap();
+    /// assert_eq!(&cached[cached.len() - pulled..], b"batched ");
+    /// drop(cached);
+    /// assert_eq!(bytes.advance_cache_by(100), 4);
+    /// ```
+    pub fn advance_cache_by(&self, n: usize) -> usize {
+        let mut tail = self.iter_tail();
+        let start = tail.curr;
+        (&mut tail).take(n).for_each(drop);
+        tail.curr - start
+    }
+
+    /// Pull the rest of the items one at a time, calling `f` with the number of cached
+    /// items after each one.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::exhaust_with()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let reiterate = CopyReiterate::new(0..1000u32);
+    /// let stop_at = |n| if n == 100 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) };
+    /// assert_eq!(reiterate.exhaust_with(stop_at), ControlFlow::Break(()));
+    /// assert_eq!(reiterate.cache_size_bytes().ready(), Some(100 * 4));
+    ///
+    /// assert_eq!(reiterate.exhaust_with(stop_at), ControlFlow::Continue(()));
+    /// assert_eq!(reiterate.cache_size_bytes().ready(), Some(1000 * 4));
+    /// assert!(reiterate.into_iter().eq(0..1000));
+    /// ```
+    pub fn exhaust_with(&self, mut f: impl FnMut(usize) -> ControlFlow<()>) -> ControlFlow<()> {
+        let mut tail = self.iter_tail();
+        while tail.next().is_some() {
+            f(tail.curr)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Pull the rest of the items for up to `duration`, checking the time after each one.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::exhaust_for()`.
+    pub fn exhaust_for(&self, duration: Duration) -> ControlFlow<()> {
+        let deadline = Instant::now() + duration;
+        self.exhaust_with(|_| until(deadline))
+    }
+
+    /// Pull until `target` items are cached, stopping early once `deadline` has passed.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::try_prime_within()`.
+    ///
+    /// ```rust
+    /// use reiterate::{CopyReiterate, PrimeOutcome};
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let slow = (0..1000u32).inspect(|_| thread::sleep(Duration::from_millis(5)));
+    /// let reiterate = CopyReiterate::new(slow);
+    /// let outcome = reiterate.try_prime_within(1000, Instant::now() + Duration::from_millis(50));
+    /// assert!(matches!(outcome, PrimeOutcome::DeadlineReached { .. }));
+    /// assert!(outcome.pulled() < 1000);
+    ///
+    /// let frame = Instant::now();
+    /// assert_eq!(reiterate.try_prime_within(outcome.pulled(), frame), PrimeOutcome::Primed { pulled: 0 });
+    /// ```
+    pub fn try_prime_within(&self, target: usize, deadline: Instant) -> PrimeOutcome {
+        if self.visible_len() >= target {
+            return PrimeOutcome::Primed { pulled: 0 };
+        }
+        let mut tail = self.iter_tail();
+        prime(|| tail.next().map(|_| tail.curr), target, deadline)
+    }
+}
+
+/// The result of `try_prime_within()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrimeOutcome {
+    /// The target number of items is cached
+    Primed { pulled: usize },
+    /// The deadline passed before the target was reached
+    DeadlineReached { pulled: usize },
+    /// The stream ended before the target was reached
+    Exhausted { pulled: usize },
+}
+
+impl PrimeOutcome {
+    /// The number of items pulled by the call
+    pub fn pulled(&self) -> usize {
+        match *self {
+            PrimeOutcome::Primed { pulled }
+            | PrimeOutcome::DeadlineReached { pulled }
+            | PrimeOutcome::Exhausted { pulled } => pulled,
+        }
+    }
+}
+
+/// Call `pull`, which returns the number of cached items, until it reaches `target`, ends,
+/// or `deadline` passes
+fn prime(
+    mut pull: impl FnMut() -> Option<usize>,
+    target: usize,
+    deadline: Instant,
+) -> PrimeOutcome {
+    let mut pulled = 0;
+    loop {
+        if until(deadline).is_break() {
+            return PrimeOutcome::DeadlineReached { pulled };
+        }
+        match pull() {
+            Some(cached) => {
+                pulled += 1;
+                if cached >= target {
+                    return PrimeOutcome::Primed { pulled };
+                }
+            }
+            None => return PrimeOutcome::Exhausted { pulled },
+        }
+    }
+}
+
+fn until(deadline: Instant) -> ControlFlow<()> {
+    if Instant::now() < deadline {
+        ControlFlow::Continue(())
+    } else {
+        ControlFlow::Break(())
+    }
+}
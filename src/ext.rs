@@ -0,0 +1,46 @@
+use crate::{CloneReiterate, CopyReiterate, ReiterableItem, Reiterate};
+
+/// Methods to wrap any iterator in a reiterate at the end of a chain of adaptors,
+/// implemented for every iterator.
+///
+/// ```rust
+/// use reiterate::ReiterateExt;
+///
+/// let squares = (1..=4).map(|i| i * i).map(Box::new).reiterate();
+/// for _ in 0..2 {
+///     assert_eq!(squares.into_iter().sum::<i32>(), 30);
+/// }
+///
+/// let evens = (0..10).filter(|i| i % 2 == 0).copy_reiterate();
+/// assert_eq!(evens.into_iter().collect::<Vec<_>>(), evens.into_iter().collect::<Vec<_>>());
+///
+/// let names = ["ada", "grace"].iter().map(|name| (name.len(), *name)).clone_reiterate();
+/// assert_eq!(names.into_iter().last(), Some((5, "grace")));
+/// ```
+pub trait ReiterateExt: Iterator + Sized {
+    /// Wrap this iterator in a `Reiterate`, like `Reiterate::new()`
+    fn reiterate(self) -> Reiterate<Self>
+    where
+        Self::Item: ReiterableItem,
+    {
+        Reiterate::new(self)
+    }
+
+    /// Wrap this iterator in a `CopyReiterate`, like `CopyReiterate::new()`
+    fn copy_reiterate(self) -> CopyReiterate<Self>
+    where
+        Self::Item: Copy,
+    {
+        CopyReiterate::new(self)
+    }
+
+    /// Wrap this iterator in a `CloneReiterate`, like `CloneReiterate::new()`
+    fn clone_reiterate(self) -> CloneReiterate<Self>
+    where
+        Self::Item: Clone,
+    {
+        CloneReiterate::new(self)
+    }
+}
+
+impl<I: Iterator> ReiterateExt for I {}
@@ -0,0 +1,164 @@
+use crate::{ContentionAware, ReiterableItem, Reiterate, Reiterator};
+
+use std::rc::Rc;
+
+/// A `Reiterate` over the `Ok` items of a fallible source that stops at the first error,
+/// created by `Reiterate::try_new()`
+pub type TryReiterate<I, E> = Reiterate<UntilError<I, E>>;
+
+/// An iterator over the `Ok` items of a fallible source that ends at the first error and
+/// keeps it, used as the source of `Reiterate::try_new`.
+pub struct UntilError<I, E> {
+    iter: I,
+    error: Option<Rc<E>>,
+}
+
+impl<I, E> UntilError<I, E> {
+    /// The error that ended the source, if any
+    pub fn error(&self) -> Option<Rc<E>> {
+        self.error.clone()
+    }
+}
+
+impl<I, T, E> Iterator for UntilError<I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(val) => Some(val),
+            Err(err) => {
+                self.error = Some(Rc::new(err));
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() {
+            return (0, Some(0));
+        }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T, E> Reiterate<UntilError<I, E>>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: ReiterableItem,
+{
+    /// Create a `Reiterate` over a fallible source that ends at its first error, for
+    /// errors after which the source can't go on, like I/O errors.
+    ///
+    /// The `Ok` items are cached as usual, and the error is kept by `error()`. Handles
+    /// from `try_iter()` replay the error at the position where it happened. Unlike for
+    /// `skip_errors()`, the source isn't pulled from again after an error.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::io::{BufRead, ErrorKind};
+    ///
+    /// let lines = Reiterate::try_new(b"ok\nfine\n\xff\xfe\nnever seen\n".lines());
+    /// for _ in 0..2 {
+    ///     let mut iter = lines.try_iter();
+    ///     assert_eq!(iter.next().unwrap().unwrap(), "ok");
+    ///     assert_eq!(iter.next().unwrap().unwrap(), "fine");
+    ///     assert_eq!(iter.next().unwrap().unwrap_err().kind(), ErrorKind::InvalidData);
+    ///     assert!(iter.next().is_none());
+    /// }
+    ///
+    /// // plain handles only see the items before the error
+    /// assert_eq!(lines.into_iter().collect::<Vec<_>>(), ["ok", "fine"]);
+    /// assert_eq!(lines.error().ready().unwrap().unwrap().kind(), ErrorKind::InvalidData);
+    /// ```
+    pub fn try_new<S>(iter: S) -> Self
+    where
+        S: IntoIterator<Item = Result<T, E>, IntoIter = I>,
+    {
+        Reiterate::new(UntilError {
+            iter: iter.into_iter(),
+            error: None,
+        })
+    }
+
+    /// The error that ended the source, if it has been reached, or `Contended` during a
+    /// pull
+    pub fn error(&self) -> ContentionAware<Option<Rc<E>>> {
+        ContentionAware::borrow(&self.iter).map(|iter| iter.error())
+    }
+
+    /// Get an iterator over the items that yields the error that ended the source after
+    /// them, if any.
+    ///
+    /// A `truncate()`d `Reiterate` ends before the error, and handles over it don't yield
+    /// the error.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let parsed = Reiterate::try_new("1 2 x 4".split(' ').map(|s| s.parse::<u8>().map(Box::new)));
+    /// let mut iter = parsed.try_iter();
+    /// assert_eq!(iter.next(), Some(Ok(&1)));
+    /// // a handle further behind only gets the error once it gets there
+    /// let behind = parsed.try_iter();
+    /// assert_eq!(iter.next(), Some(Ok(&2)));
+    /// assert!(matches!(iter.next(), Some(Err(_))));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(behind.filter(Result::is_err).count(), 1);
+    ///
+    /// parsed.truncate(1);
+    /// assert_eq!(parsed.try_iter().collect::<Vec<_>>(), [Ok(&1)]);
+    /// ```
+    pub fn try_iter(&self) -> TryReiterator<'_, I, E> {
+        TryReiterator {
+            inner: self.into_iter(),
+            done: false,
+        }
+    }
+}
+
+/// An iterator over a `TryReiterate` produced by `try_iter()`, yielding the error that
+/// ended the source after the items
+pub struct TryReiterator<'a, I, E>
+where
+    UntilError<I, E>: Iterator,
+{
+    inner: Reiterator<'a, UntilError<I, E>>,
+    done: bool,
+}
+
+impl<'a, I, T, E> Iterator for TryReiterator<'a, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: ReiterableItem + 'a,
+{
+    type Item = Result<&'a T::Target, Rc<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(val) = self.inner.next() {
+            return Some(Ok(val));
+        }
+        self.done = true;
+        let iterable = self.inner.iterable;
+        if self.inner.curr >= iterable.limit.get() {
+            return None;
+        }
+        iterable.iter.borrow().error().map(Err)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.size_hint();
+        (lower, upper.and_then(|upper| upper.checked_add(1)))
+    }
+}
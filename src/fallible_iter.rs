@@ -0,0 +1,123 @@
+use crate::{ContentionAware, ReiterableItem, Reiterate, TryReiterate, TryReiterator};
+
+use fallible_iterator::FallibleIterator;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// The source of a `FallibleReiterate`, as a plain iterator over `Result`s
+type Source<I> = fallible_iterator::Iterator<I>;
+
+/// An adaptor around a `fallible_iterator::FallibleIterator` that can produce multiple
+/// fallible iterators sharing an underlying cache.
+///
+/// The successful items are cached like for a `Reiterate`. The first error ends the
+/// source, which isn't pulled from again, and every handle returns that error once it
+/// gets past the items before it, like the source did, then `Ok(None)`. Errors are
+/// shared between the handles through an `Rc`.
+///
+/// ```rust
+/// use fallible_iterator::FallibleIterator;
+/// use reiterate::FallibleReiterate;
+///
+/// /// A cursor over the rows of a query, failing after `fail_after` rows
+/// struct Cursor {
+///     next_id: u32,
+///     fail_after: u32,
+/// }
+///
+/// impl FallibleIterator for Cursor {
+///     type Item = String;
+///     type Error = String;
+///
+///     fn next(&mut self) -> Result<Option<String>, String> {
+///         if self.next_id == self.fail_after {
+///             return Err("connection reset".to_string());
+///         }
+///         self.next_id += 1;
+///         Ok(Some(format!("row {}", self.next_id)))
+///     }
+/// }
+///
+/// let rows = FallibleReiterate::new(Cursor { next_id: 0, fail_after: 2 });
+/// for _ in 0..2 {
+///     let mut iter = rows.iter();
+///     assert_eq!(iter.next(), Ok(Some("row 1")));
+///     assert_eq!(iter.next(), Ok(Some("row 2")));
+///     assert_eq!(iter.next().unwrap_err().as_str(), "connection reset");
+///     assert_eq!(iter.next(), Ok(None));
+/// }
+///
+/// // with the usual `FallibleIterator` adaptors
+/// let lens: Result<Vec<usize>, _> = rows.iter().map(|row| Ok(row.len())).collect();
+/// assert!(lens.is_err());
+/// assert_eq!(rows.iter().take(2).count(), Ok(2));
+/// assert_eq!(rows.cached_len(), 2);
+/// ```
+pub struct FallibleReiterate<I>
+where
+    I: FallibleIterator,
+{
+    inner: TryReiterate<Source<I>, I::Error>,
+}
+
+impl<I> FallibleReiterate<I>
+where
+    I: FallibleIterator,
+    I::Item: ReiterableItem,
+{
+    pub fn new(iter: I) -> Self {
+        FallibleReiterate {
+            inner: Reiterate::try_new(iter.iterator()),
+        }
+    }
+
+    /// Get a fallible iterator over the items, yielding the error that ended the source
+    /// after them, if any
+    pub fn iter(&self) -> FallibleReiterator<'_, I> {
+        FallibleReiterator {
+            inner: self.inner.try_iter(),
+        }
+    }
+
+    /// The error that ended the source, if it has been reached, or `Contended` during a
+    /// pull
+    pub fn error(&self) -> ContentionAware<Option<Rc<I::Error>>> {
+        self.inner.error()
+    }
+
+    /// The number of items pulled from the source so far, not counting the error
+    pub fn cached_len(&self) -> usize {
+        self.inner.cached_len()
+    }
+
+    /// Whether the source has panicked while an item was being pulled from it, like for
+    /// `Reiterate::is_poisoned()`
+    pub fn is_poisoned(&self) -> bool {
+        self.inner.is_poisoned()
+    }
+}
+
+/// A fallible iterator over a `FallibleReiterate`, produced by calling `.iter()` on it
+pub struct FallibleReiterator<'a, I>
+where
+    I: FallibleIterator,
+{
+    inner: TryReiterator<'a, Source<I>, I::Error>,
+}
+
+impl<'a, I> FallibleIterator for FallibleReiterator<'a, I>
+where
+    I: FallibleIterator,
+    I::Item: ReiterableItem + 'a,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+    type Error = Rc<I::Error>;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.inner.next().transpose()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
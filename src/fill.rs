@@ -0,0 +1,71 @@
+use crate::{poison_on_unwind, ReiterableItem, Reiterate};
+
+/// A cache that is filled one item at a time from an underlying iterator and the sources
+/// queued after it.
+///
+/// This is implemented for `&Reiterate`, which pulls through interior mutability, and for
+/// `ReiterateMut`, which pulls through `&mut self`, so that both go through the same
+/// steps to fill their cache.
+pub(crate) trait Fill {
+    type Item;
+
+    /// The number of items cached so far
+    fn filled_len(&self) -> usize;
+
+    /// Pull the next item from the underlying iterator, or from the queued sources once
+    /// it has ended
+    fn pull_item(&mut self) -> Option<Self::Item>;
+
+    /// Add an item to the end of the cache
+    fn store_item(&mut self, val: Self::Item);
+
+    /// Pull the next item into the cache, returning whether there was one
+    #[inline]
+    fn fill_one(&mut self) -> bool {
+        match self.pull_item() {
+            Some(val) => {
+                self.store_item(val);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pull until at least `n` items are cached, or the stream ends, returning how many
+    /// items are cached
+    fn fill_to(&mut self, n: usize) -> usize {
+        while self.filled_len() < n && self.fill_one() {}
+        self.filled_len()
+    }
+}
+
+impl<I> Fill for &Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item = I::Item;
+
+    fn filled_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    fn pull_item(&mut self) -> Option<I::Item> {
+        poison_on_unwind(&self.poisoned, || {
+            let val = self.iter.borrow_mut().next();
+            self.queued.or_pull(val)
+        })
+    }
+
+    /// Keeps track of the size and address of the item too
+    fn store_item(&mut self, val: I::Item) {
+        if let Some(measurer) = self.measurer {
+            self.heap_size.set(self.heap_size.get() + measurer(&val));
+        }
+        self.cache.push(val);
+        if let Some(aliasing) = &self.aliasing {
+            let idx = self.cache.len() - 1;
+            aliasing.borrow_mut().record(&self.cache[idx], idx);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::slice;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a flat view over items that are batches of `U`s, e.g. `Box<Vec<U>>` or `Box<[U]>`.
+    ///
+    /// The view is lazy, pulling batches only once they are needed, and remembers where
+    /// each batch starts, so that looking up an item by its flat index doesn't walk the
+    /// batches before it.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let batches = vec![vec![1, 2], vec![], vec![3], vec![], vec![], vec![4, 5, 6]];
+    /// let reiterate = Reiterate::new(batches.into_iter().map(Box::new));
+    /// let flat = reiterate.flatten_view();
+    ///
+    /// assert_eq!(flat.iter().collect::<Vec<_>>(), [&1, &2, &3, &4, &5, &6]);
+    /// assert_eq!(flat.iter().collect::<Vec<_>>(), [&1, &2, &3, &4, &5, &6]);
+    /// for (idx, item) in flat.iter().enumerate() {
+    ///     assert_eq!(flat.get(idx), Some(item));
+    /// }
+    /// assert_eq!(flat.get(6), None);
+    /// assert_eq!(flat.len(), 6);
+    /// ```
+    pub fn flatten_view<U>(&self) -> FlattenView<'_, I, U>
+    where
+        <I::Item as Deref>::Target: AsRef<[U]>,
+    {
+        FlattenView {
+            iterable: self,
+            ends: RefCell::new(Vec::new()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A flat view over the batches of a `Reiterate`, produced by `flatten_view()`
+pub struct FlattenView<'a, I, U>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: AsRef<[U]>,
+{
+    iterable: &'a Reiterate<I>,
+    // The flat index after the end of each batch seen so far
+    ends: RefCell<Vec<usize>>,
+    _marker: PhantomData<fn() -> U>,
+}
+
+impl<'a, I, U> FlattenView<'a, I, U>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: AsRef<[U]>,
+    U: 'a,
+{
+    /// Get the batch at `idx`, pulling from the underlying iterator as needed
+    fn batch(&self, idx: usize) -> Option<&'a [U]> {
+        if idx >= self.iterable.limit.get() {
+            return None;
+        }
+        let cache = &self.iterable.cache;
+        let mut tail = self.iterable.iter_tail();
+        while cache.len() <= idx {
+            tail.next()?;
+        }
+        let mut ends = self.ends.borrow_mut();
+        while ends.len() <= idx {
+            let end = ends.last().copied().unwrap_or(0) + cache[ends.len()].as_ref().len();
+            ends.push(end);
+        }
+        Some(cache[idx].as_ref())
+    }
+
+    /// Get the item at flat index `idx`, pulling batches as needed
+    pub fn get(&self, idx: usize) -> Option<&'a U> {
+        loop {
+            let (batches, end) = {
+                let ends = self.ends.borrow();
+                (ends.len(), ends.last().copied().unwrap_or(0))
+            };
+            if idx < end {
+                break;
+            }
+            self.batch(batches)?;
+        }
+        let ends = self.ends.borrow();
+        let batch = ends.partition_point(|&end| end <= idx);
+        let start = batch.checked_sub(1).map_or(0, |prev| ends[prev]);
+        drop(ends);
+        self.batch(batch)?.get(idx - start)
+    }
+
+    /// Exhaust the underlying iterator and get the total number of items across batches
+    pub fn len(&self) -> usize {
+        while self.batch(self.ends.borrow().len()).is_some() {}
+        self.ends.borrow().last().copied().unwrap_or(0)
+    }
+
+    /// Whether there are no items across batches, exhausting the underlying iterator
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the items of every batch
+    pub fn iter(&self) -> FlattenIter<'_, 'a, I, U> {
+        FlattenIter {
+            view: self,
+            next_batch: 0,
+            batch: [].iter(),
+        }
+    }
+}
+
+/// An iterator over the items of a `FlattenView`, produced by calling `.iter()`
+pub struct FlattenIter<'v, 'a, I, U>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: AsRef<[U]>,
+{
+    view: &'v FlattenView<'a, I, U>,
+    next_batch: usize,
+    batch: slice::Iter<'a, U>,
+}
+
+impl<'v, 'a, I, U> Iterator for FlattenIter<'v, 'a, I, U>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: AsRef<[U]>,
+    U: 'a,
+{
+    type Item = &'a U;
+
+    fn next(&mut self) -> Option<&'a U> {
+        loop {
+            if let Some(item) = self.batch.next() {
+                return Some(item);
+            }
+            self.batch = self.view.batch(self.next_batch)?.iter();
+            self.next_batch += 1;
+        }
+    }
+}
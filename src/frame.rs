@@ -0,0 +1,134 @@
+use crate::{ReiterableItem, Reiterate, Reiterator};
+
+use std::iter::Take;
+use std::ops::{Deref, Range};
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a view of the next frame of the main pass: the items up to the first one
+    /// matching `pred`, the delimiter, which is part of the frame if `inclusive`.
+    ///
+    /// The frame starts where `primary()` is at, and the main pass moves past the
+    /// delimiter, so repeated calls return successive frames. The items are pulled up to
+    /// the delimiter and no further, and the boundaries are kept in the view, so every
+    /// frame can be replayed any number of times, however many were scanned after it.
+    /// A last frame cut short by the end of the stream has no delimiter. Returns `None`
+    /// once the main pass has reached the end of the stream.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let packets = Reiterate::new("ab;;cde;f".chars().map(Box::new));
+    /// let frames: Vec<_> = std::iter::from_fn(|| packets.scan_to(|&c| c == ';', false)).collect();
+    /// assert_eq!(frames.len(), 4);
+    /// for _ in 0..2 {
+    ///     let text: Vec<String> = frames.iter().map(|frame| frame.iter().collect()).collect();
+    ///     assert_eq!(text, ["ab", "", "cde", "f"]);
+    /// }
+    /// assert!(frames[..3].iter().all(|frame| frame.is_terminated()));
+    /// assert!(!frames[3].is_terminated());
+    /// assert_eq!(frames[2].range(), 4..7);
+    /// ```
+    ///
+    /// Frames can keep their delimiter, and the main pass carries on after them:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let mut lines = Reiterate::new(vec!["GET /", "Host: a", "", "body"]);
+    /// let head = lines.scan_to(|line| line.is_empty(), true).unwrap();
+    /// assert_eq!(head.iter().collect::<Vec<_>>(), ["GET /", "Host: a", ""]);
+    /// assert_eq!(lines.primary().collect::<Vec<_>>(), ["body"]);
+    /// assert!(lines.scan_to(|line| line.is_empty(), true).is_none());
+    /// ```
+    pub fn scan_to(
+        &self,
+        mut pred: impl FnMut(&<I::Item as Deref>::Target) -> bool,
+        inclusive: bool,
+    ) -> Option<FrameView<'_, I>> {
+        let start = self.primary.get();
+        self.get_or_pull(start)?;
+        let mut idx = start;
+        let (end, terminated) = loop {
+            match self.get_or_pull(idx) {
+                Some(item) if pred(item) => {
+                    self.primary.set(idx + 1);
+                    break (if inclusive { idx + 1 } else { idx }, true);
+                }
+                Some(_) => idx += 1,
+                None => {
+                    self.primary.set(idx);
+                    break (idx, false);
+                }
+            }
+        };
+        Some(FrameView {
+            iterable: self,
+            start,
+            end,
+            terminated,
+        })
+    }
+}
+
+/// A view of a frame of a `Reiterate`, produced by `scan_to()`
+pub struct FrameView<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    start: usize,
+    end: usize,
+    // Whether the frame ended with a delimiter, rather than with the stream
+    terminated: bool,
+}
+
+impl<'a, I> FrameView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Iterate over the frame
+    pub fn iter(&self) -> Take<Reiterator<'a, I>> {
+        // The frame is cached, so this never pulls
+        Reiterator {
+            iterable: self.iterable,
+            curr: self.start,
+        }
+        .take(self.len())
+    }
+
+    /// The indices of the items of the frame
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Whether the frame ended with a delimiter, rather than with the stream
+    pub fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<'a, I> IntoIterator for &FrameView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type IntoIter = Take<Reiterator<'a, I>>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
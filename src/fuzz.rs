@@ -0,0 +1,89 @@
+use crate::{CopyReiterate, Reiterable, Reiterate};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::vec;
+
+/// The most handles `mid_life()` interleaves
+const MAX_HANDLES: usize = 4;
+
+/// Generates a random sequence of items, along with a random state partway through its
+/// life, so that fuzzing covers reiterates that have already been iterated over.
+///
+/// The items are generated first, the same way as a `Vec<T>`, and then the state: a
+/// prefix of the items already cached, and up to four handles that took turns pulling
+/// items in a random order before being dropped. Replaying always yields all the items.
+///
+/// This needs the `arbitrary` feature.
+///
+/// ```rust
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use reiterate::CopyReiterate;
+///
+/// let bytes = [7, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+/// let reiterate = CopyReiterate::<std::vec::IntoIter<u8>>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// let items = Vec::<u8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// assert!(reiterate.cached_len().ready().unwrap() <= items.len());
+/// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), items);
+/// ```
+impl<'a, T> Arbitrary<'a> for CopyReiterate<vec::IntoIter<T>>
+where
+    T: Arbitrary<'a> + Copy,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let items: Vec<T> = u.arbitrary()?;
+        let len = items.len();
+        let reiterate = CopyReiterate::new(items);
+        mid_life(&reiterate, len, u)?;
+        Ok(reiterate)
+    }
+}
+
+/// Generates a random sequence of items, along with a random state partway through its
+/// life, like for a `CopyReiterate`.
+///
+/// This needs the `arbitrary` feature.
+///
+/// ```rust
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use reiterate::Reiterate;
+///
+/// let bytes = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8, 4, 6];
+/// let reiterate = Reiterate::<std::vec::IntoIter<Box<i16>>>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// let items = Vec::<Box<i16>>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// assert!(reiterate.cached_len() <= items.len());
+/// assert!(reiterate.into_iter().eq(items.iter().map(|item| &**item)));
+/// ```
+impl<'a, T> Arbitrary<'a> for Reiterate<vec::IntoIter<Box<T>>>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let items: Vec<Box<T>> = u.arbitrary()?;
+        let len = items.len();
+        let reiterate = Reiterate::new(items);
+        mid_life(&reiterate, len, u)?;
+        Ok(reiterate)
+    }
+}
+
+/// Bring a fresh `reiterable` over `len` items to a random point of its life, as told by
+/// `u`: some handles take turns pulling items, then some prefix is cached
+fn mid_life<R: Reiterable>(reiterable: &R, len: usize, u: &mut Unstructured<'_>) -> Result<()> {
+    let mut handles: Vec<_> = (0..u.int_in_range(0..=MAX_HANDLES)?)
+        .map(|_| reiterable.iter())
+        .collect();
+    // Every handle is done after `len + 1` turns, ending with its `None`
+    for _ in 0..(len + 1) * handles.len() {
+        if handles.is_empty() || u.is_empty() {
+            break;
+        }
+        let turn = u.choose_index(handles.len())?;
+        if handles[turn].next().is_none() {
+            drop(handles.swap_remove(turn));
+        }
+    }
+    drop(handles);
+    let cached = u.int_in_range(0..=len)?;
+    reiterable.iter().take(cached).for_each(drop);
+    Ok(())
+}
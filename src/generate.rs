@@ -0,0 +1,113 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::iter::{self, FromFn, Successors};
+
+impl<T, F> Reiterate<FromFn<F>>
+where
+    F: FnMut() -> Option<T>,
+    T: ReiterableItem,
+{
+    /// Create a `Reiterate` over the items returned by `f` until it returns `None`, like
+    /// `std::iter::from_fn()`.
+    ///
+    /// `f` is only called for items that aren't cached yet.
+    ///
+    /// ```rust
+    /// use reiterate::{BoxedReiterate, Reiterate};
+    ///
+    /// let mut calls = 0;
+    /// let fib = {
+    ///     let (mut a, mut b) = (0u64, 1);
+    ///     Reiterate::from_fn(move || {
+    ///         let next = a;
+    ///         (a, b) = (b, a + b);
+    ///         Some(Box::new(next))
+    ///     })
+    /// };
+    /// assert_eq!(fib.get(10), Some(&55));
+    /// assert_eq!(fib.into_iter().take(6).copied().collect::<Vec<_>>(), [0, 1, 1, 2, 3, 5]);
+    ///
+    /// // a memoized sequence behind a nameable type
+    /// let lines: BoxedReiterate<'_, String> = Reiterate::from_fn(|| {
+    ///     calls += 1;
+    ///     (calls <= 2).then(|| format!("line {}", calls))
+    /// })
+    /// .into();
+    /// assert_eq!(lines.into_iter().collect::<Vec<_>>(), ["line 1", "line 2"]);
+    /// assert_eq!(lines.into_iter().count(), 2);
+    /// ```
+    pub fn from_fn(f: F) -> Self {
+        Reiterate::new(iter::from_fn(f))
+    }
+}
+
+impl<T, F> Reiterate<Successors<T, F>>
+where
+    F: FnMut(&T) -> Option<T>,
+    T: ReiterableItem,
+{
+    /// Create a `Reiterate` over `first` and the items computed from each previous one by
+    /// `succ`, until it returns `None`, like `std::iter::successors()`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let paths = Reiterate::successors(Some("a/b/c".to_string()), |path| {
+    ///     path.rfind('/').map(|idx| path[..idx].to_string())
+    /// });
+    /// assert_eq!(paths.into_iter().collect::<Vec<_>>(), ["a/b/c", "a/b", "a"]);
+    /// ```
+    pub fn successors(first: Option<T>, succ: F) -> Self {
+        Reiterate::new(iter::successors(first, succ))
+    }
+}
+
+impl<T, F> CopyReiterate<FromFn<F>>
+where
+    F: FnMut() -> Option<T>,
+    T: Copy,
+{
+    /// Create a `CopyReiterate` over the items returned by `f` until it returns `None`,
+    /// like `Reiterate::from_fn()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let mut state = 1u32;
+    /// let noise = CopyReiterate::from_fn(|| {
+    ///     state ^= state << 13;
+    ///     state ^= state >> 17;
+    ///     state ^= state << 5;
+    ///     Some(state % 100)
+    /// });
+    /// let first: Vec<_> = noise.into_iter().take(4).collect();
+    /// assert_eq!(noise.into_iter().take(4).collect::<Vec<_>>(), first);
+    /// ```
+    pub fn from_fn(f: F) -> Self {
+        CopyReiterate::new(iter::from_fn(f))
+    }
+}
+
+impl<T, F> CopyReiterate<Successors<T, F>>
+where
+    F: FnMut(&T) -> Option<T>,
+    T: Copy,
+{
+    /// Create a `CopyReiterate` over `first` and the items computed from each previous one
+    /// by `succ`, like `Reiterate::successors()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let collatz = CopyReiterate::successors(Some(6u32), |&n| match n {
+    ///     1 => None,
+    ///     n if n % 2 == 0 => Some(n / 2),
+    ///     n => Some(3 * n + 1),
+    /// });
+    /// assert_eq!(collatz.into_iter().collect::<Vec<_>>(), [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+    /// assert_eq!(collatz.into_iter().max(), Some(16));
+    /// ```
+    pub fn successors(first: Option<T>, succ: F) -> Self {
+        CopyReiterate::new(iter::successors(first, succ))
+    }
+}
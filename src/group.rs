@@ -0,0 +1,245 @@
+use crate::Reiterable;
+
+use elsa::FrozenVec;
+
+use stable_deref_trait::StableDeref;
+use std::cell::RefCell;
+use std::ops::Deref;
+
+/// An adaptor around an iterator that groups consecutive items sharing a key,
+/// and can produce multiple iterators over the groups sharing an underlying cache.
+///
+/// Like `Itertools::group_by`, but replayable: the key function runs exactly once per item
+/// of the underlying iterator, and group boundaries are recorded so that every iterator sees
+/// the same groups. Each group is itself a lazy view into the shared cache.
+///
+/// ```rust
+/// use reiterate::GroupedReiterate;
+///
+/// let x = vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+/// let grouped = GroupedReiterate::new(x.into_iter().map(Box::new), |s: &&str| s.as_bytes()[0]);
+///
+/// for _ in 0..2 {
+///     let groups: Vec<_> = grouped
+///         .iter()
+///         .map(|(k, group)| (*k as char, group.map(|s| *s).collect::<Vec<_>>()))
+///         .collect();
+///     assert_eq!(
+///         groups,
+///         [
+///             ('a', vec!["apple", "avocado"]),
+///             ('b', vec!["banana", "blueberry"]),
+///             ('c', vec!["cherry"]),
+///         ]
+///     );
+/// }
+/// ```
+///
+/// A group can be iterated lazily while other iterators run ahead through the same cache:
+///
+/// ```rust
+/// use reiterate::GroupedReiterate;
+///
+/// let calls = std::cell::Cell::new(0);
+/// let x = vec![1, 1, 1, 2, 2, 3];
+/// let grouped = GroupedReiterate::new(x.into_iter().map(Box::new), |i: &i32| {
+///     calls.set(calls.get() + 1);
+///     *i
+/// });
+///
+/// let (key, mut ones) = grouped.iter().next().unwrap();
+/// assert_eq!(*key, 1);
+/// assert_eq!(ones.next(), Some(&1));
+///
+/// // races ahead, deciding where the first group ends
+/// let keys: Vec<_> = grouped.iter().map(|(k, _)| *k).collect();
+/// assert_eq!(keys, [1, 2, 3]);
+///
+/// assert_eq!(ones.count(), 2);
+/// assert_eq!(calls.get(), 6);
+/// ```
+pub struct GroupedReiterate<I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    inner: RefCell<GroupedReiterateInner<I, F>>,
+    items: FrozenVec<I::Item>,
+    // The key and starting index of each group
+    groups: FrozenVec<Box<(K, usize)>>,
+}
+
+struct GroupedReiterateInner<I, F> {
+    iter: I,
+    key: F,
+}
+
+impl<I, K, F> GroupedReiterate<I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    pub fn new<T>(iter: T, key: F) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        GroupedReiterate {
+            inner: RefCell::new(GroupedReiterateInner {
+                iter: iter.into_iter(),
+                key,
+            }),
+            items: FrozenVec::new(),
+            groups: FrozenVec::new(),
+        }
+    }
+
+    /// Get an iterator over the groups, reusing cached values
+    pub fn iter(&self) -> GroupedReiterator<'_, I, K, F> {
+        GroupedReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+
+    fn group_start(&self, group: usize) -> Option<usize> {
+        self.groups.get(group).map(|g| g.1)
+    }
+
+    /// Pull a single item from the underlying iterator, recording whether it
+    /// extends the last group or starts a new one.
+    fn pull(&self) -> Option<()> {
+        let mut inner = self.inner.borrow_mut();
+        let item = inner.iter.next()?;
+        let key = (inner.key)(&item);
+        drop(inner);
+        let idx = self.items.len();
+        self.items.push(item);
+        if self.groups.last().is_none_or(|last| last.0 != key) {
+            self.groups.push(Box::new((key, idx)));
+        }
+        Some(())
+    }
+}
+
+impl<'a, I, K, F> IntoIterator for &'a GroupedReiterate<I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    type IntoIter = GroupedReiterator<'a, I, K, F>;
+    type Item = (&'a K, Group<'a, I, K, F>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I, K, F> Reiterable for GroupedReiterate<I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    type Item<'a>
+        = (&'a K, Group<'a, I, K, F>)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = GroupedReiterator<'a, I, K, F>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> GroupedReiterator<'_, I, K, F> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator over groups, produced by calling `.iter()` on a `GroupedReiterate`
+pub struct GroupedReiterator<'a, I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    iterable: &'a GroupedReiterate<I, K, F>,
+    curr: usize,
+}
+
+impl<'a, I, K, F> Iterator for GroupedReiterator<'a, I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    type Item = (&'a K, Group<'a, I, K, F>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterable = self.iterable;
+        // Finding out where the next group starts requires reading through the current one
+        while iterable.groups.len() <= self.curr {
+            iterable.pull()?;
+        }
+        let group = &iterable.groups[self.curr];
+        let view = Group {
+            iterable,
+            group: self.curr,
+            curr: group.1,
+        };
+        self.curr += 1;
+        Some((&group.0, view))
+    }
+}
+
+/// A lazy view over the items of a single group, yielded by a `GroupedReiterator`
+pub struct Group<'a, I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    iterable: &'a GroupedReiterate<I, K, F>,
+    group: usize,
+    curr: usize,
+}
+
+impl<'a, I, K, F> Iterator for Group<'a, I, K, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    K: PartialEq,
+    F: FnMut(&<I::Item as Deref>::Target) -> K,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterable = self.iterable;
+        let ended = |curr| {
+            iterable
+                .group_start(self.group + 1)
+                .is_some_and(|end| curr >= end)
+        };
+        if ended(self.curr) {
+            return None;
+        }
+        if self.curr >= iterable.items.len() {
+            iterable.pull()?;
+            // The pulled item may have started the next group instead
+            if ended(self.curr) {
+                return None;
+            }
+        }
+        let item = iterable.items.get(self.curr)?;
+        self.curr += 1;
+        Some(item)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Exhaust the underlying iterator and index the positions of the items by the key
+    /// `key_fn` computes for them.
+    ///
+    /// `key_fn` is called once per item. Items cached later, e.g. from a source queued
+    /// with `queue_source()`, are added by `GroupIndex::refresh()`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let words = vec!["apple", "bean", "avocado", "beet", "cherry"];
+    /// let reiterate = Reiterate::new(words);
+    /// let mut index = reiterate.group_indices_by(|word| word.as_bytes()[0]);
+    ///
+    /// assert_eq!(index.positions(&b'a'), [0, 2]);
+    /// assert_eq!(index.iter_group(&b'b').collect::<Vec<_>>(), ["bean", "beet"]);
+    /// assert!(index.positions(&b'z').is_empty());
+    /// assert_eq!(index.iter_group(&b'z').count(), 0);
+    ///
+    /// reiterate.queue_source(vec!["apricot", "date"]);
+    /// assert_eq!(index.positions(&b'a'), [0, 2]);
+    /// assert_eq!(index.refresh(), 2);
+    /// assert_eq!(index.positions(&b'a'), [0, 2, 5]);
+    /// assert_eq!(index.iter_group(&b'd').collect::<Vec<_>>(), ["date"]);
+    /// ```
+    pub fn group_indices_by<K, F>(&self, key_fn: F) -> GroupIndex<'_, I, K, F>
+    where
+        K: Hash + Eq,
+        F: Fn(&<I::Item as Deref>::Target) -> K,
+    {
+        let mut index = GroupIndex {
+            iterable: self,
+            key_fn,
+            groups: HashMap::new(),
+            indexed: 0,
+        };
+        index.refresh();
+        index
+    }
+}
+
+/// The positions of the items of a `Reiterate` grouped by key, produced by
+/// `group_indices_by()`
+pub struct GroupIndex<'a, I, K, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    iterable: &'a Reiterate<I>,
+    key_fn: F,
+    groups: HashMap<K, Vec<u32>>,
+    // How many cached items have been added to `groups`
+    indexed: usize,
+}
+
+impl<'a, I, K, F> GroupIndex<'a, I, K, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    K: Hash + Eq,
+    F: Fn(&<I::Item as Deref>::Target) -> K,
+{
+    /// Exhaust the underlying iterator again, and index the items cached since the index
+    /// was last brought up to date. Returns how many items were added.
+    ///
+    /// If the `Reiterate` was truncated below the indexed items, the index is rebuilt.
+    pub fn refresh(&mut self) -> usize {
+        let iterable = self.iterable;
+        iterable.iter_tail().for_each(drop);
+        let len = iterable.visible_len();
+        if len < self.indexed {
+            self.groups.clear();
+            self.indexed = 0;
+        }
+        let added = len - self.indexed;
+        for idx in self.indexed..len {
+            let pos = u32::try_from(idx).expect("too many items to index");
+            let key = (self.key_fn)(&iterable.cache[idx]);
+            self.groups.entry(key).or_default().push(pos);
+        }
+        self.indexed = len;
+        added
+    }
+
+    /// The positions of the items with the given key, in stream order
+    pub fn positions<Q>(&self, key: &Q) -> &[u32]
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.groups.get(key).map_or(&[], |positions| positions)
+    }
+
+    /// Iterate over the items with the given key, in stream order
+    pub fn iter_group<Q>(
+        &self,
+        key: &Q,
+    ) -> impl Iterator<Item = &'a <I::Item as Deref>::Target> + '_
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let iterable = self.iterable;
+        self.positions(key)
+            .iter()
+            .map(move |&pos| &iterable.cache[pos as usize])
+    }
+
+    /// The number of distinct keys
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Whether there are no keys, because there are no items
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::ops::Deref;
+use std::ptr;
+
+/// The addresses of the cached items, in order
+#[derive(Default)]
+pub(crate) struct AddressIndex {
+    // (address, cache index) pairs, sorted
+    addresses: Vec<(usize, usize)>,
+    // How many cached items have been added to `addresses`
+    indexed: usize,
+}
+
+impl AddressIndex {
+    pub(crate) fn clear(&mut self) {
+        self.addresses.clear();
+        self.indexed = 0;
+    }
+}
+
+fn address<T: ?Sized>(item: &T) -> usize {
+    item as *const T as *const u8 as usize
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Find the cache index of an item from a reference a handle returned.
+    ///
+    /// Cached items never move, so the reference is matched by identity rather than by
+    /// value, in O(log n) once the cached items have been indexed. Items are indexed the
+    /// first time this is called after they get cached, which doesn't pull from the
+    /// underlying iterator. References that don't point to a cached item return `None`,
+    /// even if they're equal to one. If several cached items share their target, like
+    /// clones of an `Rc` or empty strings, the first one's index is returned.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let words = vec!["alpha", "beta", "gamma", "delta", "epsilon"];
+    /// let reiterate = Reiterate::new(words.iter().map(|w| w.to_string()));
+    /// let mut refs: Vec<(usize, &str)> = reiterate.into_iter().enumerate().collect();
+    /// refs.reverse();
+    /// refs.swap(0, 3);
+    ///
+    /// for (idx, word) in refs {
+    ///     assert_eq!(reiterate.index_of(word), Some(idx));
+    /// }
+    ///
+    /// // equal, but not from the cache
+    /// let beta = "beta".to_string();
+    /// assert_eq!(reiterate.index_of(&beta), None);
+    /// // part of a cached item, but not the item itself
+    /// let gamma = reiterate.into_iter().nth(2).unwrap();
+    /// assert_eq!(reiterate.index_of(gamma), Some(2));
+    /// assert_eq!(reiterate.index_of(&gamma[..2]), None);
+    /// assert_eq!(reiterate.index_of(&gamma[1..]), None);
+    /// ```
+    pub fn index_of(&self, item: &<I::Item as Deref>::Target) -> Option<usize> {
+        let mut index = self.addresses.borrow_mut();
+        let len = self.visible_len();
+        if index.indexed < len {
+            for idx in index.indexed..len {
+                index.addresses.push((address(&self.cache[idx]), idx));
+            }
+            index.addresses.sort_unstable();
+            index.indexed = len;
+        }
+        let addr = address(item);
+        let start = index.addresses.partition_point(|&(a, _)| a < addr);
+        index.addresses[start..]
+            .iter()
+            .take_while(|&&(a, _)| a == addr)
+            .map(|&(_, idx)| idx)
+            .find(|&idx| ptr::eq(&self.cache[idx], item))
+    }
+}
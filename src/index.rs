@@ -0,0 +1,147 @@
+use crate::{ContentionAware, CopyReiterate, ReiterableItem, Reiterate};
+
+use std::ops::{Deref, Index};
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get the item at `idx`, pulling from the underlying iterator just far enough to
+    /// reach it, or `None` if the stream is shorter than that.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(|i| Box::new(i * i)));
+    /// assert_eq!(reiterate.get(3), Some(&9));
+    /// assert_eq!(reiterate.cached_len(), 4);
+    /// // already cached, so nothing is pulled
+    /// assert_eq!(reiterate.get(1), Some(&1));
+    /// assert_eq!(reiterate.cached_len(), 4);
+    ///
+    /// assert_eq!(reiterate.get(10), None);
+    /// assert_eq!(reiterate.cached_len(), 10);
+    /// reiterate.truncate(5);
+    /// assert_eq!(reiterate.get(7), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        let mut tail = self.iter_tail();
+        match idx.checked_sub(tail.curr) {
+            Some(ahead) => tail.nth(ahead),
+            None => self.get_or_pull(idx),
+        }
+    }
+
+    /// Get the item at `idx` if it is cached, without pulling.
+    ///
+    /// The underlying iterator is never called, so this is safe to use from code paths
+    /// that mustn't trigger the side effects of a pull.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b"]);
+    /// assert_eq!(reiterate.get_cached(0), None);
+    /// reiterate.exhaust();
+    /// assert_eq!(reiterate.get_cached(1), Some("b"));
+    /// assert_eq!(reiterate.get_cached(2), None);
+    /// ```
+    pub fn get_cached(&self, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        if idx >= self.visible_len() {
+            return None;
+        }
+        self.cache.get(idx)
+    }
+}
+
+/// Index into the cached items, without pulling.
+///
+/// Like for a slice, indexing panics if the item at that index isn't cached; use
+/// `get_cached()` for an `Option`, or `get()` to pull up to it. Indexing a `CopyReiterate`
+/// isn't possible, since its cache can't be lent out as a reference; use `get()` or
+/// `as_slice()` instead.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let words = Reiterate::new("the quick brown fox".split(' ').map(String::from));
+/// words.exhaust();
+/// assert_eq!(&words[1], "quick");
+/// assert_eq!((0..4).map(|i| words[i].len()).sum::<usize>(), 16);
+/// ```
+///
+/// ```rust,should_panic
+/// use reiterate::Reiterate;
+///
+/// let lazy = Reiterate::new(vec![Box::new(1)]);
+/// // nothing has been pulled yet
+/// let _ = lazy[0];
+/// ```
+impl<I> Index<usize> for Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Output = <I::Item as Deref>::Target;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        match self.get_cached(idx) {
+            Some(item) => item,
+            None => panic!(
+                "index {} is out of the {} cached items",
+                idx,
+                self.visible_len()
+            ),
+        }
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get the item at `idx`, pulling from the underlying iterator just far enough to
+    /// reach it, or `None` if the stream is shorter than that.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("reiterate".chars());
+    /// assert_eq!(reiterate.get(2), Some('i'));
+    /// assert_eq!(reiterate.cached_len().ready(), Some(3));
+    /// assert_eq!(reiterate.get(0), Some('r'));
+    /// assert_eq!(reiterate.get(8), Some('e'));
+    /// assert_eq!(reiterate.get(9), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<I::Item> {
+        let mut tail = self.iter_tail();
+        match idx.checked_sub(tail.curr) {
+            Some(ahead) => tail.nth(ahead),
+            None => self.get_or_pull(idx),
+        }
+    }
+
+    /// Get the item at `idx` if it is cached, without pulling, or `Contended` during a
+    /// pull.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::get_cached()`, and never calls the
+    /// underlying iterator.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let requests = Cell::new(0);
+    /// let pages = CopyReiterate::new((0..10).inspect(|_| requests.set(requests.get() + 1)));
+    /// assert_eq!(pages.get_cached(0).ready(), Some(None));
+    /// assert_eq!(pages.get(2), Some(2));
+    /// assert_eq!(pages.get_cached(1).ready(), Some(Some(1)));
+    /// assert_eq!(pages.get_cached(3).ready(), Some(None));
+    /// assert_eq!(requests.get(), 3);
+    /// ```
+    pub fn get_cached(&self, idx: usize) -> ContentionAware<Option<I::Item>> {
+        self.as_slice().map(|cached| cached.get(idx).copied())
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{poison_on_unwind, remaining_hint, ContentionAware, Reiterable};
+
+use smallvec::SmallVec;
+use std::cell::{Cell, RefCell};
+
+/// An adaptor around an iterator over Copy items like `CopyReiterate`, that caches its
+/// first `N` items inline and only allocates once more than that have been pulled.
+///
+/// For short streams of small items, like the tokens of a single line, this avoids
+/// allocating anything in the common case. `spilled()` tells whether the cache has moved
+/// to the heap.
+///
+/// ```rust
+/// use reiterate::InlineCopyReiterate;
+///
+/// let short: InlineCopyReiterate<_, 8> = InlineCopyReiterate::new("a+b".chars());
+/// for _ in 0..2 {
+///     assert_eq!(short.into_iter().collect::<String>(), "a+b");
+/// }
+/// assert!(!short.spilled());
+///
+/// let long: InlineCopyReiterate<_, 8> = InlineCopyReiterate::new(0..100);
+/// let mut iter = long.into_iter();
+/// assert_eq!(iter.nth(7), Some(7));
+/// assert!(!long.spilled());
+/// assert_eq!(iter.next(), Some(8));
+/// assert!(long.spilled());
+/// assert_eq!(long.into_iter().sum::<i32>(), 4950);
+/// ```
+pub struct InlineCopyReiterate<I, const N: usize>
+where
+    I: Iterator,
+{
+    poisoned: Cell<bool>,
+    cache: RefCell<SmallVec<[I::Item; N]>>,
+    iter: RefCell<I>,
+}
+
+impl<I, const N: usize> InlineCopyReiterate<I, N>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        InlineCopyReiterate {
+            poisoned: Cell::new(false),
+            cache: RefCell::new(SmallVec::new()),
+            iter: RefCell::new(iter.into_iter()),
+        }
+    }
+
+    /// Whether the underlying iterator has panicked while an item was being pulled from
+    /// it, like for `CopyReiterate::is_poisoned()`
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.get()
+    }
+
+    /// The number of items pulled from the underlying iterator so far, or `Contended`
+    /// during a pull
+    pub fn cached_len(&self) -> ContentionAware<usize> {
+        ContentionAware::borrow(&self.cache).map(|cache| cache.len())
+    }
+
+    /// Whether more than `N` items have been pulled, so that the cache is on the heap.
+    ///
+    /// Like pulling, this panics if called during a pull.
+    pub fn spilled(&self) -> bool {
+        self.cache.borrow().spilled()
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<I::Item> {
+        if let Some(&val) = self.cache.borrow().get(idx) {
+            return Some(val);
+        }
+        let val = poison_on_unwind(&self.poisoned, || self.iter.borrow_mut().next())?;
+        self.cache.borrow_mut().push(val);
+        Some(val)
+    }
+}
+
+impl<'a, I, const N: usize> IntoIterator for &'a InlineCopyReiterate<I, N>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type IntoIter = InlineCopyReiterator<'a, I, N>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InlineCopyReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I, const N: usize> Reiterable for InlineCopyReiterate<I, N>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = InlineCopyReiterator<'a, I, N>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> InlineCopyReiterator<'_, I, N> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an
+/// `&InlineCopyReiterate` instance
+pub struct InlineCopyReiterator<'a, I, const N: usize>
+where
+    I: Iterator,
+{
+    iterable: &'a InlineCopyReiterate<I, N>,
+    curr: usize,
+}
+
+impl<'a, I, const N: usize> Clone for InlineCopyReiterator<'a, I, N>
+where
+    I: Iterator,
+{
+    fn clone(&self) -> Self {
+        InlineCopyReiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        }
+    }
+}
+
+impl<'a, I, const N: usize> Iterator for InlineCopyReiterator<'a, I, N>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Neither can be looked at during a pull, like from a closure of the source
+        let (cache, iter) = match (
+            self.iterable.cache.try_borrow(),
+            self.iterable.iter.try_borrow(),
+        ) {
+            (Ok(cache), Ok(iter)) => (cache, iter),
+            _ => return (0, None),
+        };
+        let cached = cache.len().saturating_sub(self.curr);
+        remaining_hint(cached, iter.size_hint(), usize::MAX, self.curr)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        // Skip over cached items without going through them
+        let cached = self.iterable.cache.borrow().len();
+        let skip = n.min(cached.saturating_sub(self.curr));
+        self.curr += skip;
+        for _ in skip..n {
+            self.next()?;
+        }
+        self.next()
+    }
+}
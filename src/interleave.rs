@@ -0,0 +1,132 @@
+use crate::{ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+
+/// Iterate over two `Reiterate`s in alternation, yielding `a0, b0, a1, b1, ...`.
+///
+/// Once either stream ends, the rest of the other one follows. Both are pulled lazily,
+/// and through their shared caches, so replaying the interleaved view doesn't pull
+/// anything again.
+///
+/// ```rust
+/// use reiterate::{interleave, Reiterate};
+/// use std::cell::Cell;
+///
+/// let pulls = Cell::new(0);
+/// let count = |s: &'static str| {
+///     pulls.set(pulls.get() + 1);
+///     s
+/// };
+/// let a = Reiterate::new(vec!["a0", "a1", "a2", "a3"].into_iter().map(count));
+/// let b = Reiterate::new(vec!["b0", "b1"].into_iter().map(count));
+///
+/// let first: Vec<_> = interleave(&a, &b).collect();
+/// assert_eq!(first, ["a0", "b0", "a1", "b1", "a2", "a3"]);
+/// assert_eq!(pulls.get(), 6);
+///
+/// let second: Vec<_> = interleave(&a, &b).collect();
+/// assert_eq!(second, first);
+/// assert_eq!(pulls.get(), 6);
+///
+/// let empty = Reiterate::new(Vec::<&str>::new());
+/// assert_eq!(interleave(&empty, &b).collect::<Vec<_>>(), ["b0", "b1"]);
+/// ```
+pub fn interleave<'a, I, J>(
+    a: &'a Reiterate<I>,
+    b: &'a Reiterate<J>,
+) -> InterleaveReiterator<'a, I, J>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    J: Iterator + ?Sized,
+    J::Item: ReiterableItem<Target = <I::Item as Deref>::Target>,
+{
+    InterleaveReiterator {
+        a: Some(a.into_iter()),
+        b: Some(b.into_iter()),
+        b_next: false,
+        shortest: false,
+    }
+}
+
+/// Iterate over two `Reiterate`s in alternation, yielding `a0, b0, a1, b1, ...`, until
+/// either stream ends.
+///
+/// Like `Itertools::interleave_shortest`, this stops as soon as the stream whose turn
+/// it is has ended.
+///
+/// ```rust
+/// use reiterate::{interleave_shortest, Reiterate};
+///
+/// let a = Reiterate::new(vec!["a0", "a1", "a2"]);
+/// let b = Reiterate::new(vec!["b0"]);
+/// assert_eq!(interleave_shortest(&a, &b).collect::<Vec<_>>(), ["a0", "b0", "a1"]);
+/// assert_eq!(interleave_shortest(&b, &a).collect::<Vec<_>>(), ["b0", "a0"]);
+/// ```
+pub fn interleave_shortest<'a, I, J>(
+    a: &'a Reiterate<I>,
+    b: &'a Reiterate<J>,
+) -> InterleaveReiterator<'a, I, J>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    J: Iterator + ?Sized,
+    J::Item: ReiterableItem<Target = <I::Item as Deref>::Target>,
+{
+    InterleaveReiterator {
+        shortest: true,
+        ..interleave(a, b)
+    }
+}
+
+/// An iterator alternating between two `Reiterate`s, produced by `interleave()` or
+/// `interleave_shortest()`
+pub struct InterleaveReiterator<'a, I, J>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    J: Iterator + ?Sized,
+    J::Item: ReiterableItem<Target = <I::Item as Deref>::Target>,
+{
+    // `None` once the stream has ended
+    a: Option<Reiterator<'a, I>>,
+    b: Option<Reiterator<'a, J>>,
+    b_next: bool,
+    shortest: bool,
+}
+
+impl<'a, I, J> Iterator for InterleaveReiterator<'a, I, J>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    J: Iterator + ?Sized,
+    J::Item: ReiterableItem<Target = <I::Item as Deref>::Target>,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for _ in 0..2 {
+            let b_next = self.b_next;
+            self.b_next = !b_next;
+            let item = if b_next {
+                self.b.as_mut().and_then(Iterator::next)
+            } else {
+                self.a.as_mut().and_then(Iterator::next)
+            };
+            if item.is_some() {
+                return item;
+            }
+            if b_next {
+                self.b = None;
+            } else {
+                self.a = None;
+            }
+            if self.shortest {
+                self.a = None;
+                self.b = None;
+                return None;
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{poison_on_unwind, Reiterable, ReiterableItem};
+
+use elsa::FrozenVec;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::ops::Deref;
+
+/// An adaptor around an iterator of heap-allocated items that can produce multiple
+/// iterators sharing an underlying cache, keeping a single copy of equal items.
+///
+/// Each item is looked up among the distinct items cached so far as it is pulled. If an
+/// equal one is already cached, the new item is dropped right away and its position in
+/// the stream refers to the earlier one instead. Handles replay the stream exactly like
+/// for a `Reiterate`, but equal items are yielded as references to the same allocation,
+/// which saves a lot of memory for repetitive streams, like the fields of a log.
+///
+/// ```rust
+/// use reiterate::InternedReiterate;
+///
+/// let levels = ["info", "warn", "info", "info", "error", "warn"];
+/// let reiterate = InternedReiterate::new(levels.iter().map(|level| level.to_string()));
+/// for _ in 0..2 {
+///     assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), levels);
+/// }
+/// assert_eq!(reiterate.cached_len(), 6);
+/// assert_eq!(reiterate.distinct_len(), 3);
+///
+/// let mut iter = reiterate.into_iter();
+/// let first = iter.next().unwrap();
+/// let third = iter.nth(1).unwrap();
+/// assert!(std::ptr::eq(first, third));
+/// ```
+pub struct InternedReiterate<I>
+where
+    I: Iterator,
+{
+    poisoned: Cell<bool>,
+    // One of each distinct item, in the order they were first pulled
+    distinct: FrozenVec<I::Item>,
+    // The indices into `distinct` of the items with each hash
+    buckets: RefCell<HashMap<u64, Vec<usize>>>,
+    // For each position in the stream, the index into `distinct` of its item
+    positions: RefCell<Vec<usize>>,
+    hasher: RandomState,
+    iter: RefCell<I>,
+}
+
+impl<I> InternedReiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Eq + Hash,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        InternedReiterate {
+            poisoned: Cell::new(false),
+            distinct: FrozenVec::new(),
+            buckets: RefCell::new(HashMap::new()),
+            positions: RefCell::new(Vec::new()),
+            hasher: RandomState::new(),
+            iter: RefCell::new(iter.into_iter()),
+        }
+    }
+
+    /// Whether the underlying iterator has panicked while an item was being pulled from
+    /// it, like for `Reiterate::is_poisoned()`
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.get()
+    }
+
+    /// The number of items pulled from the underlying iterator so far
+    pub fn cached_len(&self) -> usize {
+        self.positions.borrow().len()
+    }
+
+    /// The number of distinct items among them, each of which is cached once
+    pub fn distinct_len(&self) -> usize {
+        self.distinct.len()
+    }
+
+    /// Get the index into `distinct` of an item equal to `val`, caching it if there is none
+    fn intern(&self, val: I::Item) -> usize {
+        let hash = self.hasher.hash_one(&*val);
+        let mut buckets = self.buckets.borrow_mut();
+        let bucket = buckets.entry(hash).or_default();
+        if let Some(&idx) = bucket.iter().find(|&&idx| self.distinct[idx] == *val) {
+            return idx;
+        }
+        self.distinct.push(val);
+        let idx = self.distinct.len() - 1;
+        bucket.push(idx);
+        idx
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        let cached = self.positions.borrow().get(idx).copied();
+        let interned = match cached {
+            Some(interned) => interned,
+            None => {
+                let val = poison_on_unwind(&self.poisoned, || self.iter.borrow_mut().next())?;
+                let interned = self.intern(val);
+                self.positions.borrow_mut().push(interned);
+                interned
+            }
+        };
+        self.distinct.get(interned)
+    }
+}
+
+impl<'a, I> IntoIterator for &'a InternedReiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Eq + Hash,
+{
+    type IntoIter = InternedReiterator<'a, I>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InternedReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I> Reiterable for InternedReiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Eq + Hash,
+{
+    type Item<'a>
+        = &'a <I::Item as Deref>::Target
+    where
+        Self: 'a;
+    type Iter<'a>
+        = InternedReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> InternedReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&InternedReiterate`
+/// instance
+pub struct InternedReiterator<'a, I>
+where
+    I: Iterator,
+{
+    iterable: &'a InternedReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Clone for InternedReiterator<'a, I>
+where
+    I: Iterator,
+{
+    fn clone(&self) -> Self {
+        InternedReiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        }
+    }
+}
+
+impl<'a, I> Iterator for InternedReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Eq + Hash,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip over cached items without going through them
+        let skip = n.min(self.iterable.cached_len().saturating_sub(self.curr));
+        self.curr += skip;
+        for _ in skip..n {
+            self.next()?;
+        }
+        self.next()
+    }
+}
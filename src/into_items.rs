@@ -0,0 +1,127 @@
+use crate::{remaining_hint, CopyReiterate, CopyReiterateInner, ReiterableItem, Reiterate};
+
+use std::collections::VecDeque;
+use std::vec;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Get an iterator over the items by value, which yields the cached items and then
+    /// pulls the rest of the stream, once there is no more replaying to do.
+    ///
+    /// The stream goes on through the queued sources, and ends at the length set by
+    /// `truncate()`, like for a handle. This isn't `IntoIterator` for `Reiterate`, since
+    /// `.into_iter()` on a `Reiterate` gives a handle, the way it does on a reference.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// assert_eq!(reiterate.into_iter().next(), Some("a"));
+    /// reiterate.queue_source(vec!["d".to_string()]);
+    ///
+    /// let mut owned = Vec::new();
+    /// for s in reiterate.into_items() {
+    ///     owned.push(s);
+    /// }
+    /// assert_eq!(owned, ["a", "b", "c", "d"]);
+    /// ```
+    pub fn into_items(self) -> IntoItems<I> {
+        let left = self.limit.get();
+        let len = self.visible_len();
+        let mut cache = self.cache.into_vec();
+        cache.truncate(len);
+        IntoItems {
+            cached: cache.into_iter(),
+            iter: self.iter.into_inner(),
+            // SAFETY: the queued sources have the type of the underlying iterator
+            queued: unsafe { self.queued.into_sources() },
+            left,
+        }
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// Get an iterator over the cached items and then the rest of the stream, like
+    /// `Reiterate::into_items()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(0..10);
+    /// reiterate.into_iter().nth(3);
+    /// reiterate.truncate(6);
+    /// let mut items = reiterate.into_items();
+    /// assert_eq!(items.size_hint(), (6, Some(6)));
+    /// assert_eq!(items.collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn into_items(self) -> IntoItems<I> {
+        let left = self.limit.get();
+        let len = self.visible_len();
+        let CopyReiterateInner { mut cache, iter } = self.inner.into_inner();
+        cache.truncate(len);
+        IntoItems {
+            cached: cache.into_iter(),
+            iter,
+            // SAFETY: as above
+            queued: unsafe { self.queued.into_sources() },
+            left,
+        }
+    }
+}
+
+/// An iterator over the items of a `Reiterate` or `CopyReiterate` by value, produced by
+/// `into_items()`
+pub struct IntoItems<I>
+where
+    I: Iterator,
+{
+    cached: vec::IntoIter<I::Item>,
+    iter: I,
+    queued: VecDeque<I>,
+    // How many more items the stream has at most, as set by `truncate()`
+    left: usize,
+}
+
+impl<I> Iterator for IntoItems<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        let val = self.cached.next().or_else(|| {
+            self.iter.next().or_else(|| loop {
+                let source = self.queued.front_mut()?;
+                if let Some(val) = source.next() {
+                    return Some(val);
+                }
+                self.queued.pop_front();
+            })
+        })?;
+        self.left -= 1;
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sources = self.queued.iter().map(Iterator::size_hint).fold(
+            self.iter.size_hint(),
+            |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            },
+        );
+        remaining_hint(self.cached.len(), sources, self.left, 0)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{ContentionAware, Reiterate};
+
+use serde::de::DeserializeOwned;
+use std::cell::Ref;
+use std::io::BufRead;
+use std::marker::PhantomData;
+
+/// What `Reiterate::from_json_lines` should do with a line that fails to parse
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonLinesPolicy {
+    /// End the stream at the first malformed line
+    Stop,
+    /// Record the error and continue with the next line
+    SkipErrors,
+}
+
+/// An iterator deserializing one boxed value per line of a reader, used as the source of
+/// `Reiterate::from_json_lines`.
+pub struct JsonLines<T, R> {
+    reader: R,
+    policy: JsonLinesPolicy,
+    buf: String,
+    line: usize,
+    done: bool,
+    errors: Vec<(usize, serde_json::Error)>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, R> JsonLines<T, R> {
+    /// The errors encountered so far, along with their 1-based line numbers
+    pub fn errors(&self) -> &[(usize, serde_json::Error)] {
+        &self.errors
+    }
+}
+
+impl<T, R> Iterator for JsonLines<T, R>
+where
+    T: DeserializeOwned,
+    R: BufRead,
+{
+    type Item = Box<T>;
+
+    fn next(&mut self) -> Option<Box<T>> {
+        while !self.done {
+            self.buf.clear();
+            self.line += 1;
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => self.done = true,
+                Ok(_) if self.buf.trim().is_empty() => (),
+                Ok(_) => match serde_json::from_str(&self.buf) {
+                    Ok(val) => return Some(Box::new(val)),
+                    Err(err) => {
+                        self.errors.push((self.line, err));
+                        self.done = self.policy == JsonLinesPolicy::Stop;
+                    }
+                },
+                Err(err) => {
+                    self.errors.push((self.line, serde_json::Error::io(err)));
+                    self.done = true;
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T, R> Reiterate<JsonLines<T, R>>
+where
+    T: DeserializeOwned,
+    R: BufRead,
+{
+    /// Create a `Reiterate` over the JSON values on each line of `reader`.
+    ///
+    /// Lines are read and deserialized lazily, and each value is only ever parsed once.
+    /// Blank lines are ignored. Malformed lines are recorded in `json_errors()`, and
+    /// either end the stream or are skipped depending on `policy`. I/O errors always end
+    /// the stream.
+    ///
+    /// ```rust
+    /// use reiterate::{JsonLines, JsonLinesPolicy, Reiterate};
+    ///
+    /// let input = "[\"a\", 1]\n[\"b\", 2]\nnot json\n[\"c\", 3]\n";
+    ///
+    /// let stop: Reiterate<JsonLines<(String, u32), _>> =
+    ///     Reiterate::from_json_lines(input.as_bytes(), JsonLinesPolicy::Stop);
+    /// for _ in 0..2 {
+    ///     let names: Vec<_> = stop.into_iter().map(|(name, _)| name.as_str()).collect();
+    ///     assert_eq!(names, ["a", "b"]);
+    /// }
+    /// assert_eq!(stop.json_errors().ready().unwrap().len(), 1);
+    /// assert_eq!(stop.json_errors().ready().unwrap()[0].0, 3);
+    ///
+    /// let skip: Reiterate<JsonLines<(String, u32), _>> =
+    ///     Reiterate::from_json_lines(input.as_bytes(), JsonLinesPolicy::SkipErrors);
+    /// for _ in 0..2 {
+    ///     let names: Vec<_> = skip.into_iter().map(|(name, _)| name.as_str()).collect();
+    ///     assert_eq!(names, ["a", "b", "c"]);
+    /// }
+    /// assert_eq!(skip.json_errors().ready().unwrap().len(), 1);
+    /// assert!(skip.json_errors().ready().unwrap()[0].1.is_syntax());
+    /// ```
+    pub fn from_json_lines(reader: R, policy: JsonLinesPolicy) -> Self {
+        Reiterate::new(JsonLines {
+            reader,
+            policy,
+            buf: String::new(),
+            line: 0,
+            done: false,
+            errors: Vec::new(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// The errors encountered so far, along with their 1-based line numbers, or
+    /// `Contended` during a pull
+    pub fn json_errors(&self) -> ContentionAware<Ref<'_, [(usize, serde_json::Error)]>> {
+        ContentionAware::borrow(&self.iter).map(|iter| Ref::map(iter, |iter| iter.errors()))
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{poison_on_unwind, CopyReiterate, CopyReiterator};
+
+use std::cell::Cell;
+
+/// Where a `CopyReiterate` created by `lazy_caching()` is at
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LazyCaching {
+    /// Caching everything, which is where every other `CopyReiterate` starts
+    Off,
+    /// No handle has been created yet
+    Unused,
+    /// The first handle is streaming items without caching them
+    Streaming,
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// Create a `CopyReiterate` that only starts caching once it is iterated over a second
+    /// time, for code paths that usually only iterate once.
+    ///
+    /// The first handle streams items straight from the underlying iterator. As soon as
+    /// another handle is created, or anything else needs the cache, everything pulled from
+    /// then on is cached as usual. The items that were streamed until then are gone, so
+    /// the cache and every handle created later start at the first item pulled after
+    /// that; `missed_prefix_len()` tells how many items were missed.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::lazy_caching(0..6);
+    /// let mut first = reiterate.into_iter();
+    /// assert_eq!(first.by_ref().take(2).collect::<Vec<_>>(), [0, 1]);
+    /// assert_eq!(reiterate.cache_size_bytes().ready(), Some(0));
+    ///
+    /// // a second handle starts caching
+    /// let second = reiterate.into_iter();
+    /// assert_eq!(reiterate.missed_prefix_len(), 2);
+    /// assert_eq!(first.collect::<Vec<_>>(), [2, 3, 4, 5]);
+    /// assert_eq!(second.collect::<Vec<_>>(), [2, 3, 4, 5]);
+    /// assert_eq!(reiterate.into_iter().count(), 4);
+    /// ```
+    ///
+    /// A single pass never caches anything:
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::lazy_caching(0..1000);
+    /// assert_eq!(reiterate.into_iter().sum::<u32>(), 499500);
+    /// assert_eq!(reiterate.cache_size_bytes().ready(), Some(0));
+    /// assert_eq!(reiterate.missed_prefix_len(), 1000);
+    ///
+    /// let reiterate = CopyReiterate::lazy_caching(0..5);
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [0, 1, 2, 3, 4]);
+    /// assert_eq!(reiterate.cached_len().ready(), Some(0));
+    /// ```
+    pub fn lazy_caching<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        CopyReiterate {
+            lazy: Cell::new(LazyCaching::Unused),
+            lazily: true,
+            ..CopyReiterate::new(iter)
+        }
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// How many items were streamed through by the first handle of a `lazy_caching()`
+    /// `CopyReiterate`, and aren't in the cache.
+    pub fn missed_prefix_len(&self) -> usize {
+        self.missed.get()
+    }
+
+    /// Note that a handle is being created
+    pub(crate) fn start_handle(&self) {
+        match self.lazy.get() {
+            LazyCaching::Off => {}
+            LazyCaching::Unused => self.lazy.set(LazyCaching::Streaming),
+            LazyCaching::Streaming => self.lazy.set(LazyCaching::Off),
+        }
+    }
+
+    /// Start caching, since something other than the first handle needs the cache
+    pub(crate) fn stop_streaming(&self) {
+        self.lazy.set(LazyCaching::Off);
+    }
+}
+
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Pull the next item from the source without caching it, for the first handle of a
+    /// `lazy_caching()` `CopyReiterate`.
+    ///
+    /// There is no logical length to check, since `truncate()` stops streaming.
+    #[inline]
+    pub(crate) fn next_streamed(&mut self) -> Option<I::Item> {
+        let iterable = self.iterable;
+        let val = poison_on_unwind(&iterable.poisoned, || {
+            let val = iterable.inner.borrow_mut().iter.next();
+            iterable.queued.or_pull(val)
+        })?;
+        iterable.missed.set(iterable.missed.get() + 1);
+        Some(val)
+    }
+}
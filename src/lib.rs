@@ -1,8 +1,186 @@
+use crate::fill::Fill;
 use elsa::FrozenVec;
 
 pub use stable_deref_trait::StableDeref;
 use std::cell::{Cell, RefCell};
+use std::iter;
+use std::mem;
 use std::ops::Deref;
+use std::panic::RefUnwindSafe;
+
+mod aliasing;
+mod append;
+mod arena;
+#[cfg(feature = "futures")]
+mod async_reiterate;
+mod bi;
+mod budget;
+mod channel;
+mod chars;
+mod checkpoint;
+mod chunk;
+mod clone;
+mod close;
+#[cfg(feature = "lz4_flex")]
+mod compressed;
+mod content;
+mod contention;
+mod convert;
+mod cycle;
+mod dedup;
+mod exhaust;
+mod ext;
+mod fallible;
+#[cfg(feature = "fallible-iterator")]
+mod fallible_iter;
+mod fill;
+mod flatten;
+mod frame;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod generate;
+mod group;
+mod group_index;
+mod identity;
+mod index;
+#[cfg(feature = "smallvec")]
+mod inline;
+mod interleave;
+mod intern;
+mod into_items;
+#[cfg(feature = "serde")]
+mod json;
+mod lazy;
+mod lock;
+mod map;
+mod map_view;
+mod options;
+mod owned;
+mod pairwise;
+#[cfg(feature = "rayon")]
+mod par;
+mod partition;
+mod pin;
+mod primary;
+mod push;
+mod queue;
+mod rc;
+mod reiterable;
+mod reiterate_mut;
+mod replace;
+mod retain;
+mod rev;
+mod rewind;
+#[cfg(feature = "rand")]
+mod sample;
+mod scan;
+#[cfg(feature = "serde")]
+mod serialize;
+mod size;
+mod skip_errors;
+#[cfg(feature = "bytemuck")]
+mod snapshot;
+mod sorted;
+#[cfg(feature = "spill")]
+mod spill;
+mod split;
+mod split_at;
+mod stats;
+mod str_arena;
+pub mod sync;
+mod timestamps;
+mod tracked;
+mod truncate;
+mod tuples;
+mod watch;
+mod while_view;
+mod window;
+
+pub use crate::arena::{ArenaReiterate, ArenaReiterator};
+#[cfg(feature = "tokio")]
+pub use crate::async_reiterate::AsyncLines;
+#[cfg(feature = "futures")]
+pub use crate::async_reiterate::{AsyncReiterate, AsyncReiterator};
+pub use crate::bi::{BiReiterate, BiReiterator};
+pub use crate::budget::{BudgetedNext, PullBudget};
+pub use crate::channel::{ChannelSource, TryNext};
+#[cfg(feature = "unicode-segmentation")]
+pub use crate::chars::GraphemeReiterator;
+pub use crate::checkpoint::{Checkpoint, StaleCheckpoint};
+pub use crate::chunk::{
+    ArrayChunkRemainder, BufferedChunks, ChunkResult, ChunkView, WeightedChunk, WeightedChunks,
+    WeightedChunksReiterator,
+};
+pub use crate::clone::{ArcSource, CloneReiterate, CloneReiterator, RcSource, SharedReiterate};
+pub use crate::close::Closable;
+#[cfg(feature = "lz4_flex")]
+pub use crate::compressed::{CompressedReiterate, CompressedReiterator};
+pub use crate::content::{ContentKey, Divergence};
+pub use crate::contention::ContentionAware;
+pub use crate::convert::{BoxedCopyReiterate, BoxedReiterate, BoxedSource};
+pub use crate::cycle::{CopyCycleReiterator, CycleReiterator};
+pub use crate::dedup::{CopyDedupReiterator, CopyDedupView, DedupReiterator, DedupView, TargetEq};
+pub use crate::exhaust::PrimeOutcome;
+pub use crate::ext::ReiterateExt;
+pub use crate::fallible::{TryReiterate, TryReiterator, UntilError};
+#[cfg(feature = "fallible-iterator")]
+pub use crate::fallible_iter::{FallibleReiterate, FallibleReiterator};
+pub use crate::flatten::{FlattenIter, FlattenView};
+pub use crate::frame::FrameView;
+pub use crate::group::{Group, GroupedReiterate, GroupedReiterator};
+pub use crate::group_index::GroupIndex;
+#[cfg(feature = "smallvec")]
+pub use crate::inline::{InlineCopyReiterate, InlineCopyReiterator};
+pub use crate::interleave::{interleave, interleave_shortest, InterleaveReiterator};
+pub use crate::intern::{InternedReiterate, InternedReiterator};
+pub use crate::into_items::IntoItems;
+#[cfg(feature = "serde")]
+pub use crate::json::{JsonLines, JsonLinesPolicy};
+pub use crate::map::{ReiterateMap, ReiterateMapIter};
+pub use crate::map_view::{CopyMapView, CopyMapViewReiterator, MapView, MapViewReiterator};
+pub use crate::options::{OptionSource, OptionsReiterator, PresentReiterator};
+pub use crate::owned::{ClonedReiterator, CopiedReiterator, OwnedReiterator};
+pub use crate::pairwise::{DeltaReiterator, DeltaView, PairwiseMapReiterator, PairwiseMapView};
+pub use crate::partition::{PartitionReiterator, PartitionView};
+pub use crate::pin::{PinReiterate, PinReiterator};
+pub use crate::primary::{CopyPrimaryIter, PrimaryIter, SharedCursor};
+pub use crate::push::{CopyPushReiterate, CopyPushReiterator, PushReiterate, PushReiterator};
+pub use crate::rc::{RcReiterate, RcReiterator};
+pub use crate::reiterable::Reiterable;
+pub use crate::reiterate_mut::ReiterateMut;
+pub use crate::rev::{CachedReiterator, CopyCachedReiterator};
+pub use crate::scan::{ScanReiterate, ScanReiterator};
+pub use crate::skip_errors::SkipErrors;
+pub use crate::sorted::{CopySortedView, SortedView};
+#[cfg(feature = "spill")]
+pub use crate::spill::{SpillingReiterate, SpillingReiterator};
+pub use crate::split::{Segment, SplitReiterator, SplitView};
+pub use crate::split_at::{PrefixView, SuffixView};
+pub use crate::stats::CacheStats;
+pub use crate::str_arena::{StrArenaReiterate, StrArenaReiterator};
+pub use crate::timestamps::{TimedReiterator, Timestamped};
+pub use crate::tracked::{TrackedReiterate, TrackedReiterator};
+pub use crate::tuples::{CopyTupleWindows, LazyWindows, TupleWindows};
+pub use crate::watch::{CopyWatcher, Watcher};
+pub use crate::while_view::{
+    SkipWhileReiterator, SkipWhileView, TakeWhileReiterator, TakeWhileView,
+};
+pub use crate::window::WindowedReiterate;
+
+/// Items that can be cached by a `Reiterate`.
+///
+/// This is implemented for every `StableDeref` type and can't be implemented otherwise;
+/// it only exists so that the compiler can point at a fix when the bound isn't met.
+/// The fix is part of the message, since the compiler sometimes replaces labels and notes
+/// with its own suggestions.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be cached by `Reiterate`: wrap items with `.map(Box::new)`, or use `CopyReiterate` for `Copy` items",
+    label = "`{Self}` doesn't implement `StableDeref`"
+)]
+pub trait ReiterableItem: StableDeref {}
+
+#[diagnostic::do_not_recommend]
+impl<T: StableDeref> ReiterableItem for T {}
 
 /// An adaptor around an iterator that can produce multiple iterators
 /// sharing an underlying cache.
@@ -37,37 +215,195 @@ use std::ops::Deref;
 ///     println!("{}", i);
 /// }
 /// ```
+// `repr(C)` and the source coming last let a boxed `Reiterate` be converted into one
+// over a `dyn Iterator`, see `convert.rs`
+#[repr(C)]
 pub struct Reiterate<I>
 where
-    I: Iterator,
-    I::Item: StableDeref,
+    I: Iterator + ?Sized,
 {
-    iter: RefCell<I>,
-    curr: Cell<usize>,
     cache: FrozenVec<I::Item>,
+    poisoned: Cell<bool>,
+    sorted: sorted::SortedSlot,
+    measurer: Option<size::Measurer<I::Item>>,
+    // The total heap size of the cached items, as reported by `measurer`
+    heap_size: Cell<usize>,
+    addresses: RefCell<identity::AddressIndex>,
+    // Set by `detect_aliasing()`
+    aliasing: Option<RefCell<aliasing::AliasDetector>>,
+    // The logical length of the stream set by `truncate()`, or `usize::MAX`
+    limit: Cell<usize>,
+    // Bumped whenever the cache is rewritten, to tell stale checkpoints apart
+    generation: usize,
+    // Where the iterator returned by `primary()` is at
+    primary: Cell<usize>,
+    // Set by `with_stats()`
+    stats: Option<Box<stats::Counters>>,
+    // Whether `next()` has to go through `next_uncached()` even for cached items, because
+    // the stream is truncated or hits are counted
+    slow_path: Cell<bool>,
+    // The sources to pull from once `iter` has ended
+    queued: queue::SourceQueue<I::Item>,
+    iter: RefCell<I>,
 }
 
 impl<I> Reiterate<I>
 where
-    I: Iterator,
-    I::Item: StableDeref,
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
 {
     pub fn new<T>(iter: T) -> Self
     where
+        I: Sized,
         T: IntoIterator<Item = I::Item, IntoIter = I>,
     {
         Reiterate {
-            iter: RefCell::new(iter.into_iter()),
             cache: FrozenVec::new(),
-            curr: Cell::new(0),
+            poisoned: Cell::new(false),
+            sorted: Default::default(),
+            measurer: None,
+            heap_size: Cell::new(0),
+            addresses: RefCell::default(),
+            aliasing: None,
+            limit: Cell::new(usize::MAX),
+            generation: 0,
+            primary: Cell::new(0),
+            stats: None,
+            slow_path: Cell::new(false),
+            queued: Default::default(),
+            iter: RefCell::new(iter.into_iter()),
+        }
+    }
+
+    /// Create a `Reiterate` whose cache has room for `capacity` items, so that it doesn't
+    /// reallocate until then.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let lines = vec!["a".to_string(); 1000];
+    /// let reiterate = Reiterate::with_capacity(lines, 1000);
+    /// assert_eq!(reiterate.into_iter().count(), 1000);
+    /// ```
+    pub fn with_capacity<T>(iter: T, capacity: usize) -> Self
+    where
+        I: Sized,
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Reiterate {
+            cache: FrozenVec::from(Vec::with_capacity(capacity)),
+            ..Reiterate::new(iter)
+        }
+    }
+
+    /// Whether the underlying iterator has panicked while an item was being pulled from it.
+    ///
+    /// A panic during a pull leaves the cached prefix intact and usable, since items are
+    /// only cached once the underlying iterator has returned them. Once poisoned, the
+    /// flag stays set: the underlying iterator may be in an unusable state, so callers
+    /// should decide whether to trust anything it produces afterwards, including the end
+    /// of the stream.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::panic::catch_unwind;
+    ///
+    /// let x = (0..5).map(|i| if i == 3 { panic!("broken source") } else { Box::new(i) });
+    /// let reiterate = Reiterate::new(x);
+    ///
+    /// let result = catch_unwind(|| reiterate.into_iter().count());
+    /// assert!(result.is_err());
+    /// assert!(reiterate.is_poisoned());
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.by_ref().take(3).cloned().collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.get()
+    }
+
+    /// Get an iterator that starts after the cached items, only yielding items
+    /// that haven't been pulled from the underlying iterator yet.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c"]);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next(), Some("a"));
+    ///
+    /// let mut tail = reiterate.iter_tail();
+    /// assert_eq!(tail.next(), Some("b"));
+    /// // the item pulled by the tail is cached for everyone else
+    /// assert_eq!(iter.next(), Some("b"));
+    /// ```
+    pub fn iter_tail(&self) -> Reiterator<'_, I> {
+        Reiterator {
+            iterable: self,
+            curr: self.visible_len(),
+        }
+    }
+
+    /// Get an iterator that starts at index `start`, pulling up to it first if it isn't
+    /// cached yet.
+    ///
+    /// The cached items before `start` are skipped over without being walked through. If
+    /// the stream is shorter than `start`, the iterator starts at its end.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let pages = Reiterate::new((1..=5).map(|i| format!("page {}", i)));
+    /// let mut resumed = pages.iter_from(3);
+    /// assert_eq!(pages.cached_len(), 3);
+    /// assert_eq!(resumed.next(), Some("page 4"));
+    ///
+    /// assert_eq!(pages.iter_from(1).next(), Some("page 2"));
+    /// assert_eq!(pages.iter_from(10).next(), None);
+    /// assert_eq!(pages.cached_len(), 5);
+    /// ```
+    pub fn iter_from(&self, start: usize) -> Reiterator<'_, I> {
+        let mut tail = self.iter_tail();
+        if let Some(ahead) = start.checked_sub(tail.curr) {
+            (&mut tail).take(ahead).for_each(drop);
+        }
+        Reiterator {
+            iterable: self,
+            curr: start.min(tail.curr),
+        }
+    }
+
+    /// The number of cached items within the logical length set by `truncate()`
+    fn visible_len(&self) -> usize {
+        self.cache.len().min(self.limit.get())
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if needed.
+    /// Only pulls once, so `idx` should be at most the length of the cache.
+    fn get_or_pull(&self, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        if idx >= self.limit.get() {
+            return None;
         }
+        if idx >= self.cache.len() && self.iter_tail().next().is_none() {
+            return None;
+        }
+        self.cache.get(idx)
     }
 }
 
+/// Panics in the underlying iterator never leave the cache in an inconsistent state,
+/// and are reported through `is_poisoned()`.
+impl<I> RefUnwindSafe for Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: StableDeref + RefUnwindSafe,
+{
+}
+
 impl<'a, I> IntoIterator for &'a Reiterate<I>
 where
-    I: Iterator,
-    I::Item: StableDeref,
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
 {
     type IntoIter = Reiterator<'a, I>;
     type Item = &'a <I::Item as Deref>::Target;
@@ -81,38 +417,407 @@ where
 }
 
 /// An individual iterator, produced by calling `.into_iter()` on an `&Reiterate` instance
+///
+/// Handles aren't fused: one that has returned `None` carries on into the items of a
+/// source queued later, or of an underlying iterator that resumes. Use `.fuse()` for a
+/// handle that stays done.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let reiterate = Reiterate::new(vec![Box::new(1)]);
+/// let mut iter = reiterate.into_iter();
+/// let mut fused = reiterate.into_iter().fuse();
+/// assert_eq!((iter.next(), iter.next()), (Some(&1), None));
+/// assert_eq!((fused.next(), fused.next()), (Some(&1), None));
+/// reiterate.queue_source(vec![Box::new(2)]);
+/// assert_eq!(iter.next(), Some(&2));
+/// assert_eq!(fused.next(), None);
+/// ```
 pub struct Reiterator<'a, I>
 where
-    I: Iterator,
-    I::Item: StableDeref,
+    I: Iterator + ?Sized,
 {
     iterable: &'a Reiterate<I>,
     curr: usize,
 }
 
+/// Forks the iterator: the clone starts where this one is at, and both share the cache.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let reiterate = Reiterate::new(vec!["(", "a", ")"]);
+/// let mut iter = reiterate.into_iter();
+/// iter.next();
+/// let mut speculative = iter.clone();
+/// assert_eq!(speculative.next(), Some("a"));
+/// assert_eq!(speculative.next(), Some(")"));
+/// // the fork doesn't move the original
+/// assert_eq!(iter.next(), Some("a"));
+/// ```
+impl<'a, I> Clone for Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Reiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        }
+    }
+}
+
+impl<'a, I> Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: StableDeref + Copy,
+{
+    /// Advances the iterator like `next()`, but returns the cached item itself
+    /// instead of a reference to its target.
+    ///
+    /// This is useful when the underlying iterator produces references like `&'b str`,
+    /// since the result keeps its original lifetime instead of being tied to the `Reiterate`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let words: Vec<&'static str> = {
+    ///     let reiterate = Reiterate::new(vec!["a", "b", "c"]);
+    ///     let mut iter = reiterate.into_iter();
+    ///     let mut words = Vec::new();
+    ///     while let Some(word) = iter.next_original() {
+    ///         words.push(word);
+    ///     }
+    ///     words
+    /// };
+    /// assert_eq!(words, ["a", "b", "c"]);
+    /// ```
+    pub fn next_original(&mut self) -> Option<I::Item> {
+        self.next()?;
+        self.iterable.cache.get_copy(self.curr - 1)
+    }
+}
+
 impl<'a, I> Iterator for Reiterator<'a, I>
 where
-    I: Iterator,
+    I: Iterator + ?Sized,
     I::Item: StableDeref + Sized,
 {
     type Item = &'a <I::Item as Deref>::Target;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let itercurr = self.iterable.curr.get();
-        if self.curr == itercurr {
-            self.iterable.curr.set(itercurr + 1);
-            let val = self.iterable.iter.borrow_mut().next();
-            self.curr += 1;
-            if let Some(val) = val {
-                self.iterable.cache.push(val)
+        match self.iterable.cache.get(self.curr) {
+            Some(val) if !self.iterable.slow_path.get() => {
+                self.curr += 1;
+                Some(val)
             }
-            return self.iterable.cache.get(self.curr - 1);
-        } else if self.curr > itercurr {
-            return None;
+            _ => self.next_uncached(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let iterable = self.iterable;
+        let cached = iterable.cache.len().saturating_sub(self.curr);
+        // The source can't be asked during a pull, like from one of its closures
+        let source = match iterable.iter.try_borrow() {
+            Ok(iter) => iterable.queued.chain_size_hint(iter.size_hint()),
+            Err(_) => (0, None),
+        };
+        remaining_hint(cached, source, iterable.limit.get(), self.curr)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip the cached items without going through them
+        let skip = n.min(self.iterable.visible_len().saturating_sub(self.curr));
+        self.curr += skip;
+        for _ in skip..n {
+            self.next()?;
+        }
+        self.next()
+    }
+
+    fn count(mut self) -> usize {
+        let cached = self.iterable.visible_len().saturating_sub(self.curr);
+        self.curr += cached;
+        self.fold(cached, |count, _| count + 1)
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // Skip to the last cached item, then pull whatever comes after it
+        let cached = self.iterable.visible_len().saturating_sub(self.curr);
+        self.curr += cached.saturating_sub(1);
+        self.fold(None, |_, val| Some(val))
+    }
+}
+
+/// The size hint is exact when the underlying iterator's is, counting the cached items
+/// this handle hasn't reached and the items left to pull.
+///
+/// Like the size hint, `len()` can't be known during a pull, and panics if called from
+/// inside the underlying iterator.
+///
+/// ```rust
+/// use reiterate::Reiterate;
+///
+/// let reiterate = Reiterate::new((0..10).map(Box::new));
+/// let mut iter = reiterate.into_iter();
+/// iter.nth(3);
+/// assert_eq!(iter.len(), 6);
+/// assert_eq!(reiterate.into_iter().len(), 10);
+/// reiterate.truncate(5);
+/// assert_eq!(iter.len(), 1);
+/// ```
+impl<'a, I> ExactSizeIterator for Reiterator<'a, I>
+where
+    I: ExactSizeIterator + ?Sized,
+    I::Item: StableDeref + Sized,
+{
+}
+
+impl<'a, I> Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Look at the next item without advancing the iterator.
+    ///
+    /// The item is pulled if it isn't cached yet, and stays cached for every handle, so
+    /// peeking any number of times only pulls once.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["let", "x", "=", "1"]);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.peek(), Some("let"));
+    /// assert_eq!(iter.peek(), Some("let"));
+    /// assert_eq!(reiterate.cached_len(), 1);
+    /// assert_eq!(iter.next(), Some("let"));
+    /// assert_eq!(iter.nth(2), Some("1"));
+    /// assert_eq!(iter.peek(), None);
+    /// ```
+    pub fn peek(&self) -> Option<&'a <I::Item as Deref>::Target> {
+        Reiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        }
+        .next()
+    }
+
+    /// Advance the iterator and return the next item if `pred` returns true for it.
+    ///
+    /// A rejected item isn't consumed: it stays available to this iterator and, like any
+    /// other pulled item, to all the others.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let tokens = Reiterate::new(vec!["let", "x", "=", "1", ";"].into_iter().map(Box::new));
+    ///
+    /// // `let <name> = <number> ;`
+    /// let parse = |tokens: &Reiterate<_>| {
+    ///     let mut iter = tokens.into_iter();
+    ///     iter.next_if_eq(&"let")?;
+    ///     let name = iter.next_if(|t: &&str| t.chars().all(char::is_alphabetic))?;
+    ///     iter.next_if_eq(&"=")?;
+    ///     let value = iter.next_if(|t: &&str| t.parse::<u32>().is_ok())?;
+    ///     iter.next_if_eq(&";")?;
+    ///     Some((*name, *value))
+    /// };
+    /// assert_eq!(parse(&tokens), Some(("x", "1")));
+    /// assert_eq!(parse(&tokens), Some(("x", "1")));
+    ///
+    /// let mut iter = tokens.into_iter();
+    /// assert_eq!(iter.next_if_eq(&"var"), None);
+    /// assert_eq!(iter.advance_while(|t| *t != "="), 2);
+    /// assert_eq!(iter.next(), Some(&"="));
+    /// ```
+    pub fn next_if(
+        &mut self,
+        pred: impl FnOnce(&'a <I::Item as Deref>::Target) -> bool,
+    ) -> Option<&'a <I::Item as Deref>::Target> {
+        let mut probe = Reiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        };
+        let item = probe.next()?;
+        if pred(item) {
+            self.curr = probe.curr;
+            Some(item)
         } else {
+            None
+        }
+    }
+
+    /// Advance the iterator and return the next item if it is equal to `expected`.
+    pub fn next_if_eq<Q>(&mut self, expected: &Q) -> Option<&'a <I::Item as Deref>::Target>
+    where
+        Q: ?Sized,
+        <I::Item as Deref>::Target: PartialEq<Q>,
+    {
+        self.next_if(|item| item == expected)
+    }
+
+    /// Advance the iterator past every item for which `pred` returns true,
+    /// returning how many items were consumed.
+    pub fn advance_while(
+        &mut self,
+        mut pred: impl FnMut(&<I::Item as Deref>::Target) -> bool,
+    ) -> usize {
+        let mut consumed = 0;
+        while self.next_if(&mut pred).is_some() {
+            consumed += 1;
+        }
+        consumed
+    }
+
+    /// Advance the iterator by up to `n` items, returning how many it went past, which is
+    /// less than `n` if the stream ends first.
+    ///
+    /// Cached items are skipped over at once, and only the rest are pulled, like for
+    /// `nth()`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(Box::new));
+    /// reiterate.into_iter().nth(5);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.advance_by(8), 8);
+    /// assert_eq!(reiterate.cached_len(), 8);
+    /// assert_eq!(iter.next(), Some(&8));
+    /// assert_eq!(iter.advance_by(5), 1);
+    /// ```
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        let skip = n.min(self.iterable.visible_len().saturating_sub(self.curr));
+        self.curr += skip;
+        let mut advanced = skip;
+        while advanced < n && self.next().is_some() {
+            advanced += 1;
+        }
+        advanced
+    }
+
+    /// Move this iterator straight to the end of the cache, so that it only yields items
+    /// that haven't been pulled from the underlying iterator yet.
+    ///
+    /// Returns how many cached items were skipped. A truncated stream ends at its logical
+    /// length, so this doesn't go past it.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c", "d"]);
+    /// let mut first = reiterate.into_iter();
+    /// first.by_ref().take(2).for_each(drop);
+    ///
+    /// let mut second = reiterate.into_iter();
+    /// assert_eq!(second.next(), Some("a"));
+    /// assert_eq!(second.catch_up(), 1);
+    /// assert_eq!(second.next(), Some("c"));
+    /// assert_eq!(second.catch_up(), 0);
+    ///
+    /// // once the stream is exhausted, catching up goes to the end
+    /// assert_eq!(first.count(), 2);
+    /// assert_eq!(second.catch_up(), 1);
+    /// assert_eq!(second.next(), None);
+    ///
+    /// // never past the length set by `truncate()`
+    /// reiterate.truncate(2);
+    /// let mut third = reiterate.into_iter();
+    /// assert_eq!(third.catch_up(), 2);
+    /// assert_eq!(reiterate.iter_tail().chain(third).count(), 0);
+    /// ```
+    pub fn catch_up(&mut self) -> usize {
+        let len = self.iterable.visible_len();
+        let skipped = len.saturating_sub(self.curr);
+        self.curr = self.curr.max(len);
+        skipped
+    }
+
+    /// Pull the next item from the source, for a handle that has reached the end of the cache.
+    ///
+    /// This also yields the cached items of a truncated stream, or of one that counts its
+    /// hits. The cache length is the frontier, so a `None` from the source doesn't move
+    /// it, and later pulls can still succeed.
+    #[cold]
+    fn next_uncached(&mut self) -> Option<&'a <I::Item as Deref>::Target> {
+        let mut iterable = self.iterable;
+        if self.curr >= iterable.limit.get() {
+            return None;
+        }
+        if let Some(val) = iterable.cache.get(self.curr) {
             self.curr += 1;
-            return self.iterable.cache.get(self.curr - 1);
+            if let Some(stats) = &iterable.stats {
+                stats.hit();
+            }
+            return Some(val);
         }
+        if let Some(stats) = &iterable.stats {
+            stats.miss();
+        }
+        let frontier = iterable.cache.len();
+        if !iterable.fill_one() {
+            // Another handle may still have cached items meanwhile, as below
+            let val = iterable.cache.get(self.curr)?;
+            self.curr += 1;
+            return Some(val);
+        }
+        if let Some(stats) = &iterable.stats {
+            stats.pulled(iterable.cache.len());
+        }
+        // A queued source can have pulled through another handle while this one was
+        // waiting on it, so the item this handle pulled isn't necessarily the next one
+        let val = iterable.cache.get(self.curr);
+        self.curr += 1;
+        self.check_invariants(frontier);
+        val
+    }
+
+    /// Check that the cache only grew while pulling from `frontier`, and that this handle
+    /// is still within it
+    #[inline]
+    fn check_invariants(&self, frontier: usize) {
+        let len = self.iterable.cache.len();
+        debug_assert!(len > frontier, "the cache didn't grow while pulling");
+        debug_assert!(self.curr <= len, "a handle is past the end of the cache");
+    }
+}
+
+/// Lets `itertools` adaptors like `peeking_take_while` work directly on a handle.
+///
+/// A rejected item stays in the shared cache, so neither this handle nor any
+/// other will pull it from the source again.
+///
+/// ```rust
+/// use itertools::Itertools;
+/// use reiterate::Reiterate;
+///
+/// let pulls = std::cell::Cell::new(0);
+/// let x = vec!["a", "b", "c", "d"];
+/// let reiterate = Reiterate::new(x.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+///
+/// for _ in 0..2 {
+///     let mut iter = reiterate.into_iter();
+///     let first: Vec<_> = iter.peeking_take_while(|s| *s < "c").collect();
+///     assert_eq!(first, ["a", "b"]);
+///     assert_eq!(iter.next(), Some("c"));
+/// }
+/// assert_eq!(pulls.get(), 3);
+/// ```
+#[cfg(feature = "itertools")]
+impl<'a, I> itertools::PeekingNext for Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: StableDeref + Sized,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        self.next_if(|item| accept(&item))
     }
 }
 
@@ -120,7 +825,7 @@ where
 /// sharing an underlying cache.
 ///
 /// The underlying iterator must produce Copy values. If your values aren't Copy, please
-/// use `Reiterator` instead.
+/// use `Reiterator` instead, or `CloneReiterate` if they are cheap to clone.
 ///
 /// ```rust
 /// use reiterate::CopyReiterate;
@@ -136,52 +841,186 @@ where
 ///     println!("{}", i);
 /// }
 /// ```
+// Laid out like `Reiterate`, see `convert.rs`
+#[repr(C)]
 pub struct CopyReiterate<I>
 where
-    I: Iterator,
-    I::Item: Copy,
+    I: Iterator + ?Sized,
 {
+    poisoned: Cell<bool>,
+    sorted: sorted::SortedSlot,
+    // The logical length of the stream set by `truncate()`, or `usize::MAX`
+    limit: Cell<usize>,
+    // Bumped whenever the cache is rewritten, to tell stale checkpoints apart
+    generation: usize,
+    // Where the iterator returned by `primary()` is at
+    primary: Cell<usize>,
+    // The sources to pull from once `iter` has ended
+    queued: queue::SourceQueue<I::Item>,
+    lazy: Cell<lazy::LazyCaching>,
+    // Whether it was created by `lazy_caching()`, since `lazy` is `Off` once it caches
+    lazily: bool,
+    // How many items were streamed through without being cached, see `lazy_caching()`
+    missed: Cell<usize>,
     inner: RefCell<CopyReiterateInner<I>>,
 }
 
+#[repr(C)]
 struct CopyReiterateInner<I>
 where
-    I: Iterator,
-    I::Item: Copy,
+    I: Iterator + ?Sized,
 {
-    iter: I,
-    curr: usize,
     cache: Vec<I::Item>,
+    iter: I,
 }
 
 impl<I> CopyReiterate<I>
 where
-    I: Iterator,
+    I: Iterator + ?Sized,
     I::Item: Copy,
 {
     pub fn new<T>(iter: T) -> Self
     where
+        I: Sized,
         T: IntoIterator<Item = I::Item, IntoIter = I>,
     {
         CopyReiterate {
+            poisoned: Cell::new(false),
+            sorted: Default::default(),
+            limit: Cell::new(usize::MAX),
+            generation: 0,
+            primary: Cell::new(0),
+            queued: Default::default(),
+            lazy: Cell::new(lazy::LazyCaching::Off),
+            lazily: false,
+            missed: Cell::new(0),
             inner: RefCell::new(CopyReiterateInner {
-                iter: iter.into_iter(),
                 cache: Vec::new(),
-                curr: 0,
+                iter: iter.into_iter(),
             }),
         }
     }
+
+    /// Create a `CopyReiterate` whose cache has room for `capacity` items, like
+    /// `Reiterate::with_capacity()`.
+    pub fn with_capacity<T>(iter: T, capacity: usize) -> Self
+    where
+        I: Sized,
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let mut reiterate = CopyReiterate::new(iter);
+        reiterate.inner.get_mut().cache.reserve_exact(capacity);
+        reiterate
+    }
+
+    /// Whether the underlying iterator has panicked while an item was being pulled from it.
+    ///
+    /// A panic during a pull leaves the cached prefix intact and usable, since items are
+    /// only cached once the underlying iterator has returned them. Once poisoned, the
+    /// flag stays set: the underlying iterator may be in an unusable state, so callers
+    /// should decide whether to trust anything it produces afterwards, including the end
+    /// of the stream.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::panic::catch_unwind;
+    ///
+    /// let x = (0..5).map(|i| if i == 3 { panic!("broken source") } else { i });
+    /// let reiterate = CopyReiterate::new(x);
+    ///
+    /// let result = catch_unwind(|| reiterate.into_iter().count());
+    /// assert!(result.is_err());
+    /// assert!(reiterate.is_poisoned());
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.by_ref().take(3).collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.get()
+    }
+
+    /// Get an iterator that starts after the cached items, only yielding items
+    /// that haven't been pulled from the underlying iterator yet.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(1..=3);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    ///
+    /// let mut tail = reiterate.iter_tail();
+    /// assert_eq!(tail.next(), Some(2));
+    /// // the item pulled by the tail is cached for everyone else
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    pub fn iter_tail(&self) -> CopyReiterator<'_, I> {
+        self.stop_streaming();
+        CopyReiterator {
+            iterable: self,
+            curr: self.visible_len(),
+        }
+    }
+
+    /// Get an iterator that starts at index `start`, pulling up to it first if it isn't
+    /// cached yet, like `Reiterate::iter_from()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("reiterate".chars());
+    /// assert_eq!(reiterate.iter_from(2).collect::<String>(), "iterate");
+    /// assert_eq!(reiterate.iter_from(9).next(), None);
+    /// ```
+    pub fn iter_from(&self, start: usize) -> CopyReiterator<'_, I> {
+        let mut tail = self.iter_tail();
+        if let Some(ahead) = start.checked_sub(tail.curr) {
+            (&mut tail).take(ahead).for_each(drop);
+        }
+        CopyReiterator {
+            iterable: self,
+            curr: start.min(tail.curr),
+        }
+    }
+
+    /// The number of cached items within the logical length set by `truncate()`
+    fn visible_len(&self) -> usize {
+        self.inner.borrow().cache.len().min(self.limit.get())
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if needed.
+    /// Only pulls once, so `idx` should be at most the length of the cache.
+    fn get_or_pull(&self, idx: usize) -> Option<I::Item> {
+        if idx >= self.limit.get() {
+            return None;
+        }
+        let len = self.inner.borrow().cache.len();
+        if idx >= len && self.iter_tail().next().is_none() {
+            return None;
+        }
+        self.inner.borrow().cache.get(idx).copied()
+    }
+}
+
+/// Panics in the underlying iterator never leave the cache in an inconsistent state,
+/// and are reported through `is_poisoned()`.
+impl<I> RefUnwindSafe for CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + RefUnwindSafe,
+{
 }
 
 impl<'a, I> IntoIterator for &'a CopyReiterate<I>
 where
-    I: Iterator,
+    I: Iterator + ?Sized,
     I::Item: Copy,
 {
     type IntoIter = CopyReiterator<'a, I>;
     type Item = I::Item;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.start_handle();
         CopyReiterator {
             iterable: self,
             curr: 0,
@@ -190,37 +1029,430 @@ where
 }
 
 /// An individual iterator, produced by calling `.into_iter()` on an `&CopyReiterate` instance
+///
+/// Like a `Reiterator`, it isn't fused.
 pub struct CopyReiterator<'a, I>
 where
-    I: Iterator,
-    I::Item: Copy,
+    I: Iterator + ?Sized,
 {
     iterable: &'a CopyReiterate<I>,
     curr: usize,
 }
 
+/// Forks the iterator, like for `Reiterator`. On a `lazy_caching()` `CopyReiterate`, the
+/// clone is another handle, so caching starts.
+///
+/// ```rust
+/// use reiterate::CopyReiterate;
+///
+/// let reiterate = CopyReiterate::lazy_caching(1..=5);
+/// let mut iter = reiterate.into_iter();
+/// assert_eq!(iter.next(), Some(1));
+/// let fork = iter.clone();
+/// assert_eq!(iter.collect::<Vec<_>>(), [2, 3, 4, 5]);
+/// assert_eq!(fork.collect::<Vec<_>>(), [2, 3, 4, 5]);
+/// ```
+impl<'a, I> Clone for CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    fn clone(&self) -> Self {
+        self.iterable.stop_streaming();
+        CopyReiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        }
+    }
+}
+
 impl<'a, I> Iterator for CopyReiterator<'a, I>
 where
-    I: Iterator,
+    I: Iterator + ?Sized,
     I::Item: Copy + Sized,
 {
     type Item = I::Item;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
+        if self.iterable.lazy.get() == lazy::LazyCaching::Streaming {
+            return self.next_streamed();
+        }
         let mut iterable = self.iterable.inner.borrow_mut();
-        if self.curr == iterable.curr {
-            iterable.curr += 1;
-            let val = iterable.iter.next();
-            self.curr += 1;
-            if let Some(val) = val {
-                iterable.cache.push(val)
+        match iterable.cache.get(self.curr) {
+            Some(&val) if self.curr < self.iterable.limit.get() => {
+                self.curr += 1;
+                Some(val)
             }
-            return iterable.cache.get(self.curr - 1).cloned();
-        } else if self.curr > iterable.curr {
-            return None;
+            _ => self.next_uncached(&mut iterable),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The cache can't be looked at during a pull, like from a closure of the source
+        let iterable = match self.iterable.inner.try_borrow() {
+            Ok(iterable) => iterable,
+            Err(_) => return (0, None),
+        };
+        let cached = iterable.cache.len().saturating_sub(self.curr);
+        let source = self
+            .iterable
+            .queued
+            .chain_size_hint(iterable.iter.size_hint());
+        remaining_hint(cached, source, self.iterable.limit.get(), self.curr)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skip = n.min(self.cached_left());
+        self.curr += skip;
+        for _ in skip..n {
+            self.next()?;
+        }
+        self.next()
+    }
+
+    fn count(mut self) -> usize {
+        let cached = self.cached_left();
+        self.curr += cached;
+        self.fold(cached, |count, _| count + 1)
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.curr += self.cached_left().saturating_sub(1);
+        self.fold(None, |_, val| Some(val))
+    }
+}
+
+/// The size hint is exact when the underlying iterator's is, like for `Reiterator`.
+///
+/// ```rust
+/// use reiterate::CopyReiterate;
+///
+/// let reiterate = CopyReiterate::new(vec![1, 2, 3]);
+/// let mut iter = reiterate.into_iter();
+/// iter.next();
+/// reiterate.queue_source(vec![4, 5]);
+/// assert_eq!(iter.len(), 4);
+/// ```
+impl<'a, I> ExactSizeIterator for CopyReiterator<'a, I>
+where
+    I: ExactSizeIterator + ?Sized,
+    I::Item: Copy + Sized,
+{
+}
+
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Collect the remaining items into a `Vec`, copying the cached ones in bulk.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(0..10);
+    /// let mut first = reiterate.into_iter();
+    /// first.by_ref().take(5).for_each(drop);
+    ///
+    /// let mut second = reiterate.into_iter();
+    /// second.nth(2);
+    /// // starts mid-cache and continues through the source
+    /// assert_eq!(second.collect_vec(), (3..10).collect::<Vec<_>>());
+    /// assert_eq!(first.collect_vec(), (5..10).collect::<Vec<_>>());
+    /// ```
+    pub fn collect_vec(&mut self) -> Vec<I::Item> {
+        let mut out = Vec::with_capacity(self.size_hint().0);
+        self.extend_into(&mut out);
+        out
+    }
+
+    /// Append the remaining items to `out`, copying the cached ones in bulk.
+    ///
+    /// The first handle of a `lazy_caching()` `CopyReiterate` streams them into `out`
+    /// without caching them, like when it is iterated over.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(vec![1, 2, 3]);
+    /// reiterate.into_iter().nth(1);
+    ///
+    /// let mut out = vec![0];
+    /// reiterate.into_iter().extend_into(&mut out);
+    /// assert_eq!(out, [0, 1, 2, 3]);
+    /// ```
+    pub fn extend_into(&mut self, out: &mut Vec<I::Item>) {
+        if self.iterable.lazy.get() == lazy::LazyCaching::Streaming {
+            out.extend(iter::from_fn(|| self.next_streamed()));
+            return;
+        }
+        let mut iterable = self.iterable.inner.borrow_mut();
+        let iterable = &mut *iterable;
+        let limit = self.iterable.limit.get();
+        if let Some(cached) = iterable
+            .cache
+            .get(self.curr..iterable.cache.len().min(limit))
+        {
+            out.extend_from_slice(cached);
+        }
+        let start = iterable.cache.len();
+        let missing = limit.saturating_sub(start);
+        let CopyReiterateInner { cache, iter } = iterable;
+        let queued = &self.iterable.queued;
+        poison_on_unwind(&self.iterable.poisoned, || {
+            let pulled = iter::from_fn(|| queued.or_pull(iter.next()));
+            cache.extend(pulled.take(missing))
+        });
+        out.extend_from_slice(&iterable.cache[start..]);
+        self.curr = iterable.cache.len();
+    }
+
+    /// Look at the next item without advancing the iterator.
+    ///
+    /// This is the `Copy` counterpart of `Reiterator::peek()`. On a `lazy_caching()`
+    /// `CopyReiterate`, this starts caching, since the item has to stay available.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("12+3".chars());
+    /// let mut iter = reiterate.into_iter();
+    /// let mut number = 0;
+    /// while let Some(digit) = iter.peek().and_then(|c| c.to_digit(10)) {
+    ///     number = number * 10 + digit;
+    ///     iter.next();
+    /// }
+    /// assert_eq!(number, 12);
+    /// assert_eq!(iter.next(), Some('+'));
+    /// ```
+    pub fn peek(&self) -> Option<I::Item> {
+        self.iterable.stop_streaming();
+        CopyReiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        }
+        .next()
+    }
+
+    /// Advance the iterator and return the next item if `pred` returns true for it.
+    ///
+    /// A rejected item isn't consumed: it stays available to this iterator and, like any
+    /// other pulled item, to all the others.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// // `<digits> + <digits>`, returning the length of each number
+    /// fn parse<I: Iterator<Item = char>>(tokens: &CopyReiterate<I>) -> Option<(usize, usize)> {
+    ///     let mut iter = tokens.into_iter();
+    ///     let lhs = iter.advance_while(|c| c.is_ascii_digit());
+    ///     iter.next_if_eq(&'+')?;
+    ///     let rhs = iter.advance_while(|c| c.is_ascii_digit());
+    ///     Some((lhs, rhs))
+    /// }
+    ///
+    /// let tokens = CopyReiterate::new("1+23".chars());
+    /// assert_eq!(parse(&tokens), Some((1, 2)));
+    /// assert_eq!(parse(&tokens), Some((1, 2)));
+    ///
+    /// let mut iter = tokens.into_iter();
+    /// assert_eq!(iter.next_if(|c| c.is_alphabetic()), None);
+    /// assert_eq!(iter.next(), Some('1'));
+    /// ```
+    pub fn next_if(&mut self, pred: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        // A rejected item has to stay available
+        self.iterable.stop_streaming();
+        let mut probe = CopyReiterator {
+            iterable: self.iterable,
+            curr: self.curr,
+        };
+        let item = probe.next()?;
+        if pred(&item) {
+            self.curr = probe.curr;
+            Some(item)
         } else {
-            self.curr += 1;
-            return iterable.cache.get(self.curr - 1).cloned();
+            None
         }
     }
+
+    /// Advance the iterator and return the next item if it is equal to `expected`.
+    pub fn next_if_eq<Q>(&mut self, expected: &Q) -> Option<I::Item>
+    where
+        Q: ?Sized,
+        I::Item: PartialEq<Q>,
+    {
+        self.next_if(|item| item == expected)
+    }
+
+    /// Advance the iterator past every item for which `pred` returns true,
+    /// returning how many items were consumed.
+    pub fn advance_while(&mut self, mut pred: impl FnMut(&I::Item) -> bool) -> usize {
+        let mut consumed = 0;
+        while self.next_if(&mut pred).is_some() {
+            consumed += 1;
+        }
+        consumed
+    }
+
+    /// Advance the iterator by up to `n` items, returning how many it went past, like
+    /// `Reiterator::advance_by()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("abcdef".chars());
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.advance_by(2), 2);
+    /// assert_eq!(iter.next(), Some('c'));
+    /// assert_eq!(reiterate.into_iter().advance_by(10), 6);
+    /// ```
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        let skip = n.min(self.cached_left());
+        self.curr += skip;
+        let mut advanced = skip;
+        while advanced < n && self.next().is_some() {
+            advanced += 1;
+        }
+        advanced
+    }
+
+    /// Move this iterator straight to the end of the cache, so that it only yields items
+    /// that haven't been pulled from the underlying iterator yet.
+    ///
+    /// Returns how many cached items were skipped.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(1..=4);
+    /// let mut first = reiterate.into_iter();
+    /// first.by_ref().take(2).for_each(drop);
+    ///
+    /// let mut second = reiterate.into_iter();
+    /// assert_eq!(second.next(), Some(1));
+    /// assert_eq!(second.catch_up(), 1);
+    /// assert_eq!(second.next(), Some(3));
+    ///
+    /// assert_eq!(first.count(), 2);
+    /// assert_eq!(second.catch_up(), 1);
+    /// assert_eq!(second.next(), None);
+    ///
+    /// reiterate.truncate(2);
+    /// let mut third = reiterate.into_iter();
+    /// assert_eq!(third.catch_up(), 2);
+    /// assert_eq!(reiterate.iter_tail().chain(third).count(), 0);
+    /// ```
+    pub fn catch_up(&mut self) -> usize {
+        let len = self.iterable.visible_len();
+        let skipped = len.saturating_sub(self.curr);
+        self.curr = self.curr.max(len);
+        skipped
+    }
+
+    /// The number of cached items this handle can skip over without copying them out,
+    /// which is none for a handle that is streaming them
+    fn cached_left(&self) -> usize {
+        if self.iterable.lazy.get() == lazy::LazyCaching::Streaming {
+            return 0;
+        }
+        self.iterable.visible_len().saturating_sub(self.curr)
+    }
+
+    /// Pull the next item from the source, for a handle that has reached the end of the cache
+    #[cold]
+    fn next_uncached(&mut self, iterable: &mut CopyReiterateInner<I>) -> Option<I::Item> {
+        if self.curr >= self.iterable.limit.get() {
+            return None;
+        }
+        let val = poison_on_unwind(&self.iterable.poisoned, || {
+            self.iterable.queued.or_pull(iterable.iter.next())
+        })?;
+        iterable.cache.push(val);
+        self.curr += 1;
+        // The cache is borrowed while pulling, so no other handle can pull meanwhile
+        debug_assert_eq!(
+            self.curr,
+            iterable.cache.len(),
+            "a handle pulled from mid-cache"
+        );
+        Some(val)
+    }
+}
+
+/// Lets `itertools` adaptors like `peeking_take_while` work directly on a handle.
+///
+/// A rejected item stays in the shared cache, so neither this handle nor any
+/// other will pull it from the source again.
+///
+/// ```rust
+/// use itertools::Itertools;
+/// use reiterate::CopyReiterate;
+///
+/// let pulls = std::cell::Cell::new(0);
+/// let x = vec![1, 2, 3, 4];
+/// let reiterate = CopyReiterate::new(x.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+///
+/// for _ in 0..2 {
+///     let mut iter = reiterate.into_iter();
+///     let first: Vec<_> = iter.peeking_take_while(|&i| i < 3).collect();
+///     assert_eq!(first, [1, 2]);
+///     assert_eq!(iter.next(), Some(3));
+/// }
+/// assert_eq!(pulls.get(), 3);
+/// ```
+#[cfg(feature = "itertools")]
+impl<'a, I> itertools::PeekingNext for CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Sized,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        self.next_if(accept)
+    }
+}
+
+/// The size hint of a handle `curr` items in, with `cached` cached items left and
+/// `source` the size hint of what is left to pull
+fn remaining_hint(
+    cached: usize,
+    source: (usize, Option<usize>),
+    limit: usize,
+    curr: usize,
+) -> (usize, Option<usize>) {
+    let lower = cached.saturating_add(source.0);
+    let upper = source.1.and_then(|upper| upper.checked_add(cached));
+    match limit {
+        usize::MAX => (lower, upper),
+        limit => {
+            let remaining = limit.saturating_sub(curr);
+            (
+                lower.min(remaining),
+                Some(upper.map_or(remaining, |upper| upper.min(remaining))),
+            )
+        }
+    }
+}
+
+/// Run a pull from an underlying iterator, leaving `poisoned` set if it panics.
+///
+/// The flag is only set while unwinding, so that `is_poisoned()` stays accurate when
+/// called during the pull.
+#[inline]
+fn poison_on_unwind<T>(poisoned: &Cell<bool>, pull: impl FnOnce() -> T) -> T {
+    struct Guard<'a>(&'a Cell<bool>);
+
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let guard = Guard(poisoned);
+    let ret = pull();
+    mem::forget(guard);
+    ret
 }
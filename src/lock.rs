@@ -0,0 +1,50 @@
+//! The lock used by the thread-safe variants, backed by `parking_lot` when that feature
+//! is enabled and by `std` otherwise
+
+#[cfg(not(feature = "parking_lot"))]
+use std::sync::PoisonError;
+
+#[cfg(feature = "parking_lot")]
+pub(crate) type MutexGuard<'a, T> = parking_lot::MutexGuard<'a, T>;
+#[cfg(not(feature = "parking_lot"))]
+pub(crate) type MutexGuard<'a, T> = std::sync::MutexGuard<'a, T>;
+
+/// A mutex that does not poison: a panic while it is held leaves it usable.
+///
+/// Everything guarded by it is left consistent when the underlying iterator panics, so
+/// there is nothing to recover from a poisoned `std` lock.
+pub(crate) struct Mutex<T: ?Sized> {
+    #[cfg(feature = "parking_lot")]
+    inner: parking_lot::Mutex<T>,
+    #[cfg(not(feature = "parking_lot"))]
+    inner: std::sync::Mutex<T>,
+}
+
+impl<T> Mutex<T> {
+    pub(crate) fn new(val: T) -> Self {
+        Mutex {
+            #[cfg(feature = "parking_lot")]
+            inner: parking_lot::Mutex::new(val),
+            #[cfg(not(feature = "parking_lot"))]
+            inner: std::sync::Mutex::new(val),
+        }
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Mutex::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    #[cfg(feature = "parking_lot")]
+    pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+        self.inner.lock()
+    }
+
+    #[cfg(not(feature = "parking_lot"))]
+    pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
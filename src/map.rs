@@ -0,0 +1,202 @@
+use crate::Reiterable;
+
+use elsa::FrozenVec;
+
+use stable_deref_trait::StableDeref;
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+/// An adaptor around an iterator of key-value pairs that supports lazy lookup by key,
+/// as well as replaying the pairs in stream order through multiple iterators.
+///
+/// Looking up a key that hasn't been seen yet reads more of the underlying iterator until
+/// the key shows up or the iterator ends. Everything read is kept for future lookups and
+/// replays.
+///
+/// If a key occurs multiple times in the stream, `get()` returns the value of its first
+/// occurrence. Iterating still replays every pair, duplicates included.
+///
+/// ```rust
+/// use reiterate::ReiterateMap;
+///
+/// let pulls = std::cell::Cell::new(0);
+/// let x = vec![("a", "one".to_string()), ("b", "two".to_string()), ("a", "three".to_string())];
+/// let map = ReiterateMap::new(x.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+///
+/// // pulls the first two pairs
+/// assert_eq!(map.get(&"b"), Some("two"));
+/// assert_eq!(pulls.get(), 2);
+/// // already cached
+/// assert_eq!(map.get(&"a"), Some("one"));
+/// assert_eq!(pulls.get(), 2);
+/// // reads the rest of the stream without finding anything
+/// assert_eq!(map.get(&"c"), None);
+/// assert_eq!(pulls.get(), 3);
+/// // the first occurrence of a duplicate key wins
+/// assert_eq!(map.get(&"a"), Some("one"));
+///
+/// let pairs: Vec<_> = map.iter().map(|(k, v)| (*k, v)).collect();
+/// assert_eq!(pairs, [("a", "one"), ("b", "two"), ("a", "three")]);
+/// assert_eq!(pulls.get(), 3);
+/// ```
+pub struct ReiterateMap<K, V, I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: StableDeref,
+{
+    iter: RefCell<I>,
+    entries: FrozenVec<Box<(K, V)>>,
+    // Maps key hashes to the indices of the first occurrence of each key with that hash
+    index: RefCell<HashMap<u64, Vec<usize>>>,
+    hasher: RandomState,
+}
+
+impl<K, V, I> ReiterateMap<K, V, I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: StableDeref,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V), IntoIter = I>,
+    {
+        ReiterateMap {
+            iter: RefCell::new(iter.into_iter()),
+            entries: FrozenVec::new(),
+            index: RefCell::new(HashMap::new()),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Get the value for a key, reading more of the underlying iterator if the key
+    /// hasn't been seen yet.
+    pub fn get<Q>(&self, k: &Q) -> Option<&V::Target>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(idx) = self.find(k) {
+            return Some(&self.entry(idx).1);
+        }
+        loop {
+            let idx = self.pull()?;
+            let entry = self.entry(idx);
+            // The key wasn't found before, so this is its first occurrence
+            if entry.0.borrow() == k {
+                return Some(&entry.1);
+            }
+        }
+    }
+
+    /// Get an iterator over the pairs in stream order, reusing cached pairs
+    pub fn iter(&self) -> ReiterateMapIter<'_, K, V, I> {
+        ReiterateMapIter {
+            iterable: self,
+            curr: 0,
+        }
+    }
+
+    fn entry(&self, idx: usize) -> &(K, V) {
+        &self.entries[idx]
+    }
+
+    fn hash<Q: Hash + ?Sized>(&self, k: &Q) -> u64 {
+        self.hasher.hash_one(k)
+    }
+
+    fn find<Q>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.index.borrow();
+        index
+            .get(&self.hash(k))?
+            .iter()
+            .cloned()
+            .find(|&idx| self.entry(idx).0.borrow() == k)
+    }
+
+    /// Pull a single pair from the underlying iterator, returning its index
+    fn pull(&self) -> Option<usize> {
+        let (k, v) = self.iter.borrow_mut().next()?;
+        let hash = self.hash(&k);
+        let idx = self.entries.len();
+        self.entries.push(Box::new((k, v)));
+        let mut index = self.index.borrow_mut();
+        let bucket = index.entry(hash).or_default();
+        let key = &self.entry(idx).0;
+        if !bucket.iter().any(|&i| self.entry(i).0 == *key) {
+            bucket.push(idx);
+        }
+        Some(idx)
+    }
+}
+
+impl<'a, K, V, I> IntoIterator for &'a ReiterateMap<K, V, I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: StableDeref,
+{
+    type IntoIter = ReiterateMapIter<'a, K, V, I>;
+    type Item = (&'a K, &'a V::Target);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V, I> Reiterable for ReiterateMap<K, V, I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: StableDeref,
+{
+    type Item<'a>
+        = (&'a K, &'a V::Target)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = ReiterateMapIter<'a, K, V, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> ReiterateMapIter<'_, K, V, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator over the pairs of a `ReiterateMap`, produced by calling `.iter()`
+pub struct ReiterateMapIter<'a, K, V, I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: StableDeref,
+{
+    iterable: &'a ReiterateMap<K, V, I>,
+    curr: usize,
+}
+
+impl<'a, K, V, I> Iterator for ReiterateMapIter<'a, K, V, I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: StableDeref,
+{
+    type Item = (&'a K, &'a V::Target);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.curr >= self.iterable.entries.len() {
+            self.iterable.pull()?;
+        }
+        let entry = self.iterable.entry(self.curr);
+        self.curr += 1;
+        Some((&entry.0, &*entry.1))
+    }
+}
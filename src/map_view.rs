@@ -0,0 +1,203 @@
+use crate::{CopyReiterate, CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a view of `f` applied to each item, reading from this cache.
+    ///
+    /// Nothing more is cached: `f` is called whenever a value is produced, so several
+    /// views of differently shaped values share the items pulled once. `f` may borrow from
+    /// the item it is given, and `nth()` skips over items without calling it.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let records = Reiterate::new(vec!["ada,1815".to_string(), "grace,1906".to_string()]);
+    /// let names = records.map_view(|r| r.split(',').next().unwrap());
+    /// let years = records.map_view(|r| r[r.find(',').unwrap() + 1..].parse::<u32>().unwrap());
+    /// assert_eq!(names.iter().collect::<Vec<_>>(), ["ada", "grace"]);
+    /// assert_eq!(years.iter().max(), Some(1906));
+    /// assert_eq!(years.iter().nth(1), Some(1906));
+    /// // both views read the two items pulled by the first
+    /// assert_eq!(records.cached_len(), 2);
+    /// ```
+    pub fn map_view<'a, F, U>(&'a self, f: F) -> MapView<'a, I, F>
+    where
+        F: Fn(&'a <I::Item as Deref>::Target) -> U,
+    {
+        MapView { iterable: self, f }
+    }
+}
+
+/// A view of a function of each item of a `Reiterate`, produced by `map_view()`
+pub struct MapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    f: F,
+}
+
+impl<'a, I, F, U> MapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&'a <I::Item as Deref>::Target) -> U,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> MapViewReiterator<'_, 'a, I, F> {
+        MapViewReiterator {
+            view: self,
+            iter: self.iterable.into_iter(),
+        }
+    }
+}
+
+impl<'v, 'a, I, F, U> IntoIterator for &'v MapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&'a <I::Item as Deref>::Target) -> U,
+{
+    type IntoIter = MapViewReiterator<'v, 'a, I, F>;
+    type Item = U;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `MapView`, produced by calling `.iter()`
+pub struct MapViewReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v MapView<'a, I, F>,
+    iter: Reiterator<'a, I>,
+}
+
+impl<'v, 'a, I, F, U> Iterator for MapViewReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    F: Fn(&'a <I::Item as Deref>::Target) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        self.iter.next().map(&self.view.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<U> {
+        self.iter.nth(n).map(&self.view.f)
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get a view of `f` applied to each item, reading from this cache, like
+    /// `Reiterate::map_view()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let points = CopyReiterate::new(vec![(3.0f64, 4.0f64), (6.0, 8.0)]);
+    /// let norms = points.map_view(|(x, y)| x.hypot(y));
+    /// let sums = points.map_view(|(x, y)| x + y);
+    /// assert_eq!(norms.iter().collect::<Vec<_>>(), [5.0, 10.0]);
+    /// assert_eq!(sums.iter().collect::<Vec<_>>(), [7.0, 14.0]);
+    /// ```
+    pub fn map_view<F, U>(&self, f: F) -> CopyMapView<'_, I, F>
+    where
+        F: Fn(I::Item) -> U,
+    {
+        CopyMapView { iterable: self, f }
+    }
+}
+
+/// A view of a function of each item of a `CopyReiterate`, produced by `map_view()`
+pub struct CopyMapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a CopyReiterate<I>,
+    f: F,
+}
+
+impl<'a, I, F, U> CopyMapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(I::Item) -> U,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> CopyMapViewReiterator<'_, 'a, I, F> {
+        CopyMapViewReiterator {
+            view: self,
+            iter: self.iterable.into_iter(),
+        }
+    }
+}
+
+impl<'v, 'a, I, F, U> IntoIterator for &'v CopyMapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(I::Item) -> U,
+{
+    type IntoIter = CopyMapViewReiterator<'v, 'a, I, F>;
+    type Item = U;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `CopyMapView`, produced by calling `.iter()`
+pub struct CopyMapViewReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v CopyMapView<'a, I, F>,
+    iter: CopyReiterator<'a, I>,
+}
+
+impl<'v, 'a, I, F, U> Iterator for CopyMapViewReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(I::Item) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        self.iter.next().map(&self.view.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<U> {
+        self.iter.nth(n).map(&self.view.f)
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{ReiterableItem, Reiterate, Reiterator};
+
+/// An iterator over the `Some` items of a source with gaps, remembering where they were,
+/// used as the source of `Reiterate::from_options`.
+pub struct OptionSource<I> {
+    iter: I,
+    // The number of positions read from `iter`, gaps included
+    seen: usize,
+    // The position of each `Some` item yielded so far
+    indices: Vec<usize>,
+}
+
+impl<I, T> Iterator for OptionSource<I>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let item = self.iter.next()?;
+            self.seen += 1;
+            if let Some(val) = item {
+                self.indices.push(self.seen - 1);
+                return Some(val);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T> Reiterate<OptionSource<I>>
+where
+    I: Iterator<Item = Option<T>>,
+    T: ReiterableItem,
+{
+    /// Create a `Reiterate` over a source with gaps, where `None` marks a missing item.
+    ///
+    /// Only the `Some` items are cached, along with their positions, so gaps take no
+    /// space. Handles obtained with `into_iter()` go over the `Some` items only; those
+    /// obtained with `options()` replay the gaps too, in their original positions, and
+    /// those obtained with `present_view()` pair the `Some` items with their positions.
+    ///
+    /// Sources queued with `queue_source()` aren't part of the positions, so the latter
+    /// two handles end where the source passed to this does.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let records = vec![None, None, Some(Box::new('a')), None, Some(Box::new('b')), Some(Box::new('c')), None];
+    /// let reiterate = Reiterate::from_options(records);
+    ///
+    /// let present: Vec<_> = reiterate.present_view().collect();
+    /// assert_eq!(present, [(2, &'a'), (4, &'b'), (5, &'c')]);
+    /// for _ in 0..2 {
+    ///     let all: Vec<_> = reiterate.options().collect();
+    ///     assert_eq!(all, [None, None, Some(&'a'), None, Some(&'b'), Some(&'c'), None]);
+    /// }
+    /// assert_eq!(reiterate.into_iter().collect::<String>(), "abc");
+    /// ```
+    ///
+    /// The gaps are replayed while the items are pulled lazily:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::from_options(vec![None, Some(Box::new(1)), None, None]);
+    /// let mut gaps = reiterate.options();
+    /// assert_eq!(gaps.next(), Some(None));
+    /// assert_eq!(gaps.next(), Some(Some(&1)));
+    /// let mut present = reiterate.present_view();
+    /// assert_eq!(present.next(), Some((1, &1)));
+    /// assert_eq!(present.next(), None);
+    /// assert_eq!(gaps.collect::<Vec<_>>(), [None, None]);
+    ///
+    /// let empty = Reiterate::from_options(vec![None::<Box<u8>>; 3]);
+    /// assert_eq!(empty.options().count(), 3);
+    /// assert_eq!(empty.present_view().count(), 0);
+    /// ```
+    pub fn from_options<S>(iter: S) -> Self
+    where
+        S: IntoIterator<Item = Option<T>, IntoIter = I>,
+    {
+        Reiterate::new(OptionSource {
+            iter: iter.into_iter(),
+            seen: 0,
+            indices: Vec::new(),
+        })
+    }
+
+    /// Get an iterator over every position of the source, yielding `None` for the gaps
+    pub fn options(&self) -> OptionsReiterator<'_, I, T> {
+        OptionsReiterator {
+            iter: self.into_iter(),
+            pos: 0,
+        }
+    }
+
+    /// Get an iterator over the `Some` items, along with their positions in the source
+    pub fn present_view(&self) -> PresentReiterator<'_, I, T> {
+        PresentReiterator {
+            iter: self.into_iter(),
+        }
+    }
+
+    /// The position in the source of the `Some` item at `idx` among the `Some` items, if
+    /// it has been pulled
+    fn source_index(&self, idx: usize) -> Option<usize> {
+        self.iter.borrow().indices.get(idx).copied()
+    }
+}
+
+/// An iterator over every position of a source with gaps, produced by `options()`
+pub struct OptionsReiterator<'a, I, T>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    iter: Reiterator<'a, OptionSource<I>>,
+    pos: usize,
+}
+
+impl<'a, I, T> Iterator for OptionsReiterator<'a, I, T>
+where
+    I: Iterator<Item = Option<T>>,
+    T: ReiterableItem + 'a,
+{
+    type Item = Option<&'a T::Target>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterable = self.iter.iterable;
+        loop {
+            let (next_index, pulled, seen) = {
+                let source = iterable.iter.borrow();
+                let next_index = source.indices.get(self.iter.curr).copied();
+                (next_index, source.indices.len(), source.seen)
+            };
+            match next_index {
+                Some(idx) if idx == self.pos => {
+                    self.pos += 1;
+                    return self.iter.next().map(Some);
+                }
+                // The next `Some` item is further on, or there are gaps after the last one
+                Some(_) => break,
+                None if self.pos < seen => break,
+                None => {
+                    let item = iterable.iter_tail().next();
+                    // An item from a queued source has no position
+                    if item.is_none() || iterable.iter.borrow().indices.len() == pulled {
+                        if self.pos < iterable.iter.borrow().seen {
+                            break;
+                        }
+                        return None;
+                    }
+                }
+            }
+        }
+        self.pos += 1;
+        Some(None)
+    }
+}
+
+/// An iterator over the `Some` items of a source with gaps and their positions, produced by
+/// `present_view()`
+pub struct PresentReiterator<'a, I, T>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    iter: Reiterator<'a, OptionSource<I>>,
+}
+
+impl<'a, I, T> Iterator for PresentReiterator<'a, I, T>
+where
+    I: Iterator<Item = Option<T>>,
+    T: ReiterableItem + 'a,
+{
+    type Item = (usize, &'a T::Target);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.iter.curr;
+        let item = self.iter.next()?;
+        Some((self.iter.iterable.source_index(idx)?, item))
+    }
+}
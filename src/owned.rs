@@ -0,0 +1,243 @@
+use crate::{CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::borrow::ToOwned;
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: ToOwned,
+{
+    /// Get an iterator over owned copies of the items, made with `ToOwned`, e.g. `String`s
+    /// for a stream of `Box<str>`.
+    ///
+    /// This is a regular handle underneath: it replays the cache and pulls lazily.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec![Box::<str>::from("a"), "b".into(), "c".into()]);
+    /// let owned: Vec<String> = reiterate.iter_cloned().collect();
+    /// assert_eq!(owned, ["a", "b", "c"]);
+    ///
+    /// let slices = Reiterate::new(vec![vec![1, 2].into_boxed_slice(), vec![3].into_boxed_slice()]);
+    /// assert_eq!(slices.iter_cloned().nth(1), Some(vec![3]));
+    /// ```
+    pub fn iter_cloned(&self) -> ClonedReiterator<'_, I> {
+        ClonedReiterator {
+            iter: self.into_iter(),
+        }
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Clone,
+{
+    /// Get an iterator over clones of the items.
+    ///
+    /// This is a regular handle underneath: it replays the cache and pulls lazily.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((1..=3).map(|i| Box::new(vec![i; i])));
+    /// let owned: Vec<Vec<usize>> = reiterate.iter_owned().collect();
+    /// assert_eq!(owned, [vec![1], vec![2, 2], vec![3, 3, 3]]);
+    /// assert_eq!(reiterate.iter_owned().fold(0, |len, v| len + v.len()), 6);
+    /// ```
+    pub fn iter_owned(&self) -> OwnedReiterator<'_, I> {
+        OwnedReiterator {
+            iter: self.into_iter(),
+        }
+    }
+}
+
+impl<'a, I> Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: ToOwned,
+{
+    /// Turn this handle into an iterator over owned copies of the items, made with
+    /// `ToOwned`, for APIs that take items by value.
+    ///
+    /// Unlike `.map(ToOwned::to_owned)`, this is a named type, and `nth()` and `count()`
+    /// still skip over cached items without copying them.
+    ///
+    /// ```rust
+    /// use reiterate::{ClonedReiterator, Reiterate};
+    ///
+    /// fn names<'a>(reiterate: &'a Reiterate<std::vec::IntoIter<Box<str>>>) -> ClonedReiterator<'a, std::vec::IntoIter<Box<str>>> {
+    ///     let mut iter = reiterate.into_iter();
+    ///     iter.next();
+    ///     iter.to_owned_iter()
+    /// }
+    ///
+    /// let reiterate = Reiterate::new(vec![Box::<str>::from("header"), "a".into(), "b".into(), "c".into()]);
+    /// let raw = reiterate.into_iter();
+    /// assert_eq!(raw.to_owned_iter().size_hint(), reiterate.into_iter().size_hint());
+    ///
+    /// let mut owned = names(&reiterate);
+    /// assert_eq!(owned.size_hint(), (3, Some(3)));
+    /// assert_eq!(owned.nth(1), Some(String::from("b")));
+    /// assert_eq!(owned.count(), 1);
+    /// assert_eq!(names(&reiterate).collect::<Vec<String>>(), ["a", "b", "c"]);
+    /// ```
+    pub fn to_owned_iter(self) -> ClonedReiterator<'a, I> {
+        ClonedReiterator { iter: self }
+    }
+}
+
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Wrap this handle in a named iterator over the items, for APIs that take items by
+    /// value.
+    ///
+    /// A handle already yields the items by value, so this only forwards to it, including
+    /// `nth()` and `count()`, which skip over cached items. It exists so that the
+    /// `Copy` and `ToOwned` flavors can be returned the same way.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(0..10);
+    /// assert_eq!(reiterate.into_iter().count(), 10);
+    /// reiterate.queue_source(10..15);
+    ///
+    /// let mut raw = reiterate.into_iter();
+    /// let mut compat = reiterate.into_iter().copied_compat();
+    /// assert_eq!(compat.size_hint(), raw.size_hint());
+    /// assert_eq!(compat.nth(12), raw.nth(12));
+    /// assert_eq!(compat.size_hint(), raw.size_hint());
+    /// assert_eq!(compat.count(), raw.count());
+    /// ```
+    pub fn copied_compat(self) -> CopiedReiterator<'a, I> {
+        CopiedReiterator { iter: self }
+    }
+}
+
+/// An iterator over owned copies of the items of a `Reiterate`, produced by `.iter_cloned()`
+pub struct ClonedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: Reiterator<'a, I>,
+}
+
+impl<'a, I> Iterator for ClonedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: ToOwned,
+{
+    type Item = <<I::Item as Deref>::Target as ToOwned>::Owned;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ToOwned::to_owned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(ToOwned::to_owned)
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, |acc, item| f(acc, item.to_owned()))
+    }
+}
+
+/// An iterator over clones of the items of a `Reiterate`, produced by `.iter_owned()`
+pub struct OwnedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: Reiterator<'a, I>,
+}
+
+impl<'a, I> Iterator for OwnedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Clone,
+{
+    type Item = <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).cloned()
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, |acc, item| f(acc, item.clone()))
+    }
+}
+
+/// A named iterator over the items of a `CopyReiterate`, produced by `.copied_compat()`
+pub struct CopiedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: CopyReiterator<'a, I>,
+}
+
+impl<'a, I> Iterator for CopiedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        self.iter.nth(n)
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, I::Item) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{CopyReiterate, CopyReiterator};
+
+use std::ops::Sub;
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get a view of `f(previous, current)` for each pair of consecutive items.
+    ///
+    /// Both items are read from the cache whenever a value is produced, so nothing is
+    /// kept between calls: skipping ahead with `nth()` only pulls what it has to, and
+    /// every replay gives the same values. A stream with fewer than two items gives no
+    /// values.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let readings = CopyReiterate::new(vec![(0, 1.5), (10, 2.0), (30, 3.0)]);
+    /// let rates = readings.pairwise_map_view(|(t0, v0), (t1, v1)| (v1 - v0) / (t1 - t0) as f64);
+    /// assert_eq!(rates.iter().collect::<Vec<_>>(), [0.05, 0.05]);
+    /// assert_eq!(rates.iter().nth(1), Some(0.05));
+    ///
+    /// let single = CopyReiterate::new(vec![1]);
+    /// assert_eq!(single.pairwise_map_view(|a, b| a + b).iter().next(), None);
+    /// ```
+    pub fn pairwise_map_view<F, T>(&self, f: F) -> PairwiseMapView<'_, I, F>
+    where
+        F: Fn(I::Item, I::Item) -> T,
+    {
+        self.stop_streaming();
+        PairwiseMapView { iterable: self, f }
+    }
+
+    /// Get a view of the differences between consecutive items, `current - previous`,
+    /// for counters and other increasing series.
+    ///
+    /// If `keep_first`, the first item comes first, as it is, so that the values add up
+    /// to the items; otherwise there is one value fewer than there are items. The values
+    /// are computed from the cache like for `pairwise_map_view()`, so seeking with
+    /// `nth()` and replaying give the right differences.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let counters = CopyReiterate::new(vec![100u64, 105, 105, 112, 130]);
+    /// let deltas = counters.delta_view(false);
+    /// let mut iter = deltas.iter();
+    /// assert_eq!(iter.next(), Some(5));
+    /// // seeking skips over the differences in between
+    /// assert_eq!(iter.nth(1), Some(7));
+    /// assert_eq!(iter.next(), Some(18));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(deltas.iter().collect::<Vec<_>>(), [5, 0, 7, 18]);
+    ///
+    /// let totals = counters.delta_view(true);
+    /// assert_eq!(totals.iter().collect::<Vec<_>>(), [100, 5, 0, 7, 18]);
+    /// assert_eq!(totals.iter().nth(3), Some(7));
+    /// assert_eq!(totals.iter().sum::<u64>(), 130);
+    ///
+    /// let single = CopyReiterate::new(vec![42u64]);
+    /// assert_eq!(single.delta_view(true).iter().collect::<Vec<_>>(), [42]);
+    /// assert_eq!(single.delta_view(false).iter().next(), None);
+    /// ```
+    pub fn delta_view(&self, keep_first: bool) -> DeltaView<'_, I>
+    where
+        I::Item: Sub<Output = I::Item>,
+    {
+        DeltaView {
+            pairs: self.pairwise_map_view(|prev, curr| curr - prev),
+            keep_first,
+        }
+    }
+}
+
+// The function of each pair of items behind a `DeltaView`
+type Delta<T> = fn(T, T) -> T;
+
+/// A view of a function of each pair of consecutive items of a `CopyReiterate`, produced
+/// by `pairwise_map_view()`
+pub struct PairwiseMapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a CopyReiterate<I>,
+    f: F,
+}
+
+impl<'a, I, F, T> PairwiseMapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(I::Item, I::Item) -> T,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> PairwiseMapReiterator<'_, 'a, I, F> {
+        PairwiseMapReiterator {
+            view: self,
+            ahead: self.iterable.into_iter(),
+        }
+    }
+}
+
+impl<'v, 'a, I, F, T> IntoIterator for &'v PairwiseMapView<'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(I::Item, I::Item) -> T,
+{
+    type IntoIter = PairwiseMapReiterator<'v, 'a, I, F>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `PairwiseMapView`
+pub struct PairwiseMapReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v PairwiseMapView<'a, I, F>,
+    // Positioned after the second item of the last pair
+    ahead: CopyReiterator<'a, I>,
+}
+
+impl<'v, 'a, I, F, T> Iterator for PairwiseMapReiterator<'v, 'a, I, F>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+    F: Fn(I::Item, I::Item) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.ahead.size_hint();
+        // The first item is only the first half of a pair
+        let first = (self.ahead.curr == 0) as usize;
+        (
+            lower.saturating_sub(first),
+            upper.map(|upper| upper.saturating_sub(first)),
+        )
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        if self.ahead.curr == 0 {
+            self.ahead.next()?;
+        }
+        let curr = self.ahead.nth(n)?;
+        let prev = self.ahead.iterable.inner.borrow().cache[self.ahead.curr - 2];
+        Some((self.view.f)(prev, curr))
+    }
+}
+
+/// A view of the differences between consecutive items of a `CopyReiterate`, produced by
+/// `delta_view()`
+pub struct DeltaView<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    pairs: PairwiseMapView<'a, I, Delta<I::Item>>,
+    keep_first: bool,
+}
+
+impl<'a, I> DeltaView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Sub<Output = I::Item>,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> DeltaReiterator<'_, 'a, I> {
+        DeltaReiterator {
+            pairs: self.pairs.iter(),
+            first: self.keep_first,
+        }
+    }
+}
+
+impl<'v, 'a, I> IntoIterator for &'v DeltaView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Sub<Output = I::Item>,
+{
+    type IntoIter = DeltaReiterator<'v, 'a, I>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `DeltaView`
+pub struct DeltaReiterator<'v, 'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    pairs: PairwiseMapReiterator<'v, 'a, I, Delta<I::Item>>,
+    // Whether the first item is still to be yielded, as it is
+    first: bool,
+}
+
+impl<'v, 'a, I> Iterator for DeltaReiterator<'v, 'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Sub<Output = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.first {
+            self.pairs.ahead.size_hint()
+        } else {
+            self.pairs.size_hint()
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        if !self.first {
+            return self.pairs.nth(n);
+        }
+        self.first = false;
+        let first = self.pairs.ahead.next()?;
+        match n.checked_sub(1) {
+            None => Some(first),
+            Some(n) => self.pairs.nth(n),
+        }
+    }
+}
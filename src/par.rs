@@ -0,0 +1,93 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use rayon::iter::IntoParallelIterator;
+use rayon::vec::IntoIter;
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: Sync,
+{
+    /// Iterate over the items cached so far in parallel, without pulling.
+    ///
+    /// A `Reiterate` can't be shared across threads, so this collects references to the
+    /// cached items first, and the threads only ever see those.
+    ///
+    /// ```rust
+    /// use rayon::prelude::*;
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..1000).map(|i| i.to_string()));
+    /// reiterate.into_iter().nth(99);
+    /// let digits: usize = reiterate.par_iter().map(|s| s.len()).sum();
+    /// assert_eq!(digits, 10 + 90 * 2);
+    /// assert_eq!(reiterate.cached_len(), 100);
+    /// ```
+    pub fn par_iter(&self) -> IntoIter<&<I::Item as Deref>::Target> {
+        let cached: Vec<_> = self.cache.iter().take(self.visible_len()).collect();
+        cached.into_par_iter()
+    }
+
+    /// Pull the rest of the items, then iterate over all of them in parallel.
+    ///
+    /// Pulling happens on the current thread, and never returns for an endless source.
+    ///
+    /// ```rust
+    /// use rayon::prelude::*;
+    /// use reiterate::Reiterate;
+    ///
+    /// let words = Reiterate::new("the quick red fox".split(' ').map(String::from));
+    /// let longest = words.par_exhaust().max_by_key(|w| w.len());
+    /// assert_eq!(longest, Some("quick"));
+    /// // later passes reuse the cache
+    /// assert_eq!(words.par_iter().filter(|w| w.contains('e')).count(), 2);
+    /// ```
+    pub fn par_exhaust(&self) -> IntoIter<&<I::Item as Deref>::Target> {
+        self.exhaust();
+        self.par_iter()
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Send,
+{
+    /// Iterate over the items cached so far in parallel, without pulling.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::par_iter()`, over copies of the items.
+    /// Like pulling, it panics if called during a pull.
+    ///
+    /// ```rust
+    /// use rayon::prelude::*;
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(1..=100u64);
+    /// reiterate.into_iter().nth(9);
+    /// assert_eq!(reiterate.par_iter().sum::<u64>(), 55);
+    /// ```
+    pub fn par_iter(&self) -> IntoIter<I::Item> {
+        self.stop_streaming();
+        let len = self.visible_len();
+        self.inner.borrow().cache[..len].to_vec().into_par_iter()
+    }
+
+    /// Pull the rest of the items, then iterate over all of them in parallel.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::par_exhaust()`.
+    ///
+    /// ```rust
+    /// use rayon::prelude::*;
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new((1..=100u64).filter(|i| i % 3 == 0));
+    /// assert_eq!(reiterate.par_exhaust().max(), Some(99));
+    /// assert_eq!(reiterate.par_iter().count(), 33);
+    /// ```
+    pub fn par_exhaust(&self) -> IntoIter<I::Item> {
+        self.exhaust();
+        self.par_iter()
+    }
+}
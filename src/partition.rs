@@ -0,0 +1,192 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+use std::rc::Rc;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a pair of views, over the items matching `pred` and over the rest.
+    ///
+    /// The two views share the verdicts of `pred`, so it runs at most once per item no
+    /// matter how many times, and through which view, the items are visited. When either
+    /// view needs more items, the new items are pulled and sorted for both. The views can
+    /// be cloned, and each one stays usable when the other is dropped.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let calls = Cell::new(0);
+    /// let words = vec!["apple", "Bob", "cherry", "Dave", "Eve", "fig"];
+    /// let reiterate = Reiterate::new(words.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let (names, rest) = reiterate.partition_views(|w| {
+    ///     calls.set(calls.get() + 1);
+    ///     w.starts_with(char::is_uppercase)
+    /// });
+    ///
+    /// assert_eq!(names.iter().collect::<Vec<_>>(), ["Bob", "Dave", "Eve"]);
+    /// assert_eq!((pulls.get(), calls.get()), (6, 6));
+    ///
+    /// drop(names);
+    /// for _ in 0..2 {
+    ///     assert_eq!(rest.iter().collect::<Vec<_>>(), ["apple", "cherry", "fig"]);
+    /// }
+    /// assert_eq!(rest.clone().iter().count(), 3);
+    /// assert_eq!((pulls.get(), calls.get()), (6, 6));
+    /// ```
+    ///
+    /// The views only pull as far as they need to:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((1..).map(Box::new));
+    /// let (evens, odds) = reiterate.partition_views(|i| i % 2 == 0);
+    /// let mut evens = evens.iter();
+    /// assert_eq!(evens.next(), Some(&2));
+    /// assert_eq!(odds.iter().take(3).collect::<Vec<_>>(), [&1, &3, &5]);
+    /// assert_eq!(evens.next(), Some(&4));
+    /// ```
+    pub fn partition_views<P>(&self, pred: P) -> (PartitionView<'_, I, P>, PartitionView<'_, I, P>)
+    where
+        P: Fn(&<I::Item as Deref>::Target) -> bool,
+    {
+        let partition = Rc::new(Partition {
+            iterable: self,
+            pred,
+            scanned: Cell::new(0),
+            sides: [RefCell::new(Vec::new()), RefCell::new(Vec::new())],
+        });
+        let rest = PartitionView {
+            partition: partition.clone(),
+            matching: false,
+        };
+        let matches = PartitionView {
+            partition,
+            matching: true,
+        };
+        (matches, rest)
+    }
+}
+
+/// The state shared by the two views of a partition
+struct Partition<'a, I, P>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    pred: P,
+    // How many items `pred` has been run on
+    scanned: Cell<usize>,
+    // The indices of the scanned items that don't match `pred`, then of those that do
+    sides: [RefCell<Vec<usize>>; 2],
+}
+
+impl<'a, I, P> Partition<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    /// Run `pred` on the next item, pulling it if needed.
+    /// Returns `false` if the stream has ended.
+    fn scan(&self) -> bool {
+        let idx = self.scanned.get();
+        let item = match self.iterable.get_or_pull(idx) {
+            Some(item) => item,
+            None => return false,
+        };
+        let side = (self.pred)(item) as usize;
+        self.sides[side].borrow_mut().push(idx);
+        self.scanned.set(idx + 1);
+        true
+    }
+}
+
+/// One side of a partition of a `Reiterate`, produced by `partition_views()`
+pub struct PartitionView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+{
+    partition: Rc<Partition<'a, I, P>>,
+    // Whether this view is over the items matching the predicate
+    matching: bool,
+}
+
+impl<'a, I, P> Clone for PartitionView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+{
+    fn clone(&self) -> Self {
+        PartitionView {
+            partition: self.partition.clone(),
+            matching: self.matching,
+        }
+    }
+}
+
+impl<'a, I, P> PartitionView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> PartitionReiterator<'_, 'a, I, P> {
+        PartitionReiterator {
+            view: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<'v, 'a, I, P> IntoIterator for &'v PartitionView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type IntoIter = PartitionReiterator<'v, 'a, I, P>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `PartitionView`, produced by calling `.iter()`
+pub struct PartitionReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+{
+    view: &'v PartitionView<'a, I, P>,
+    // The position among the items of this side
+    curr: usize,
+}
+
+impl<'v, 'a, I, P> Iterator for PartitionReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let partition = &*self.view.partition;
+        let side = &partition.sides[self.view.matching as usize];
+        while side.borrow().len() <= self.curr {
+            if !partition.scan() {
+                return None;
+            }
+        }
+        let idx = side.borrow()[self.curr];
+        self.curr += 1;
+        Some(&partition.iterable.cache[idx])
+    }
+}
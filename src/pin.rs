@@ -0,0 +1,153 @@
+use crate::{Reiterable, Reiterate, Reiterator};
+
+use stable_deref_trait::StableDeref;
+use std::iter::Map;
+use std::ops::Deref;
+use std::pin::Pin;
+
+/// A pinned box, which keeps its contents at the same address just like the `Box` inside
+struct PinnedBox<T: ?Sized>(Pin<Box<T>>);
+
+impl<T: ?Sized> Deref for PinnedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// SAFETY: this derefs to the contents of a `Box`, which don't move when the box does
+unsafe impl<T: ?Sized> StableDeref for PinnedBox<T> {}
+
+type PinnedSource<I, T> = Map<I, fn(Pin<Box<T>>) -> PinnedBox<T>>;
+
+/// An adaptor around an iterator of pinned boxes, like futures or self-referential values,
+/// that can produce multiple iterators sharing an underlying cache.
+///
+/// `Pin<Box<T>>` isn't `StableDeref`, so it can't be cached by a `Reiterate`. This caches
+/// the boxes without ever moving their contents or handing out `&mut` to them, so the
+/// handles can yield `Pin<&T>`.
+///
+/// ```rust
+/// use reiterate::PinReiterate;
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+///
+/// struct Node {
+///     id: u32,
+///     _pin: PhantomPinned,
+/// }
+///
+/// let nodes = (0..4).map(|id| Box::pin(Node { id, _pin: PhantomPinned }));
+/// let reiterate = PinReiterate::new(nodes);
+///
+/// let first: Vec<*const Node> = reiterate.iter().map(|node| &*node as *const Node).collect();
+/// let second: Vec<*const Node> = reiterate.iter().map(|node| &*node as *const Node).collect();
+/// assert_eq!(first, second);
+///
+/// let ids: Vec<u32> = reiterate.iter().map(|node: Pin<&Node>| node.id).collect();
+/// assert_eq!(ids, [0, 1, 2, 3]);
+/// ```
+///
+/// Unsized contents work too:
+///
+/// ```rust
+/// use reiterate::PinReiterate;
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// let futures = (0..3).map(|i| Box::pin(async move { i * 2 }) as Pin<Box<dyn Future<Output = i32>>>);
+/// let reiterate = PinReiterate::new(futures);
+/// assert_eq!(reiterate.iter().count(), 3);
+/// assert_eq!(reiterate.iter().count(), 3);
+/// ```
+pub struct PinReiterate<I, T>
+where
+    I: Iterator<Item = Pin<Box<T>>>,
+    T: ?Sized,
+{
+    inner: Reiterate<PinnedSource<I, T>>,
+}
+
+impl<I, T> PinReiterate<I, T>
+where
+    I: Iterator<Item = Pin<Box<T>>>,
+    T: ?Sized,
+{
+    pub fn new<II>(iter: II) -> Self
+    where
+        II: IntoIterator<Item = Pin<Box<T>>, IntoIter = I>,
+    {
+        PinReiterate {
+            inner: Reiterate::new(iter.into_iter().map(PinnedBox as fn(_) -> _)),
+        }
+    }
+
+    /// Get an iterator over the pinned items, reusing cached values
+    pub fn iter(&self) -> PinReiterator<'_, I, T> {
+        PinReiterator {
+            iter: self.inner.into_iter(),
+        }
+    }
+}
+
+impl<'a, I, T> IntoIterator for &'a PinReiterate<I, T>
+where
+    I: Iterator<Item = Pin<Box<T>>>,
+    T: ?Sized,
+{
+    type IntoIter = PinReiterator<'a, I, T>;
+    type Item = Pin<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I, T> Reiterable for PinReiterate<I, T>
+where
+    I: Iterator<Item = Pin<Box<T>>>,
+    T: ?Sized,
+{
+    type Item<'a>
+        = Pin<&'a T>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = PinReiterator<'a, I, T>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> PinReiterator<'_, I, T> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.iter()` on a `PinReiterate`
+pub struct PinReiterator<'a, I, T>
+where
+    I: Iterator<Item = Pin<Box<T>>>,
+    T: ?Sized,
+{
+    iter: Reiterator<'a, PinnedSource<I, T>>,
+}
+
+impl<'a, I, T> Iterator for PinReiterator<'a, I, T>
+where
+    I: Iterator<Item = Pin<Box<T>>>,
+    T: ?Sized,
+{
+    type Item = Pin<&'a T>;
+
+    fn next(&mut self) -> Option<Pin<&'a T>> {
+        // SAFETY: the item is the contents of a pinned box, and `PinReiterate` never moves
+        // its boxes out or hands out `&mut` to them
+        self.iter
+            .next()
+            .map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{CopyReiterate, CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get an iterator for the main pass over the items, which caches them like any other
+    /// handle does, for later replays.
+    ///
+    /// Its position is kept on the `Reiterate`, so each call resumes where the previous
+    /// one left off. Taking `&mut self` keeps this to a single main pass at a time.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// fn total(items: impl Iterator<Item = u32>) -> u32 {
+    ///     items.sum()
+    /// }
+    ///
+    /// let pulls = Cell::new(0);
+    /// let mut reiterate = Reiterate::new((1..=6).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1)));
+    ///
+    /// assert_eq!(reiterate.primary().take(2).copied().collect::<Vec<_>>(), [1, 2]);
+    /// // resumes after the items the first call used up
+    /// assert_eq!(total(reiterate.primary().copied()), 3 + 4 + 5 + 6);
+    /// assert_eq!(reiterate.primary().next(), None);
+    ///
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(pulls.get(), 6);
+    /// ```
+    pub fn primary(&mut self) -> PrimaryIter<'_, I> {
+        PrimaryIter {
+            iter: Reiterator {
+                iterable: self,
+                curr: self.primary.get(),
+            },
+        }
+    }
+}
+
+/// The iterator for the main pass over a `Reiterate`, produced by `primary()`
+pub struct PrimaryIter<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: Reiterator<'a, I>,
+}
+
+impl<'a, I> Iterator for PrimaryIter<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        self.iter.iterable.primary.set(self.iter.curr);
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get an iterator for the main pass over the items, which caches them like any other
+    /// handle does, for later replays.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::primary()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let mut reiterate = CopyReiterate::new("main pass".chars());
+    /// let word: String = reiterate.primary().take_while(|&c| c != ' ').collect();
+    /// assert_eq!(word, "main");
+    /// assert_eq!(reiterate.primary().collect::<String>(), "pass");
+    /// assert_eq!(reiterate.into_iter().collect::<String>(), "main pass");
+    /// ```
+    pub fn primary(&mut self) -> CopyPrimaryIter<'_, I> {
+        self.stop_streaming();
+        CopyPrimaryIter {
+            iter: CopyReiterator {
+                iterable: self,
+                curr: self.primary.get(),
+            },
+        }
+    }
+}
+
+/// The iterator for the main pass over a `CopyReiterate`, produced by `primary()`
+pub struct CopyPrimaryIter<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iter: CopyReiterator<'a, I>,
+}
+
+impl<'a, I> Iterator for CopyPrimaryIter<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next();
+        self.iter.iterable.primary.set(self.iter.curr);
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get a cursor that advances the position of the main pass, the one `primary()`
+    /// resumes from, through `&self`.
+    ///
+    /// Every cursor shares that one position, so cursors can be handed to the functions
+    /// of a parser: whatever a callee consumes is consumed for the caller too. Regular
+    /// handles keep their own positions, which makes them suited to speculative lookahead.
+    ///
+    /// ```rust
+    /// use reiterate::{CopyReiterate, SharedCursor};
+    ///
+    /// // number = digit+
+    /// fn number<I: Iterator<Item = char>>(mut cursor: SharedCursor<'_, I>) -> Option<u32> {
+    ///     let mut value = None;
+    ///     while let Some(digit) = cursor.peek().and_then(|c| c.to_digit(10)) {
+    ///         cursor.next();
+    ///         value = Some(value.unwrap_or(0) * 10 + digit);
+    ///     }
+    ///     value
+    /// }
+    ///
+    /// // sum = number ('+' number)*, backtracking over a trailing '+'
+    /// fn sum<I: Iterator<Item = char>>(mut cursor: SharedCursor<'_, I>) -> Option<u32> {
+    ///     let mut total = number(cursor)?;
+    ///     loop {
+    ///         let mark = cursor.mark();
+    ///         if cursor.next() != Some('+') {
+    ///             cursor.reset(mark);
+    ///             return Some(total);
+    ///         }
+    ///         match number(cursor) {
+    ///             Some(n) => total += n,
+    ///             None => {
+    ///                 cursor.reset(mark);
+    ///                 return Some(total);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let tokens = CopyReiterate::new("12+30+4+;".chars());
+    /// let mut cursor = tokens.shared_cursor();
+    /// assert_eq!(sum(cursor), Some(46));
+    /// // the callees consumed everything but the trailing "+;"
+    /// assert_eq!(cursor.mark(), 7);
+    /// assert_eq!(cursor.collect::<String>(), "+;");
+    ///
+    /// // a regular handle still starts from the beginning
+    /// assert_eq!(tokens.into_iter().next(), Some('1'));
+    /// ```
+    pub fn shared_cursor(&self) -> SharedCursor<'_, I> {
+        self.stop_streaming();
+        SharedCursor { iterable: self }
+    }
+}
+
+/// A cursor advancing the position of the main pass over a `CopyReiterate`, produced by
+/// `shared_cursor()`
+pub struct SharedCursor<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a CopyReiterate<I>,
+}
+
+impl<'a, I> Clone for SharedCursor<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I> Copy for SharedCursor<'a, I> where I: Iterator + ?Sized {}
+
+impl<'a, I> SharedCursor<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get the item at the shared position without consuming it
+    pub fn peek(&self) -> Option<I::Item> {
+        self.handle().next()
+    }
+
+    /// The shared position, to `reset()` to later
+    pub fn mark(&self) -> usize {
+        self.iterable.primary.get()
+    }
+
+    /// Move the shared position back, or forward, to `mark`
+    pub fn reset(&self, mark: usize) {
+        self.iterable.primary.set(mark);
+    }
+
+    /// A regular handle at the shared position
+    fn handle(&self) -> CopyReiterator<'a, I> {
+        CopyReiterator {
+            iterable: self.iterable,
+            curr: self.iterable.primary.get(),
+        }
+    }
+}
+
+impl<'a, I> Iterator for SharedCursor<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut handle = self.handle();
+        let item = handle.next();
+        self.iterable.primary.set(handle.curr);
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.handle().size_hint()
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{Reiterable, ReiterableItem, TryNext};
+
+use elsa::FrozenVec;
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+
+/// A cache that items are pushed into, rather than pulled from an iterator, and that
+/// can produce multiple iterators replaying everything pushed so far.
+///
+/// Like `Reiterate`, items must be heap-allocated StableDeref values. Until the
+/// `PushReiterate` is closed, iterators return `None` at the end of the cache but pick up
+/// items pushed later. Use `try_next()` to tell a temporarily empty cache from a closed one.
+///
+/// ```rust
+/// use reiterate::{PushReiterate, TryNext};
+///
+/// let events = PushReiterate::new();
+/// let mut first = events.into_iter();
+/// let mut second = events.into_iter();
+///
+/// events.push("start".to_string());
+/// assert_eq!(first.next(), Some("start"));
+/// assert_eq!(first.try_next(), TryNext::Empty);
+///
+/// events.push("middle".to_string());
+/// assert_eq!(second.next(), Some("start"));
+/// assert_eq!(first.next(), Some("middle"));
+///
+/// events.push("end".to_string());
+/// events.close();
+/// assert_eq!(first.try_next(), TryNext::Ready("end"));
+/// assert_eq!(first.try_next(), TryNext::Disconnected);
+/// assert_eq!(second.collect::<Vec<_>>(), ["middle", "end"]);
+/// ```
+pub struct PushReiterate<T> {
+    cache: FrozenVec<T>,
+    closed: Cell<bool>,
+}
+
+impl<T> Default for PushReiterate<T>
+where
+    T: ReiterableItem,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PushReiterate<T>
+where
+    T: ReiterableItem,
+{
+    pub fn new() -> Self {
+        PushReiterate {
+            cache: FrozenVec::new(),
+            closed: Cell::new(false),
+        }
+    }
+
+    /// Add an item, for every iterator to see.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `PushReiterate` has been closed.
+    pub fn push(&self, item: T) {
+        assert!(!self.closed.get(), "pushed to a closed PushReiterate");
+        self.cache.push(item);
+    }
+
+    /// Mark the end of the stream: iterators reaching the end of the cache are done for good
+    pub fn close(&self) {
+        self.closed.set(true);
+    }
+
+    /// Whether `close()` has been called
+    pub fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PushReiterate<T>
+where
+    T: ReiterableItem,
+{
+    type IntoIter = PushReiterator<'a, T>;
+    type Item = &'a T::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PushReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<T> Reiterable for PushReiterate<T>
+where
+    T: ReiterableItem,
+{
+    type Item<'a>
+        = &'a T::Target
+    where
+        Self: 'a;
+    type Iter<'a>
+        = PushReiterator<'a, T>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> PushReiterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&PushReiterate` instance
+pub struct PushReiterator<'a, T> {
+    iterable: &'a PushReiterate<T>,
+    curr: usize,
+}
+
+impl<'a, T> PushReiterator<'a, T>
+where
+    T: ReiterableItem,
+{
+    /// Get the next item, distinguishing a temporarily empty cache from a closed one
+    pub fn try_next(&mut self) -> TryNext<&'a T::Target> {
+        match self.next() {
+            Some(item) => TryNext::Ready(item),
+            None if self.iterable.is_closed() => TryNext::Disconnected,
+            None => TryNext::Empty,
+        }
+    }
+}
+
+impl<'a, T> Iterator for PushReiterator<'a, T>
+where
+    T: ReiterableItem,
+{
+    type Item = &'a <T as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iterable.cache.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
+
+/// A cache of `Copy` values that items are pushed into, rather than pulled from an
+/// iterator, and that can produce multiple iterators replaying everything pushed so far.
+///
+/// This is the `Copy` counterpart of `PushReiterate`.
+///
+/// ```rust
+/// use reiterate::{CopyPushReiterate, TryNext};
+///
+/// let readings = CopyPushReiterate::new();
+/// let mut live = readings.into_iter();
+/// readings.push(1.5);
+/// readings.push(2.5);
+/// assert_eq!(live.next(), Some(1.5));
+///
+/// let mut late = readings.into_iter();
+/// readings.push(3.5);
+/// readings.close();
+/// assert_eq!(late.by_ref().collect::<Vec<_>>(), [1.5, 2.5, 3.5]);
+/// assert_eq!(late.try_next(), TryNext::Disconnected);
+/// assert_eq!(live.collect::<Vec<_>>(), [2.5, 3.5]);
+/// ```
+pub struct CopyPushReiterate<T> {
+    cache: RefCell<Vec<T>>,
+    closed: Cell<bool>,
+}
+
+impl<T> Default for CopyPushReiterate<T>
+where
+    T: Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CopyPushReiterate<T>
+where
+    T: Copy,
+{
+    pub fn new() -> Self {
+        CopyPushReiterate {
+            cache: RefCell::new(Vec::new()),
+            closed: Cell::new(false),
+        }
+    }
+
+    /// Add an item, for every iterator to see.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `CopyPushReiterate` has been closed.
+    pub fn push(&self, item: T) {
+        assert!(!self.closed.get(), "pushed to a closed CopyPushReiterate");
+        self.cache.borrow_mut().push(item);
+    }
+
+    /// Mark the end of the stream: iterators reaching the end of the cache are done for good
+    pub fn close(&self) {
+        self.closed.set(true);
+    }
+
+    /// Whether `close()` has been called
+    pub fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CopyPushReiterate<T>
+where
+    T: Copy,
+{
+    type IntoIter = CopyPushReiterator<'a, T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CopyPushReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<T> Reiterable for CopyPushReiterate<T>
+where
+    T: Copy,
+{
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = CopyPushReiterator<'a, T>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> CopyPushReiterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&CopyPushReiterate` instance
+pub struct CopyPushReiterator<'a, T> {
+    iterable: &'a CopyPushReiterate<T>,
+    curr: usize,
+}
+
+impl<'a, T> CopyPushReiterator<'a, T>
+where
+    T: Copy,
+{
+    /// Get the next item, distinguishing a temporarily empty cache from a closed one
+    pub fn try_next(&mut self) -> TryNext<T> {
+        match self.next() {
+            Some(item) => TryNext::Ready(item),
+            None if self.iterable.is_closed() => TryNext::Disconnected,
+            None => TryNext::Empty,
+        }
+    }
+}
+
+impl<'a, T> Iterator for CopyPushReiterator<'a, T>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let val = *self.iterable.cache.borrow().get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::ptr::NonNull;
+
+/// A source queued by `queue_source()`, with its type erased so that the queue only
+/// depends on the item type. That keeps the layout of every field but the underlying
+/// iterator independent of its type, which `From<Reiterate<I>>` for the `dyn` version
+/// relies on.
+struct QueuedSource<T> {
+    source: NonNull<()>,
+    next: unsafe fn(NonNull<()>) -> Option<T>,
+    size_hint: unsafe fn(NonNull<()>) -> (usize, Option<usize>),
+    drop: unsafe fn(NonNull<()>),
+}
+
+unsafe fn next<I: Iterator>(source: NonNull<()>) -> Option<I::Item> {
+    (*source.cast::<I>().as_ptr()).next()
+}
+
+unsafe fn size_hint<I: Iterator>(source: NonNull<()>) -> (usize, Option<usize>) {
+    (*source.cast::<I>().as_ptr()).size_hint()
+}
+
+unsafe fn drop_source<I>(source: NonNull<()>) {
+    drop(Box::from_raw(source.cast::<I>().as_ptr()))
+}
+
+impl<T> QueuedSource<T> {
+    /// # Safety
+    ///
+    /// `source` can't be dropped later than the `Reiterate` it is queued on could be, and
+    /// has to be `Send` if the `Reiterate` is.
+    unsafe fn new<I: Iterator<Item = T>>(source: I) -> Self {
+        QueuedSource {
+            source: NonNull::from(Box::leak(Box::new(source))).cast(),
+            next: next::<I>,
+            size_hint: size_hint::<I>,
+            drop: drop_source::<I>,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The source has to be an `I`.
+    unsafe fn into_inner<I>(self) -> I {
+        let source = self.source.cast::<I>();
+        std::mem::forget(self);
+        *Box::from_raw(source.as_ptr())
+    }
+
+    fn next(&mut self) -> Option<T> {
+        // SAFETY: `source` is the boxed source that the functions were built for
+        unsafe { (self.next)(self.source) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // SAFETY: as above
+        unsafe { (self.size_hint)(self.source) }
+    }
+}
+
+impl<T> Drop for QueuedSource<T> {
+    fn drop(&mut self) {
+        // SAFETY: as above, and the source isn't used again
+        unsafe { (self.drop)(self.source) }
+    }
+}
+
+// SAFETY: sources are only queued on a `Reiterate` whose own source has the same type,
+// so the `Reiterate` isn't `Send` unless they are
+unsafe impl<T> Send for QueuedSource<T> {}
+
+/// The sources to pull from once the underlying iterator has ended, in order
+pub(crate) struct SourceQueue<T> {
+    sources: RefCell<VecDeque<QueuedSource<T>>>,
+    // Whether the last pull found every source ended, and nothing was queued since
+    ended: Cell<bool>,
+}
+
+impl<T> Default for SourceQueue<T> {
+    fn default() -> Self {
+        SourceQueue {
+            sources: RefCell::default(),
+            ended: Cell::new(false),
+        }
+    }
+}
+
+impl<T> SourceQueue<T> {
+    /// # Safety
+    ///
+    /// `source` has to have the type of the underlying iterator of the `Reiterate` that
+    /// owns this queue.
+    unsafe fn push<I: Iterator<Item = T>>(&self, source: I) {
+        self.sources
+            .borrow_mut()
+            .push_back(QueuedSource::new(source));
+        self.ended.set(false);
+    }
+
+    /// Pass on `val`, the item pulled from the underlying iterator, or pull the next item
+    /// from the queued sources if it has ended
+    #[inline]
+    pub(crate) fn or_pull(&self, val: Option<T>) -> Option<T> {
+        match val {
+            Some(val) => {
+                // An unfused underlying iterator can go on after returning `None`
+                self.ended.set(false);
+                Some(val)
+            }
+            None => self.pull(),
+        }
+    }
+
+    /// Pull the next item from the queued sources, dropping those that have ended
+    fn pull(&self) -> Option<T> {
+        loop {
+            // Not borrowed while pulling, so that the source can queue more sources
+            let mut source = match self.sources.borrow_mut().pop_front() {
+                Some(source) => source,
+                None => {
+                    self.ended.set(true);
+                    return None;
+                }
+            };
+            if let Some(val) = source.next() {
+                self.sources.borrow_mut().push_front(source);
+                return Some(val);
+            }
+        }
+    }
+
+    /// Drop the queued sources
+    pub(crate) fn clear(&mut self) {
+        // Taken out first, so that the queue is empty if dropping a source panics
+        let sources = std::mem::take(self.sources.get_mut());
+        drop(sources);
+    }
+
+    /// Forget that every source had ended, for a new underlying iterator
+    pub(crate) fn reopen(&mut self) {
+        *self.ended.get_mut() = false;
+    }
+
+    /// Whether the underlying iterator and every queued source had ended as of the last
+    /// pull, which is only called for once the underlying iterator has returned `None`
+    pub(crate) fn ended(&self) -> bool {
+        self.ended.get()
+    }
+
+    /// The combined size hint of the queued sources
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sources
+            .borrow()
+            .iter()
+            .fold((0, Some(0)), |(lower, upper), source| {
+                let (l, u) = source.size_hint();
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            })
+    }
+
+    /// Chain a size hint of the underlying iterator with those of the queued sources
+    pub(crate) fn chain_size_hint(&self, hint: (usize, Option<usize>)) -> (usize, Option<usize>) {
+        let (lower, upper) = self.size_hint();
+        (
+            hint.0.saturating_add(lower),
+            hint.1.zip(upper).and_then(|(a, b)| a.checked_add(b)),
+        )
+    }
+
+    /// Clone the queued sources.
+    ///
+    /// # Safety
+    ///
+    /// They have to be `I`s.
+    pub(crate) unsafe fn clone_as<I>(&self) -> Self
+    where
+        I: Iterator<Item = T> + Clone,
+    {
+        let sources = self.sources.borrow();
+        let cloned = sources
+            .iter()
+            .map(|source| QueuedSource::new((*source.source.cast::<I>().as_ptr()).clone()))
+            .collect();
+        SourceQueue {
+            sources: RefCell::new(cloned),
+            ended: Cell::new(self.ended.get()),
+        }
+    }
+
+    /// Take the queued sources out.
+    ///
+    /// # Safety
+    ///
+    /// They have to be `I`s.
+    pub(crate) unsafe fn into_sources<I>(self) -> VecDeque<I>
+    where
+        I: Iterator<Item = T>,
+    {
+        self.sources
+            .into_inner()
+            .into_iter()
+            .map(|source| source.into_inner::<I>())
+            .collect()
+    }
+
+    /// Queue `sources`.
+    ///
+    /// # Safety
+    ///
+    /// As for `push()`.
+    pub(crate) unsafe fn from_sources<I>(sources: VecDeque<I>) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        let queue = SourceQueue::default();
+        for source in sources {
+            queue.push(source);
+        }
+        queue
+    }
+
+    /// Convert the queued sources with `f`.
+    ///
+    /// # Safety
+    ///
+    /// They have to be `I`s, and the results are subject to the requirements of `push()`.
+    pub(crate) unsafe fn map_as<I, J>(self, f: impl Fn(I) -> J) -> SourceQueue<J::Item>
+    where
+        I: Iterator<Item = T>,
+        J: Iterator,
+    {
+        let ended = self.ended;
+        let mapped = self
+            .sources
+            .into_inner()
+            .into_iter()
+            .map(|source| QueuedSource::new(f(source.into_inner::<I>())))
+            .collect();
+        SourceQueue {
+            sources: RefCell::new(mapped),
+            ended,
+        }
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Queue another source, to be pulled from once the underlying iterator and the
+    /// sources queued before it have ended.
+    ///
+    /// Handles see one continuous stream: one that had reached the end carries on into
+    /// the new items. This can be called at any time, including from within a source
+    /// while it is being pulled from, e.g. to queue the next page of results. The
+    /// underlying iterator is asked for an item before the queued sources each time, so
+    /// one that isn't fused and resumes is picked up again first.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec![Box::new(1), Box::new(2)]);
+    /// let mut old = reiterate.into_iter();
+    /// assert_eq!(old.by_ref().count(), 2);
+    /// assert_eq!(old.next(), None);
+    ///
+    /// reiterate.queue_source(vec![Box::new(3)]);
+    /// reiterate.queue_source(vec![Box::new(4), Box::new(5)]);
+    /// assert_eq!(old.copied().collect::<Vec<_>>(), [3, 4, 5]);
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// Sources can queue the pages that come after them, as they discover them:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::OnceCell;
+    /// use std::rc::{Rc, Weak};
+    ///
+    /// type Page = Box<dyn Iterator<Item = Box<u32>>>;
+    /// type Link = Rc<OnceCell<Weak<Reiterate<Page>>>>;
+    ///
+    /// fn page(n: u32, link: Link) -> Page {
+    ///     Box::new((0..2).map(move |i| {
+    ///         if i == 1 && n < 2 {
+    ///             let reiterate = link.get().unwrap().upgrade().unwrap();
+    ///             reiterate.queue_source(page(n + 1, link.clone()));
+    ///         }
+    ///         Box::new(n * 10 + i)
+    ///     }))
+    /// }
+    ///
+    /// let link = Link::default();
+    /// let reiterate = Rc::new(Reiterate::new(page(0, link.clone())));
+    /// link.set(Rc::downgrade(&reiterate)).unwrap();
+    /// for _ in 0..2 {
+    ///     let items: Vec<u32> = reiterate.into_iter().copied().collect();
+    ///     assert_eq!(items, [0, 1, 10, 11, 20, 21]);
+    /// }
+    /// ```
+    pub fn queue_source<T>(&self, iter: T)
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        // SAFETY: the source has the type of the underlying iterator
+        unsafe { self.queued.push(iter.into_iter()) }
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// Queue another source, to be pulled from once the underlying iterator and the
+    /// sources queued before it have ended.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::queue_source()`. The queued sources
+    /// count towards the size hints of the handles.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(vec![1, 2]);
+    /// assert_eq!(reiterate.into_iter().count(), 2);
+    /// reiterate.queue_source(vec![3, 4, 5]);
+    /// reiterate.queue_source(vec![]);
+    /// reiterate.queue_source(vec![6]);
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.size_hint(), (6, Some(6)));
+    /// assert_eq!(iter.by_ref().take(4).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// assert_eq!(iter.size_hint(), (2, Some(2)));
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [1, 2, 3, 4, 5, 6]);
+    ///
+    /// // queued sources carry over into a `Reiterate`
+    /// reiterate.queue_source(vec![7]);
+    /// let boxed = reiterate.into_reiterate();
+    /// assert_eq!(boxed.into_iter().last(), Some(&7));
+    /// ```
+    pub fn queue_source<T>(&self, iter: T)
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        // SAFETY: the source has the type of the underlying iterator
+        unsafe { self.queued.push(iter.into_iter()) }
+    }
+}
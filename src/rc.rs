@@ -0,0 +1,148 @@
+use crate::{ReiterableItem, Reiterate, Reiterator};
+
+use std::borrow::ToOwned;
+use std::ops::Deref;
+use std::rc::Rc;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Move this into an `Rc`, so that iterators owning a reference to it can be stored
+    /// without a lifetime, like `SyncReiterate::into_shared()` for a single thread
+    pub fn into_shared(self) -> RcReiterate<I> {
+        RcReiterate {
+            inner: Rc::new(self),
+        }
+    }
+}
+
+/// A `Reiterate` shared through an `Rc`, produced by `Reiterate::into_shared()`.
+///
+/// Cloning it is cheap and shares the same cache. Its owned iterators aren't tied to any
+/// borrow, so they can be kept in a struct or passed to a callback with a `'static`
+/// bound. Like for an `ArcReiterate`, they yield owned copies of the cached items via
+/// `ToOwned`; borrowing iterators from `iter()` yield references instead.
+///
+/// ```rust
+/// use reiterate::{RcReiterate, RcReiterator, Reiterate};
+///
+/// struct Parser {
+///     tokens: RcReiterator<std::vec::IntoIter<String>>,
+/// }
+///
+/// fn parser(source: &RcReiterate<std::vec::IntoIter<String>>) -> Parser {
+///     Parser { tokens: source.iter_owned() }
+/// }
+///
+/// let shared = Reiterate::new(vec!["let".to_string(), "x".to_string()]).into_shared();
+/// let mut first = parser(&shared);
+/// let second = parser(&shared.clone());
+/// assert_eq!(first.tokens.next(), Some("let".to_string()));
+/// assert_eq!(second.tokens.collect::<Vec<_>>(), ["let", "x"]);
+/// assert_eq!(shared.iter().collect::<Vec<_>>(), ["let", "x"]);
+///
+/// // a callback that has to be `'static`
+/// let callback: Box<dyn Fn() -> usize> = {
+///     let shared = shared.clone();
+///     Box::new(move || shared.iter_owned().count())
+/// };
+/// assert_eq!(callback(), 2);
+/// ```
+pub struct RcReiterate<I>
+where
+    I: Iterator,
+{
+    inner: Rc<Reiterate<I>>,
+}
+
+impl<I> RcReiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Get an iterator owning a reference to the shared cache
+    pub fn iter_owned(&self) -> RcReiterator<I> {
+        RcReiterator {
+            iterable: self.inner.clone(),
+            curr: 0,
+        }
+    }
+
+    /// Get an iterator borrowing the shared cache
+    pub fn iter(&self) -> Reiterator<'_, I> {
+        self.inner.into_iter()
+    }
+}
+
+impl<I> Clone for RcReiterate<I>
+where
+    I: Iterator,
+{
+    fn clone(&self) -> Self {
+        RcReiterate {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I> Deref for RcReiterate<I>
+where
+    I: Iterator,
+{
+    type Target = Reiterate<I>;
+
+    fn deref(&self) -> &Reiterate<I> {
+        &self.inner
+    }
+}
+
+/// An iterator owning a reference to the cache of an `RcReiterate`, produced by calling
+/// `.iter_owned()`. It yields owned copies of the cached items.
+pub struct RcReiterator<I>
+where
+    I: Iterator,
+{
+    iterable: Rc<Reiterate<I>>,
+    curr: usize,
+}
+
+impl<I> Clone for RcReiterator<I>
+where
+    I: Iterator,
+{
+    fn clone(&self) -> Self {
+        RcReiterator {
+            iterable: self.iterable.clone(),
+            curr: self.curr,
+        }
+    }
+}
+
+impl<I> Iterator for RcReiterator<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+    <I::Item as Deref>::Target: ToOwned,
+{
+    type Item = <<I::Item as Deref>::Target as ToOwned>::Owned;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut handle = Reiterator {
+            iterable: &self.iterable,
+            curr: self.curr,
+        };
+        let val = handle.next()?.to_owned();
+        self.curr = handle.curr;
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Reiterator {
+            iterable: &self.iterable,
+            curr: self.curr,
+        }
+        .size_hint()
+    }
+}
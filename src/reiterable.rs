@@ -0,0 +1,84 @@
+use crate::{CopyReiterate, CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+
+/// The contract shared by every type in this crate that can be iterated over any number
+/// of times through `&self`, for code that should work with any of them.
+///
+/// ```rust
+/// use reiterate::{CopyReiterate, Reiterable, Reiterate};
+/// use std::fmt::Display;
+///
+/// fn render<'a, R>(data: &'a R) -> String
+/// where
+///     R: Reiterable,
+///     R::Item<'a>: Display,
+/// {
+///     let first: Vec<String> = data.iter().map(|item| item.to_string()).collect();
+///     // a second pass replays the same items
+///     assert_eq!(data.iter().count(), first.len());
+///     first.join(", ")
+/// }
+///
+/// let boxed = Reiterate::new((1..=3).map(Box::new));
+/// assert_eq!(render(&boxed), "1, 2, 3");
+/// let copied = CopyReiterate::new("abc".chars());
+/// assert_eq!(render(&copied), "a, b, c");
+/// ```
+///
+/// The trait isn't object safe, since its associated types are generic over the lifetime
+/// of the borrow. To erase the type of the source instead, use a `Reiterate` of a
+/// `BoxedSource`.
+pub trait Reiterable {
+    /// The items yielded by `iter()`
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// The iterator returned by `iter()`
+    type Iter<'a>: Iterator<Item = Self::Item<'a>>
+    where
+        Self: 'a;
+
+    /// Get an iterator over the items, which replays the items pulled so far before
+    /// pulling more
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+impl<I> Reiterable for Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item<'a>
+        = &'a <I::Item as Deref>::Target
+    where
+        Self: 'a;
+    type Iter<'a>
+        = Reiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> Reiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+impl<I> Reiterable for CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = CopyReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> CopyReiterator<'_, I> {
+        self.into_iter()
+    }
+}
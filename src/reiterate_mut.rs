@@ -0,0 +1,199 @@
+use crate::fill::Fill;
+use crate::queue::SourceQueue;
+use crate::{ReiterableItem, Reiterate};
+
+use elsa::FrozenVec;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::ops::Deref;
+
+/// A variant of `Reiterate` that is advanced explicitly through `&mut self`, and read
+/// through `&self`.
+///
+/// Without the interior mutability that lets `Reiterate` pull from `&self`, this is `Sync`
+/// whenever the items and the underlying iterator are, and pulling is cheaper. Convert to
+/// and from `Reiterate` with `From` to switch between the two.
+///
+/// ```rust
+/// use reiterate::ReiterateMut;
+/// use std::thread;
+///
+/// let mut reiterate = ReiterateMut::new((0..10).map(Box::new));
+/// assert_eq!(reiterate.advance(), Some(&0));
+/// assert_eq!(reiterate.prime(4), 4);
+/// assert_eq!(reiterate.get(3), Some(&3));
+/// assert_eq!(reiterate.get(4), None);
+///
+/// // the cache can be read from other threads
+/// let reiterate = &reiterate;
+/// thread::scope(|s| {
+///     s.spawn(|| assert_eq!(reiterate.cached().sum::<i32>(), 6));
+///     s.spawn(|| assert_eq!(reiterate.cached_len(), 4));
+/// });
+/// ```
+pub struct ReiterateMut<I>
+where
+    I: Iterator,
+{
+    cache: Vec<I::Item>,
+    iter: I,
+    // The sources to pull from once `iter` has ended, see `Reiterate::queue_source()`
+    queued: VecDeque<I>,
+}
+
+impl<I> ReiterateMut<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        ReiterateMut {
+            cache: Vec::new(),
+            iter: iter.into_iter(),
+            queued: VecDeque::new(),
+        }
+    }
+
+    /// Pull the next item from the underlying iterator and cache it
+    pub fn advance(&mut self) -> Option<&<I::Item as Deref>::Target> {
+        if !self.fill_one() {
+            return None;
+        }
+        self.cache.last().map(|val| &**val)
+    }
+
+    /// Pull from the underlying iterator until at least `n` items are cached, or the stream
+    /// ends. Returns how many items are cached.
+    ///
+    /// ```rust
+    /// use reiterate::ReiterateMut;
+    ///
+    /// let mut reiterate = ReiterateMut::new(vec!["a", "b", "c"]);
+    /// assert_eq!(reiterate.prime(2), 2);
+    /// assert_eq!(reiterate.prime(1), 2);
+    /// assert_eq!(reiterate.prime(usize::MAX), 3);
+    /// assert_eq!(reiterate.cached().collect::<String>(), "abc");
+    /// ```
+    pub fn prime(&mut self, n: usize) -> usize {
+        let missing = n.saturating_sub(self.cache.len());
+        self.cache.reserve(self.iter.size_hint().0.min(missing));
+        self.fill_to(n)
+    }
+
+    /// Get the cached item at `idx`
+    pub fn get(&self, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        self.cache.get(idx).map(|val| &**val)
+    }
+
+    /// Iterate over the cached items
+    pub fn cached(&self) -> impl Iterator<Item = &<I::Item as Deref>::Target> + '_ {
+        self.cache.iter().map(|val| &**val)
+    }
+
+    /// The number of cached items
+    pub fn cached_len(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+impl<I> Fill for ReiterateMut<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    type Item = I::Item;
+
+    fn filled_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    fn pull_item(&mut self) -> Option<I::Item> {
+        if let Some(val) = self.iter.next() {
+            return Some(val);
+        }
+        // Drop the queued sources that have ended
+        loop {
+            if let Some(val) = self.queued.front_mut()?.next() {
+                return Some(val);
+            }
+            self.queued.pop_front();
+        }
+    }
+
+    fn store_item(&mut self, val: I::Item) {
+        self.cache.push(val);
+    }
+}
+
+/// The cache is moved over as it is.
+///
+/// ```rust
+/// use reiterate::{Reiterate, ReiterateMut};
+///
+/// let mut fill = ReiterateMut::new((0..6).map(Box::new));
+/// fill.prime(3);
+/// let reiterate = Reiterate::from(fill);
+/// assert_eq!(reiterate.iter_tail().next(), Some(&3));
+/// assert_eq!(reiterate.into_iter().count(), 6);
+/// ```
+impl<I> From<ReiterateMut<I>> for Reiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    fn from(reiterate: ReiterateMut<I>) -> Self {
+        Reiterate {
+            cache: FrozenVec::from(reiterate.cache),
+            poisoned: Cell::new(false),
+            sorted: Default::default(),
+            measurer: None,
+            heap_size: Cell::new(0),
+            addresses: RefCell::default(),
+            aliasing: None,
+            limit: Cell::new(usize::MAX),
+            generation: 0,
+            primary: Cell::new(0),
+            stats: None,
+            slow_path: Cell::new(false),
+            // SAFETY: the queued sources have the type of the underlying iterator
+            queued: unsafe { SourceQueue::from_sources(reiterate.queued) },
+            iter: RefCell::new(reiterate.iter),
+        }
+    }
+}
+
+/// The cache and the queued sources are moved over. The truncation set by
+/// `Reiterate::truncate()` isn't carried over, so the items past it are kept too, and
+/// the stream goes on after them like the underlying iterator does.
+///
+/// ```rust
+/// use reiterate::{Reiterate, ReiterateMut};
+///
+/// let reiterate = Reiterate::new(vec!["a", "b", "c", "d"]);
+/// assert_eq!(reiterate.into_iter().nth(2), Some("c"));
+/// reiterate.truncate(2);
+/// reiterate.queue_source(vec!["e"]);
+///
+/// let mut reiterate = ReiterateMut::from(reiterate);
+/// assert_eq!(reiterate.cached().collect::<Vec<_>>(), ["a", "b", "c"]);
+/// assert_eq!(reiterate.advance(), Some("d"));
+/// assert_eq!(reiterate.advance(), Some("e"));
+/// assert_eq!(reiterate.advance(), None);
+/// ```
+impl<I> From<Reiterate<I>> for ReiterateMut<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    fn from(reiterate: Reiterate<I>) -> Self {
+        ReiterateMut {
+            cache: reiterate.cache.into_vec(),
+            iter: reiterate.iter.into_inner(),
+            // SAFETY: the queued sources have the type of the underlying iterator
+            queued: unsafe { reiterate.queued.into_sources() },
+        }
+    }
+}
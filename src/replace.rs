@@ -0,0 +1,232 @@
+use crate::lazy::LazyCaching;
+use crate::{CopyReiterate, CopyReiterateInner, ReiterableItem, Reiterate};
+
+use elsa::FrozenVec;
+use std::mem;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Replace the underlying iterator, returning the old one.
+    ///
+    /// The new iterator picks up after the cached items, which are all kept, along with
+    /// their indices. This also clears the poisoned flag, e.g. to replace an iterator that
+    /// panicked, or to continue a stream that ended with its next page. The stream is no
+    /// longer exhausted afterwards: the truncation set by `truncate()` or `close_source()`
+    /// is lifted, dropping the cached items it hid so that the new iterator carries on
+    /// right after the visible ones. Sources of different types can be swapped in by
+    /// making `I` a `Box<dyn Iterator>`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let page = |start: u32| -> Box<dyn Iterator<Item = Box<u32>>> { Box::new((start..start + 3).map(Box::new)) };
+    /// let mut reiterate = Reiterate::new(page(0));
+    /// assert_eq!(reiterate.into_iter().count(), 3);
+    ///
+    /// let mut ended = reiterate.replace_inner(page(3));
+    /// assert!(ended.next().is_none());
+    /// let all: Vec<u32> = reiterate.into_iter().copied().collect();
+    /// assert_eq!(all, [0, 1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::panic::{catch_unwind, AssertUnwindSafe};
+    ///
+    /// let source = |fail: bool| (0..4).map(move |i| if fail && i == 2 { panic!("broken source") } else { Box::new(i) });
+    /// let mut reiterate = Reiterate::new(source(true));
+    /// assert!(catch_unwind(AssertUnwindSafe(|| reiterate.into_iter().count())).is_err());
+    /// assert!(reiterate.is_poisoned());
+    ///
+    /// // a fresh source, skipping what was already cached
+    /// let mut fresh = source(false);
+    /// fresh.nth(1);
+    /// reiterate.replace_inner(fresh);
+    /// assert!(!reiterate.is_poisoned());
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// ```
+    pub fn replace_inner<T>(&mut self, iter: T) -> I
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        *self.poisoned.get_mut() = false;
+        self.lift_truncation();
+        self.queued.reopen();
+        mem::replace(self.iter.get_mut(), iter.into_iter())
+    }
+
+    /// Create a `Reiterate` from items cached earlier, and the iterator to pull the
+    /// following items from, e.g. the parts returned by `into_inner()`.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let seed = vec![Box::new(0), Box::new(1)];
+    /// let reiterate = Reiterate::from_parts(seed, (2..4).map(Box::new));
+    /// assert_eq!(reiterate.cached_len(), 2);
+    /// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    ///
+    /// let (cached, rest) = reiterate.into_inner();
+    /// let resumed = Reiterate::from_parts(cached, rest);
+    /// assert_eq!(resumed.into_iter().count(), 4);
+    /// ```
+    pub fn from_parts<T>(cache: Vec<I::Item>, iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Reiterate {
+            cache: FrozenVec::from(cache),
+            ..Reiterate::new(iter)
+        }
+    }
+
+    /// Take the cached items and the underlying iterator out, to carry on without the
+    /// `Reiterate` once it is no longer shared.
+    ///
+    /// The iterator picks up after the cached items. Every cached item is returned, even
+    /// past the length set by `truncate()`, so that none goes missing between the two;
+    /// use `into_items()` to stop at that length instead. The queued sources are dropped,
+    /// since they can't be returned as part of the underlying iterator; convert into a
+    /// `ReiterateMut` to keep them.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..6).map(Box::new));
+    /// assert_eq!(reiterate.into_iter().nth(2), Some(&2));
+    /// let (cached, rest) = reiterate.into_inner();
+    /// assert_eq!(cached, [Box::new(0), Box::new(1), Box::new(2)]);
+    /// assert_eq!(rest.map(|i| *i).collect::<Vec<_>>(), [3, 4, 5]);
+    /// ```
+    pub fn into_inner(self) -> (Vec<I::Item>, I) {
+        (self.cache.into_vec(), self.iter.into_inner())
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// Replace the underlying iterator, returning the old one.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::replace_inner()`, and lifts the
+    /// truncation in the same way.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let mut reiterate = CopyReiterate::new(vec![1, 2]);
+    /// assert_eq!(reiterate.into_iter().count(), 2);
+    /// reiterate.replace_inner(vec![3]);
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [1, 2, 3]);
+    /// ```
+    pub fn replace_inner<T>(&mut self, iter: T) -> I
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        *self.poisoned.get_mut() = false;
+        self.lift_truncation();
+        self.queued.reopen();
+        mem::replace(&mut self.inner.get_mut().iter, iter.into_iter())
+    }
+
+    /// Drop every cached item and start over with `iter` as the underlying iterator,
+    /// keeping the memory the cache has reserved, for a loop that processes many inputs
+    /// one after the other.
+    ///
+    /// This leaves the `CopyReiterate` the way its constructor did, with no logical
+    /// length, queued sources or remembered sorted orders, except that the cache doesn't
+    /// have to grow again. One created by `lazy_caching()` streams its first pass again,
+    /// even if it had started caching. Taking `&mut self` guarantees no handle is alive to
+    /// observe the change, and checkpoints taken before it are stale.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let inputs = vec![vec![3, 1, 2], vec![10, 20], vec![7]];
+    /// let mut numbers = CopyReiterate::new(Vec::new());
+    /// let mut results = Vec::new();
+    /// for input in inputs {
+    ///     numbers.reset_with(input);
+    ///     // two passes over each input, with one buffer for all of them
+    ///     let total: u32 = numbers.into_iter().sum();
+    ///     let max = numbers.into_iter().max().unwrap();
+    ///     results.push((total, max));
+    /// }
+    /// assert_eq!(results, [(6, 3), (30, 20), (7, 7)]);
+    ///
+    /// let checkpoint = numbers.checkpoint();
+    /// numbers.reset_with(vec![1]);
+    /// assert!(numbers.rollback(checkpoint).is_err());
+    /// assert_eq!(numbers.into_iter().collect_vec(), [1]);
+    ///
+    /// let mut lazy = CopyReiterate::lazy_caching(vec![1, 2]);
+    /// assert_eq!(lazy.into_iter().zip(lazy.into_iter()).count(), 2);
+    /// lazy.reset_with(vec![3, 4, 5]);
+    /// assert_eq!(lazy.into_iter().collect_vec(), [3, 4, 5]);
+    /// assert_eq!(lazy.cached_len().ready(), Some(0));
+    /// ```
+    pub fn reset_with<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let inner = self.inner.get_mut();
+        inner.cache.clear();
+        inner.iter = iter.into_iter();
+        self.queued = Default::default();
+        self.sorted.clear();
+        *self.limit.get_mut() = usize::MAX;
+        *self.primary.get_mut() = 0;
+        *self.poisoned.get_mut() = false;
+        *self.lazy.get_mut() = if self.lazily {
+            LazyCaching::Unused
+        } else {
+            LazyCaching::Off
+        };
+        *self.missed.get_mut() = 0;
+        self.generation += 1;
+    }
+
+    /// Create a `CopyReiterate` from items cached earlier, and the iterator to pull the
+    /// following items from, like `Reiterate::from_parts()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::from_parts(vec![1, 2], vec![3]);
+    /// assert_eq!(reiterate.cached_len().ready(), Some(2));
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [1, 2, 3]);
+    /// ```
+    pub fn from_parts<T>(cache: Vec<I::Item>, iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let mut reiterate = CopyReiterate::new(iter);
+        reiterate.inner.get_mut().cache = cache;
+        reiterate
+    }
+
+    /// Take the cached items and the underlying iterator out.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::into_inner()`, and returns the items
+    /// past the length set by `truncate()` and drops the queued sources in the same way.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("abcde".chars());
+    /// reiterate.into_iter().nth(1);
+    /// reiterate.truncate(1);
+    /// let (cached, rest) = reiterate.into_inner();
+    /// assert_eq!(cached, ['a', 'b']);
+    /// assert_eq!(rest.collect::<String>(), "cde");
+    /// ```
+    pub fn into_inner(self) -> (Vec<I::Item>, I) {
+        let CopyReiterateInner { cache, iter } = self.inner.into_inner();
+        (cache, iter)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Drop the cached items not matching `pred`, returning how many were dropped.
+    ///
+    /// The kept items are compacted, so they keep their relative order but the cache
+    /// indices of everything after the first dropped item shift down: positions recorded
+    /// before the call, like the `rank()`s of a `sorted_view()`, don't refer to the same
+    /// items afterwards. Taking `&mut self` guarantees no handle is alive to observe the
+    /// change, and remembered sorted orders are discarded. Items that haven't been pulled
+    /// yet are unaffected, so new handles replay the kept items and then carry on with the
+    /// underlying iterator.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::mem::size_of;
+    ///
+    /// let lines = vec!["# header", "a = 1", "# comment", "b = 2", "c = 3"];
+    /// let mut reiterate = Reiterate::with_measurer(lines.into_iter().map(String::from), String::len);
+    /// assert_eq!(reiterate.into_iter().take(4).count(), 4);
+    /// let before = reiterate.cache_size_bytes();
+    ///
+    /// assert_eq!(reiterate.retain_cached(|line| !line.starts_with('#')), 2);
+    /// assert_eq!(reiterate.cache_size_bytes(), before - 2 * size_of::<String>() - 17);
+    ///
+    /// // "c = 3" hadn't been pulled yet, and comes after the kept items
+    /// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), ["a = 1", "b = 2", "c = 3"]);
+    /// assert_eq!(reiterate.iter_tail().next(), None);
+    ///
+    /// // the sorted order is computed again from the compacted cache
+    /// assert_eq!(reiterate.sorted_view().rank(0), Some(0));
+    /// assert_eq!(reiterate.retain_cached(|line| line != "a = 1"), 1);
+    /// assert_eq!(reiterate.sorted_view().iter().collect::<Vec<_>>(), ["b = 2", "c = 3"]);
+    /// ```
+    pub fn retain_cached(
+        &mut self,
+        mut pred: impl FnMut(&<I::Item as Deref>::Target) -> bool,
+    ) -> usize {
+        let measurer = self.measurer;
+        let heap_size = self.heap_size.get_mut();
+        let cache = self.cache.as_mut();
+        let len = cache.len();
+        cache.retain(|item| {
+            let keep = pred(item);
+            if let (false, Some(measurer)) = (keep, measurer) {
+                *heap_size = heap_size.saturating_sub(measurer(item));
+            }
+            keep
+        });
+        self.sorted.clear();
+        self.addresses.get_mut().clear();
+        let primary = self.primary.get_mut();
+        *primary = (*primary).min(cache.len());
+        let dropped = len - cache.len();
+        if dropped > 0 {
+            self.generation += 1;
+            self.reset_aliasing();
+        }
+        dropped
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Drop the cached items not matching `pred`, returning how many were dropped.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::retain_cached()`, and compacts the
+    /// cache the same way.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let mut reiterate = CopyReiterate::new(1..=10);
+    /// assert_eq!(reiterate.into_iter().take(6).count(), 6);
+    ///
+    /// assert_eq!(reiterate.retain_cached(|&i| i % 3 == 0), 4);
+    /// assert_eq!(reiterate.cache_size_bytes().ready(), Some(2 * 4));
+    /// assert_eq!(reiterate.into_iter().take(3).collect::<Vec<_>>(), [3, 6, 7]);
+    ///
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.catch_up(), 3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [8, 9, 10]);
+    /// ```
+    pub fn retain_cached(&mut self, mut pred: impl FnMut(&I::Item) -> bool) -> usize {
+        let cache = &mut self.inner.get_mut().cache;
+        let len = cache.len();
+        cache.retain(|item| pred(item));
+        self.sorted.clear();
+        let primary = self.primary.get_mut();
+        *primary = (*primary).min(cache.len());
+        let dropped = len - cache.len();
+        if dropped > 0 {
+            self.generation += 1;
+        }
+        dropped
+    }
+
+    /// Move the cached items into an allocation of their exact size, before a phase
+    /// that only replays them.
+    ///
+    /// The items of a `CopyReiterate` are stored in one contiguous block already, so this
+    /// only gives back the memory reserved for items not pulled yet. Indices are
+    /// unchanged. There is no `Reiterate` counterpart, since its items own their
+    /// allocations, which can't be merged into one block.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let mut reiterate = CopyReiterate::new(0..1000);
+    /// assert_eq!(reiterate.into_iter().count(), 1000);
+    /// reiterate.compact();
+    /// assert!(reiterate.into_iter().eq(0..1000));
+    /// ```
+    pub fn compact(&mut self) {
+        self.inner.get_mut().cache.shrink_to_fit();
+    }
+}
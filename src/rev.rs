@@ -0,0 +1,234 @@
+use crate::{ContentionAware, CopyReiterate, ReiterableItem, Reiterate};
+
+use std::cell::Ref;
+use std::iter::Rev;
+use std::ops::{Deref, Range};
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Iterate over the items cached so far, from either end, without pulling.
+    ///
+    /// The iterator covers the items that were cached when it was created.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(Box::new));
+    /// reiterate.into_iter().nth(4);
+    /// let mut cached = reiterate.iter_cached();
+    /// assert_eq!(cached.len(), 5);
+    /// assert_eq!(cached.next_back(), Some(&4));
+    /// assert_eq!(cached.next(), Some(&0));
+    /// assert_eq!(cached.collect::<Vec<_>>(), [&1, &2, &3]);
+    /// ```
+    pub fn iter_cached(&self) -> CachedReiterator<'_, I> {
+        CachedReiterator {
+            iterable: self,
+            range: 0..self.visible_len(),
+        }
+    }
+
+    /// Pull the rest of the items, then iterate over all of them back to front.
+    ///
+    /// This never returns for an endless source.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let log = Reiterate::new((1..=100).map(|i| format!("entry {}", i)));
+    /// let recent: Vec<_> = log.iter_rev().take(3).collect();
+    /// assert_eq!(recent, ["entry 100", "entry 99", "entry 98"]);
+    /// assert_eq!(log.cached_len(), 100);
+    /// ```
+    pub fn iter_rev(&self) -> Rev<CachedReiterator<'_, I>> {
+        self.exhaust();
+        self.iter_cached().rev()
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Iterate over the items cached so far, from either end, without pulling.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::iter_cached()`. Like pulling, it
+    /// panics if called during a pull.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("abcdef".chars());
+    /// reiterate.into_iter().nth(2);
+    /// assert_eq!(reiterate.iter_cached().rev().collect::<String>(), "cba");
+    /// ```
+    pub fn iter_cached(&self) -> CopyCachedReiterator<'_, I> {
+        self.stop_streaming();
+        CopyCachedReiterator {
+            iterable: self,
+            range: 0..self.visible_len(),
+        }
+    }
+
+    /// Borrow the items cached so far as a slice, for replay loops that don't need to
+    /// pull, or `Contended` during a pull.
+    ///
+    /// Handles can't pull while the slice is borrowed: one reaching the end of the cache
+    /// panics instead.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let samples = CopyReiterate::new((0..1000).map(|i| i as f64 / 10.));
+    /// samples.into_iter().nth(99);
+    /// for _ in 0..3 {
+    ///     let cached = samples.as_slice().ready().unwrap();
+    ///     assert_eq!(cached.len(), 100);
+    ///     assert_eq!(cached.iter().sum::<f64>(), 495.);
+    /// }
+    /// assert_eq!(samples.cached_len().ready(), Some(100));
+    /// ```
+    pub fn as_slice(&self) -> ContentionAware<Ref<'_, [I::Item]>> {
+        self.stop_streaming();
+        let limit = self.limit.get();
+        ContentionAware::borrow(&self.inner)
+            .map(|inner| Ref::map(inner, |inner| &inner.cache[..inner.cache.len().min(limit)]))
+    }
+
+    /// Pull the rest of the items, then iterate over all of them back to front.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::iter_rev()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(1..=5);
+    /// assert_eq!(reiterate.iter_rev().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+    /// ```
+    pub fn iter_rev(&self) -> Rev<CopyCachedReiterator<'_, I>> {
+        self.exhaust();
+        self.iter_cached().rev()
+    }
+}
+
+/// A double-ended iterator over the cached items of a `Reiterate`, produced by
+/// `iter_cached()`
+pub struct CachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    range: Range<usize>,
+}
+
+impl<'a, I> Iterator for CachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterable = self.iterable;
+        self.range.next().map(|idx| &iterable.cache[idx])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let iterable = self.iterable;
+        self.range.nth(n).map(|idx| &iterable.cache[idx])
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for CachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let iterable = self.iterable;
+        self.range.next_back().map(|idx| &iterable.cache[idx])
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let iterable = self.iterable;
+        self.range.nth_back(n).map(|idx| &iterable.cache[idx])
+    }
+}
+
+impl<'a, I> ExactSizeIterator for CachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+}
+
+/// A double-ended iterator over the cached items of a `CopyReiterate`, produced by
+/// `iter_cached()`
+pub struct CopyCachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a CopyReiterate<I>,
+    range: Range<usize>,
+}
+
+impl<'a, I> Iterator for CopyCachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let iterable = self.iterable;
+        self.range
+            .next()
+            .map(|idx| iterable.inner.borrow().cache[idx])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        let iterable = self.iterable;
+        self.range
+            .nth(n)
+            .map(|idx| iterable.inner.borrow().cache[idx])
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for CopyCachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        let iterable = self.iterable;
+        self.range
+            .next_back()
+            .map(|idx| iterable.inner.borrow().cache[idx])
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<I::Item> {
+        let iterable = self.iterable;
+        self.range
+            .nth_back(n)
+            .map(|idx| iterable.inner.borrow().cache[idx])
+    }
+}
+
+impl<'a, I> ExactSizeIterator for CopyCachedReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+}
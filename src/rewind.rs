@@ -0,0 +1,115 @@
+use crate::{CopyReiterator, ReiterableItem, Reiterator};
+
+impl<'a, I> Reiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// The position of the next item this handle yields, to `rewind_to()` later
+    pub fn mark(&self) -> usize {
+        self.curr
+    }
+
+    /// Move this handle back to the first item, to reuse it instead of creating a new one.
+    ///
+    /// Handles are two words and creating one never allocates, so this is the same as
+    /// `*self = reiterate.into_iter()`, without needing the `Reiterate` at hand.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c"]);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next(), Some("a"));
+    /// iter.rewind();
+    /// assert_eq!(iter.by_ref().collect::<String>(), "abc");
+    ///
+    /// // an exhausted handle starts over too
+    /// assert_eq!(iter.next(), None);
+    /// iter.rewind();
+    /// assert_eq!(iter.next(), Some("a"));
+    /// ```
+    pub fn rewind(&mut self) {
+        self.rewind_to(0);
+    }
+
+    /// Move this handle back, or forward, to `position`.
+    ///
+    /// Moving forward past the cached items pulls the items before `position`, like
+    /// `nth()`, and a position past the end of the stream, or past the logical length set
+    /// by `truncate()`, ends the handle.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(Box::new));
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.nth(2), Some(&2));
+    /// let mark = iter.mark();
+    /// assert_eq!(iter.by_ref().take(3).count(), 3);
+    ///
+    /// iter.rewind_to(mark);
+    /// assert_eq!(iter.next(), Some(&3));
+    /// iter.rewind_to(8);
+    /// assert_eq!(iter.next(), Some(&8));
+    ///
+    /// reiterate.truncate(5);
+    /// iter.rewind_to(mark);
+    /// assert_eq!(iter.copied().collect::<Vec<_>>(), [3, 4]);
+    /// ```
+    pub fn rewind_to(&mut self, position: usize) {
+        // Handles stay within the cache, so the rest of the way is pulled
+        self.curr = position.min(self.iterable.cache.len());
+        if position > self.curr {
+            self.nth(position - self.curr - 1);
+        }
+    }
+}
+
+impl<'a, I> CopyReiterator<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// The position of the next item this handle yields, to `rewind_to()` later.
+    ///
+    /// Positions need the cache, so this stops the first handle of a `lazy_caching()`
+    /// `CopyReiterate` from streaming, and the items it streamed have no position.
+    pub fn mark(&self) -> usize {
+        self.iterable.stop_streaming();
+        self.curr
+    }
+
+    /// Move this handle back to the first item, to reuse it instead of creating a new one.
+    ///
+    /// This is the `Copy` counterpart of `Reiterator::rewind()`. The first handle of a
+    /// `lazy_caching()` `CopyReiterate` stops streaming, and goes back to the first item
+    /// cached, like handles created later.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::lazy_caching(0..5);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.nth(1), Some(1));
+    /// iter.rewind();
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.by_ref().count(), 2);
+    /// iter.rewind();
+    /// assert_eq!(iter.collect::<Vec<_>>(), [2, 3, 4]);
+    /// ```
+    pub fn rewind(&mut self) {
+        self.rewind_to(0);
+    }
+
+    /// Move this handle back, or forward, to `position`.
+    ///
+    /// This is the `Copy` counterpart of `Reiterator::rewind_to()`.
+    pub fn rewind_to(&mut self, position: usize) {
+        self.iterable.stop_streaming();
+        self.curr = position.min(self.iterable.inner.borrow().cache.len());
+        if position > self.curr {
+            self.nth(position - self.curr - 1);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use rand::seq::IteratorRandom;
+use rand::Rng;
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Pick `n` distinct items uniformly at random, using reservoir sampling.
+    ///
+    /// Every item has to be seen to be given a fair chance, so this exhausts the
+    /// underlying iterator, caching everything along the way. Use `sample_cached()`
+    /// to only sample from the items cached so far.
+    ///
+    /// If there are fewer than `n` items, all of them are returned. The order of the
+    /// returned items is not specified.
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..100).map(Box::new));
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    /// let sample = reiterate.sample(&mut rng, 3);
+    /// assert_eq!(sample, [&5, &1, &81]);
+    ///
+    /// // the whole stream was cached by the sampling pass
+    /// assert_eq!(reiterate.iter_tail().count(), 0);
+    /// assert_eq!(reiterate.sample(&mut rng, 200).len(), 100);
+    /// ```
+    pub fn sample<R>(&self, rng: &mut R, n: usize) -> Vec<&<I::Item as Deref>::Target>
+    where
+        R: Rng + ?Sized,
+    {
+        self.into_iter().choose_multiple(rng, n)
+    }
+
+    /// Pick a single item uniformly at random.
+    ///
+    /// Like `sample()`, this exhausts the underlying iterator.
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    /// use reiterate::Reiterate;
+    ///
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c", "d"]);
+    /// assert_eq!(reiterate.choose(&mut rng), Some("a"));
+    ///
+    /// let empty = Reiterate::new(Vec::<Box<u32>>::new());
+    /// assert_eq!(empty.choose(&mut rng), None);
+    /// ```
+    pub fn choose<R>(&self, rng: &mut R) -> Option<&<I::Item as Deref>::Target>
+    where
+        R: Rng + ?Sized,
+    {
+        self.iter_tail().for_each(drop);
+        match self.visible_len() {
+            0 => None,
+            len => self.cache.get(rng.random_range(0..len)),
+        }
+    }
+
+    /// Pick `n` distinct items uniformly at random from the items cached so far,
+    /// without pulling from the underlying iterator.
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..100).map(Box::new));
+    /// reiterate.into_iter().take(10).for_each(drop);
+    ///
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    /// let sample = reiterate.sample_cached(&mut rng, 3);
+    /// assert_eq!(sample, [&5, &1, &2]);
+    /// assert_eq!(reiterate.iter_tail().next(), Some(&10));
+    /// ```
+    pub fn sample_cached<R>(&self, rng: &mut R, n: usize) -> Vec<&<I::Item as Deref>::Target>
+    where
+        R: Rng + ?Sized,
+    {
+        self.cache
+            .iter()
+            .take(self.visible_len())
+            .choose_multiple(rng, n)
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Pick `n` distinct items uniformly at random, using reservoir sampling.
+    ///
+    /// Every item has to be seen to be given a fair chance, so this exhausts the
+    /// underlying iterator, caching everything along the way. Use `sample_cached()`
+    /// to only sample from the items cached so far.
+    ///
+    /// If there are fewer than `n` items, all of them are returned. The order of the
+    /// returned items is not specified.
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(0..100);
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    /// assert_eq!(reiterate.sample(&mut rng, 3), [5, 1, 81]);
+    /// assert_eq!(reiterate.iter_tail().count(), 0);
+    /// ```
+    pub fn sample<R>(&self, rng: &mut R, n: usize) -> Vec<I::Item>
+    where
+        R: Rng + ?Sized,
+    {
+        self.into_iter().choose_multiple(rng, n)
+    }
+
+    /// Pick a single item uniformly at random.
+    ///
+    /// Like `sample()`, this exhausts the underlying iterator.
+    pub fn choose<R>(&self, rng: &mut R) -> Option<I::Item>
+    where
+        R: Rng + ?Sized,
+    {
+        self.iter_tail().for_each(drop);
+        let len = self.visible_len();
+        let inner = self.inner.borrow();
+        match len {
+            0 => None,
+            len => Some(inner.cache[rng.random_range(0..len)]),
+        }
+    }
+
+    /// Pick `n` distinct items uniformly at random from the items cached so far,
+    /// without pulling from the underlying iterator.
+    pub fn sample_cached<R>(&self, rng: &mut R, n: usize) -> Vec<I::Item>
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.visible_len();
+        let inner = self.inner.borrow();
+        inner.cache[..len].iter().copied().choose_multiple(rng, n)
+    }
+}
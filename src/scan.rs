@@ -0,0 +1,192 @@
+use crate::Reiterable;
+
+use elsa::FrozenVec;
+
+use stable_deref_trait::StableDeref;
+use std::cell::RefCell;
+use std::ops::Deref;
+
+/// An adaptor around an iterator that caches a running fold state alongside each item,
+/// and can produce multiple iterators sharing the cache.
+///
+/// The fold function is called exactly once per item of the underlying iterator, with the
+/// state after the previous item (or the initial state) and the new item. Iterators yield
+/// each item together with the state after it.
+///
+/// ```rust
+/// use reiterate::ScanReiterate;
+///
+/// let chunks = vec![b"abc".to_vec(), b"de".to_vec(), b"fghi".to_vec()];
+/// // cumulative end offset of each chunk
+/// let reiterate = ScanReiterate::new(chunks, 0, |end: &usize, chunk: &[u8]| end + chunk.len());
+///
+/// let ends: Vec<_> = reiterate.iter().map(|(_, end)| *end).collect();
+/// assert_eq!(ends, [3, 5, 9]);
+///
+/// // which chunk contains byte 4?
+/// let chunk = reiterate.partition_point(|&end| end <= 4);
+/// assert_eq!(chunk, 1);
+/// assert_eq!(reiterate.iter().nth(chunk).unwrap().0, b"de");
+/// assert_eq!(reiterate.state_at(2), Some(&9));
+/// assert_eq!(reiterate.state_at(3), None);
+/// ```
+///
+/// Replaying never reruns the fold:
+///
+/// ```rust
+/// use reiterate::ScanReiterate;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let x = vec![1, 2, 3, 4].into_iter().map(Box::new);
+/// let reiterate = ScanReiterate::new(x, 0, |sum: &i32, i: &i32| {
+///     calls.set(calls.get() + 1);
+///     sum + i
+/// });
+/// for _ in 0..3 {
+///     let totals: Vec<_> = reiterate.iter().map(|(_, sum)| *sum).collect();
+///     assert_eq!(totals, [1, 3, 6, 10]);
+/// }
+/// assert_eq!(calls.get(), 4);
+/// ```
+pub struct ScanReiterate<I, St, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: FnMut(&St, &<I::Item as Deref>::Target) -> St,
+{
+    inner: RefCell<ScanReiterateInner<I, F>>,
+    init: St,
+    items: FrozenVec<I::Item>,
+    states: FrozenVec<Box<St>>,
+}
+
+struct ScanReiterateInner<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, St, F> ScanReiterate<I, St, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: FnMut(&St, &<I::Item as Deref>::Target) -> St,
+{
+    pub fn new<T>(iter: T, init: St, f: F) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        ScanReiterate {
+            inner: RefCell::new(ScanReiterateInner {
+                iter: iter.into_iter(),
+                f,
+            }),
+            init,
+            items: FrozenVec::new(),
+            states: FrozenVec::new(),
+        }
+    }
+
+    /// Get an iterator over the items and the states after them, reusing cached values
+    pub fn iter(&self) -> ScanReiterator<'_, I, St, F> {
+        ScanReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+
+    /// Get the fold state after the item at `index`, pulling from the underlying
+    /// iterator if it hasn't been reached yet.
+    pub fn state_at(&self, index: usize) -> Option<&St> {
+        self.get(index).map(|(_, state)| state)
+    }
+
+    /// Returns the index of the first cached state for which `pred` is false,
+    /// assuming the cached states are partitioned according to `pred`.
+    ///
+    /// Only the cached states are looked at, nothing is pulled from the underlying iterator.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&St) -> bool,
+    {
+        self.states.partition_point(pred)
+    }
+
+    fn get(&self, index: usize) -> Option<(&<I::Item as Deref>::Target, &St)> {
+        while self.items.len() <= index {
+            self.pull()?;
+        }
+        Some((self.items.get(index)?, self.states.get(index)?))
+    }
+
+    fn pull(&self) -> Option<()> {
+        let mut inner = self.inner.borrow_mut();
+        let item = inner.iter.next()?;
+        let prev = self.states.last().unwrap_or(&self.init);
+        let state = (inner.f)(prev, &item);
+        drop(inner);
+        self.items.push(item);
+        self.states.push(Box::new(state));
+        Some(())
+    }
+}
+
+impl<'a, I, St, F> IntoIterator for &'a ScanReiterate<I, St, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: FnMut(&St, &<I::Item as Deref>::Target) -> St,
+{
+    type IntoIter = ScanReiterator<'a, I, St, F>;
+    type Item = (&'a <I::Item as Deref>::Target, &'a St);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I, St, F> Reiterable for ScanReiterate<I, St, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: FnMut(&St, &<I::Item as Deref>::Target) -> St,
+{
+    type Item<'a>
+        = (&'a <I::Item as Deref>::Target, &'a St)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = ScanReiterator<'a, I, St, F>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> ScanReiterator<'_, I, St, F> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.iter()` on a `ScanReiterate`
+pub struct ScanReiterator<'a, I, St, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: FnMut(&St, &<I::Item as Deref>::Target) -> St,
+{
+    iterable: &'a ScanReiterate<I, St, F>,
+    curr: usize,
+}
+
+impl<'a, I, St, F> Iterator for ScanReiterator<'a, I, St, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: FnMut(&St, &<I::Item as Deref>::Target) -> St,
+{
+    type Item = (&'a <I::Item as Deref>::Target, &'a St);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(item)
+    }
+}
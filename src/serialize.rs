@@ -0,0 +1,94 @@
+use crate::{CloneReiterate, CopyReiterate};
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Error, Serialize, Serializer};
+
+/// Serialize the cached items as a sequence, to be resumed with
+/// `CopyReiterate::from_serialized()`.
+///
+/// Only the items up to the length set by `truncate()` are serialized, and the underlying
+/// iterator isn't. Serializing fails during a pull.
+///
+/// ```rust
+/// use reiterate::CopyReiterate;
+///
+/// // an expensive source, half consumed
+/// let primes = CopyReiterate::new((2u64..).filter(|n| (2..*n).all(|d| n % d != 0)));
+/// primes.into_iter().nth(9);
+/// let checkpoint = serde_json::to_string(&primes).unwrap();
+/// assert_eq!(checkpoint, "[2,3,5,7,11,13,17,19,23,29]");
+///
+/// // later, resume with the source picking up after the cached items
+/// let mut de = serde_json::Deserializer::from_str(&checkpoint);
+/// let rest = (30u64..).filter(|n| (2..*n).all(|d| n % d != 0));
+/// let resumed = CopyReiterate::from_serialized(&mut de, rest).unwrap();
+/// assert_eq!(resumed.cached_len().ready(), Some(10));
+/// assert_eq!(resumed.get(10), Some(31));
+/// ```
+impl<I> Serialize for CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Serializing after a pull is the same as serializing at the start of the next one
+        self.stop_streaming();
+        let inner = self
+            .inner
+            .try_borrow()
+            .map_err(|_| S::Error::custom("the CopyReiterate is being pulled from"))?;
+        let len = inner.cache.len().min(self.limit.get());
+        serializer.collect_seq(&inner.cache[..len])
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// Create a `CopyReiterate` from items serialized earlier, and the iterator to pull the
+    /// following items from, like `from_parts()`.
+    ///
+    /// The iterator has to pick up where the serialized one left off.
+    pub fn from_serialized<'de, D, T>(deserializer: D, iter: T) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+        I::Item: Deserialize<'de>,
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let cache = Vec::deserialize(deserializer)?;
+        Ok(CopyReiterate::from_parts(cache, iter))
+    }
+}
+
+impl<I> CloneReiterate<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Create a `CloneReiterate` from items serialized earlier, and the iterator to pull
+    /// the following items from, like `from_parts()`.
+    ///
+    /// ```rust
+    /// use reiterate::CloneReiterate;
+    ///
+    /// let names = CloneReiterate::new(vec!["ada".to_string(), "grace".to_string(), "edsger".to_string()]);
+    /// names.into_iter().nth(1);
+    /// let checkpoint = serde_json::to_string(&names).unwrap();
+    /// assert_eq!(checkpoint, r#"["ada","grace"]"#);
+    ///
+    /// let mut de = serde_json::Deserializer::from_str(&checkpoint);
+    /// let resumed = CloneReiterate::from_serialized(&mut de, vec!["barbara".to_string()]).unwrap();
+    /// assert_eq!(resumed.into_iter().collect::<Vec<_>>(), ["ada", "grace", "barbara"]);
+    /// ```
+    pub fn from_serialized<'de, D, T>(deserializer: D, iter: T) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+        I::Item: Deserialize<'de>,
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let cache = Vec::deserialize(deserializer)?;
+        Ok(CloneReiterate::from_parts(cache, iter))
+    }
+}
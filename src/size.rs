@@ -0,0 +1,77 @@
+use crate::{ContentionAware, CopyReiterate, ReiterableItem, Reiterate};
+
+use std::mem;
+
+/// Reports the heap size of an item, for `Reiterate::cache_size_bytes()`
+pub(crate) type Measurer<T> = fn(&T) -> usize;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Create a `Reiterate` that uses `measurer` to estimate the heap size of each item
+    /// in `cache_size_bytes()`, e.g. `String::capacity` for `String`s.
+    pub fn with_measurer<T>(iter: T, measurer: Measurer<I::Item>) -> Self
+    where
+        I: Sized,
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Reiterate {
+            measurer: Some(measurer),
+            ..Reiterate::new(iter)
+        }
+    }
+
+    /// A best-effort estimate of the memory used by the cached items.
+    ///
+    /// This counts the inline size of each cached item, plus the heap size of each item
+    /// as reported by the measurer given to `with_measurer()`, if any. Items are measured
+    /// once, as they get cached, so this is O(1).
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::mem::size_of;
+    ///
+    /// let words = vec!["one".to_string(), "three".to_string(), "sixteen".to_string()];
+    /// let reiterate = Reiterate::with_measurer(words, String::len);
+    /// assert_eq!(reiterate.cache_size_bytes(), 0);
+    ///
+    /// reiterate.into_iter().take(2).for_each(drop);
+    /// assert_eq!(reiterate.cache_size_bytes(), 2 * size_of::<String>() + 8);
+    ///
+    /// reiterate.into_iter().for_each(drop);
+    /// assert_eq!(reiterate.cache_size_bytes(), 3 * size_of::<String>() + 15);
+    ///
+    /// // without a measurer, only the inline size is counted
+    /// let shallow = Reiterate::new(vec!["one".to_string()]);
+    /// shallow.into_iter().for_each(drop);
+    /// assert_eq!(shallow.cache_size_bytes(), size_of::<String>());
+    /// ```
+    pub fn cache_size_bytes(&self) -> usize {
+        mem::size_of::<I::Item>() * self.cache.len() + self.heap_size.get()
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// The memory used by the cached items.
+    ///
+    /// The cache is kept next to the underlying iterator, so this is `Contended` during
+    /// a pull.
+    ///
+    /// ```rust
+    /// use reiterate::{ContentionAware, CopyReiterate};
+    ///
+    /// let reiterate = CopyReiterate::new(0..10u32);
+    /// reiterate.into_iter().take(5).for_each(drop);
+    /// assert_eq!(reiterate.cache_size_bytes(), ContentionAware::Ready(20));
+    /// ```
+    pub fn cache_size_bytes(&self) -> ContentionAware<usize> {
+        ContentionAware::borrow(&self.inner)
+            .map(|inner| mem::size_of::<I::Item>() * inner.cache.len())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{ContentionAware, ReiterableItem, Reiterate};
+
+use std::cell::Ref;
+
+/// An iterator over the `Ok` items of a fallible source that keeps the errors aside, used
+/// as the source of `Reiterate::skip_errors`.
+pub struct SkipErrors<I, E> {
+    iter: I,
+    // The number of `Ok` items yielded so far
+    good: usize,
+    ended: bool,
+    errors: Vec<(usize, E)>,
+}
+
+impl<I, E> SkipErrors<I, E> {
+    /// The errors encountered so far, along with the position of the item that follows
+    /// them
+    pub fn errors(&self) -> &[(usize, E)] {
+        &self.errors
+    }
+}
+
+impl<I, T, E> Iterator for SkipErrors<I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while !self.ended {
+            match self.iter.next() {
+                Some(Ok(val)) => {
+                    self.good += 1;
+                    return Some(val);
+                }
+                Some(Err(err)) => self.errors.push((self.good, err)),
+                None => self.ended = true,
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.ended {
+            return (0, Some(0));
+        }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T, E> Reiterate<SkipErrors<I, E>>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: ReiterableItem,
+{
+    /// Create a `Reiterate` over the `Ok` items of a fallible source, for errors that only
+    /// affect the item they replace.
+    ///
+    /// The errors are kept in `errors()` rather than cached, so that a later pass can
+    /// report them. Each one is recorded with the position in the `Reiterate` of the `Ok`
+    /// item that follows it, which is the number of cached items for errors at the end.
+    /// The source is fused: once it returns `None`, it isn't pulled from again.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let records = vec![Err("bad header"), Ok(Box::new(1)), Err("bad row"), Err("bad row"), Ok(Box::new(2)), Err("truncated")];
+    /// let reiterate = Reiterate::skip_errors(records);
+    /// for _ in 0..2 {
+    ///     assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [1, 2]);
+    /// }
+    /// assert_eq!(reiterate.source_ended().ready(), Some(true));
+    /// assert_eq!(&*reiterate.errors().ready().unwrap(), [(0, "bad header"), (1, "bad row"), (1, "bad row"), (2, "truncated")]);
+    /// ```
+    ///
+    /// Errors are recorded as the items are pulled:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::skip_errors(vec![Ok(Box::new('a')), Err(1), Ok(Box::new('b'))]);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.next(), Some(&'a'));
+    /// assert!(reiterate.errors().ready().unwrap().is_empty());
+    /// assert_eq!(iter.next(), Some(&'b'));
+    /// assert_eq!(&*reiterate.errors().ready().unwrap(), [(1, 1)]);
+    /// assert_eq!(reiterate.source_ended().ready(), Some(false));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(reiterate.source_ended().ready(), Some(true));
+    ///
+    /// let failed = Reiterate::skip_errors(vec![Err::<Box<u8>, _>("no"), Err("nope")]);
+    /// assert_eq!(failed.into_iter().count(), 0);
+    /// assert_eq!(&*failed.errors().ready().unwrap(), [(0, "no"), (0, "nope")]);
+    /// ```
+    pub fn skip_errors<S>(iter: S) -> Self
+    where
+        S: IntoIterator<Item = Result<T, E>, IntoIter = I>,
+    {
+        Reiterate::new(SkipErrors {
+            iter: iter.into_iter(),
+            good: 0,
+            ended: false,
+            errors: Vec::new(),
+        })
+    }
+
+    /// The errors encountered so far, along with the position of the item that follows
+    /// them, or `Contended` during a pull
+    pub fn errors(&self) -> ContentionAware<Ref<'_, [(usize, E)]>> {
+        ContentionAware::borrow(&self.iter).map(|iter| Ref::map(iter, |iter| iter.errors()))
+    }
+
+    /// Whether the source has ended, as opposed to the items pulled so far having run out,
+    /// or `Contended` during a pull
+    pub fn source_ended(&self) -> ContentionAware<bool> {
+        ContentionAware::borrow(&self.iter).map(|iter| iter.ended)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::CopyReiterate;
+
+use bytemuck::Pod;
+use std::convert::TryFrom;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::vec;
+
+const MAGIC: [u8; 4] = *b"RSNP";
+const VERSION: u16 = 1;
+// magic, version, header length, byte order, 3 reserved bytes, element size, count
+const HEADER_LEN: u16 = 24;
+const LITTLE_ENDIAN: u8 = 0;
+const BIG_ENDIAN: u8 = 1;
+// Items are read in batches of this many bytes, so that a corrupted count can't make
+// `read_snapshot()` allocate more than the input holds
+const READ_BATCH: usize = 64 * 1024;
+
+fn native_byte_order() -> u8 {
+    if cfg!(target_endian = "little") {
+        LITTLE_ENDIAN
+    } else {
+        BIG_ENDIAN
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Pod,
+{
+    /// Exhaust the underlying iterator and write the cached items to `w` as raw bytes,
+    /// after a header describing them. Returns the number of bytes written.
+    ///
+    /// The header is made of little-endian fields: the magic bytes `RSNP`, a `u16`
+    /// format version, the `u16` length of the header, a byte for the byte order of the
+    /// items (0 for little-endian, 1 for big-endian), 3 reserved bytes, the `u32` size
+    /// of an item and the `u64` number of items. Readers skip any header bytes past the
+    /// fields they know about, so that later versions can add fields.
+    ///
+    /// Items streamed through by a `lazy_caching()` handle aren't cached, so they aren't
+    /// part of the snapshot.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new((0..1000u32).map(|i| i * i));
+    /// let mut bytes = Vec::new();
+    /// assert_eq!(reiterate.write_snapshot(&mut bytes).unwrap(), 24 + 4000);
+    ///
+    /// let restored = CopyReiterate::<std::vec::IntoIter<u32>>::read_snapshot(&bytes[..]).unwrap();
+    /// assert!(restored.into_iter().eq(reiterate.into_iter()));
+    ///
+    /// // the item size is checked
+    /// let err = CopyReiterate::<std::vec::IntoIter<u64>>::read_snapshot(&bytes[..]).err().unwrap();
+    /// assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    /// ```
+    ///
+    /// Any `Pod` type can be snapshotted:
+    ///
+    /// ```rust
+    /// use bytemuck::{Pod, Zeroable};
+    /// use reiterate::CopyReiterate;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// struct Point {
+    ///     x: f32,
+    ///     y: f32,
+    ///     id: u32,
+    /// }
+    /// unsafe impl Zeroable for Point {}
+    /// unsafe impl Pod for Point {}
+    ///
+    /// let points = CopyReiterate::new((0..3).map(|i| Point { x: i as f32, y: -1.5, id: i }));
+    /// let mut bytes = Vec::new();
+    /// points.write_snapshot(&mut bytes).unwrap();
+    ///
+    /// let restored = CopyReiterate::<std::vec::IntoIter<Point>>::read_snapshot(&bytes[..]).unwrap();
+    /// assert_eq!(restored.into_iter().collect_vec(), points.into_iter().collect_vec());
+    /// ```
+    pub fn write_snapshot(&self, mut w: impl Write) -> io::Result<u64> {
+        self.iter_tail().for_each(drop);
+        let len = self.visible_len();
+        let inner = self.inner.borrow();
+        let bytes: &[u8] = bytemuck::cast_slice(&inner.cache[..len]);
+        let elem_size = u32::try_from(mem::size_of::<I::Item>())
+            .map_err(|_| invalid("items are too large to snapshot"))?;
+
+        let mut header = Vec::with_capacity(HEADER_LEN.into());
+        header.extend_from_slice(&MAGIC);
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        header.extend_from_slice(&HEADER_LEN.to_le_bytes());
+        header.push(native_byte_order());
+        header.extend_from_slice(&[0; 3]);
+        header.extend_from_slice(&elem_size.to_le_bytes());
+        header.extend_from_slice(&(len as u64).to_le_bytes());
+        debug_assert_eq!(header.len(), usize::from(HEADER_LEN));
+
+        w.write_all(&header)?;
+        w.write_all(bytes)?;
+        Ok(header.len() as u64 + bytes.len() as u64)
+    }
+}
+
+impl<T> CopyReiterate<vec::IntoIter<T>>
+where
+    T: Pod,
+{
+    /// Read a snapshot written by `write_snapshot()`, into a `CopyReiterate` that has
+    /// every item cached already.
+    ///
+    /// Snapshots whose header is malformed, whose version is newer, or whose items have
+    /// another size or byte order than `T` are rejected with `InvalidData`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::io::ErrorKind;
+    ///
+    /// type Restored = CopyReiterate<std::vec::IntoIter<u32>>;
+    ///
+    /// let mut bytes = Vec::new();
+    /// CopyReiterate::new(vec![7u32, 8, 9]).write_snapshot(&mut bytes).unwrap();
+    ///
+    /// let mut bad_magic = bytes.clone();
+    /// bad_magic[0] = b'X';
+    /// assert_eq!(Restored::read_snapshot(&bad_magic[..]).err().unwrap().kind(), ErrorKind::InvalidData);
+    ///
+    /// let mut newer = bytes.clone();
+    /// newer[4] = 2;
+    /// assert_eq!(Restored::read_snapshot(&newer[..]).err().unwrap().kind(), ErrorKind::InvalidData);
+    ///
+    /// // a count larger than the data fails without allocating for it
+    /// let mut huge = bytes.clone();
+    /// huge[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    /// assert!(Restored::read_snapshot(&huge[..]).is_err());
+    ///
+    /// assert_eq!(Restored::read_snapshot(&bytes[..bytes.len() - 1]).err().unwrap().kind(), ErrorKind::UnexpectedEof);
+    ///
+    /// // an empty stream round-trips too
+    /// let mut empty = Vec::new();
+    /// CopyReiterate::new(Vec::<u32>::new()).write_snapshot(&mut empty).unwrap();
+    /// assert_eq!(Restored::read_snapshot(&empty[..]).unwrap().into_iter().count(), 0);
+    /// ```
+    pub fn read_snapshot(mut r: impl Read) -> io::Result<Self> {
+        let mut prefix = [0; 8];
+        r.read_exact(&mut prefix)?;
+        if prefix[..4] != MAGIC {
+            return Err(invalid("not a snapshot"));
+        }
+        let version = u16::from_le_bytes([prefix[4], prefix[5]]);
+        if version == 0 || version > VERSION {
+            return Err(invalid("unsupported snapshot version"));
+        }
+        let header_len = u16::from_le_bytes([prefix[6], prefix[7]]);
+        if header_len < HEADER_LEN {
+            return Err(invalid("snapshot header is too short"));
+        }
+        let mut header = vec![0; usize::from(header_len) - prefix.len()];
+        r.read_exact(&mut header)?;
+        if header[0] != native_byte_order() {
+            return Err(invalid("snapshot has another byte order"));
+        }
+        let elem_size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        if u32::try_from(mem::size_of::<T>()).ok() != Some(elem_size) {
+            return Err(invalid("snapshot has another item size"));
+        }
+        let mut count = [0; 8];
+        count.copy_from_slice(&header[8..16]);
+        let count = usize::try_from(u64::from_le_bytes(count))
+            .map_err(|_| invalid("snapshot is too large"))?;
+
+        let batch = match mem::size_of::<T>() {
+            0 => count,
+            size => (READ_BATCH / size).max(1),
+        };
+        let mut items: Vec<T> = Vec::new();
+        while items.len() < count {
+            let start = items.len();
+            items.resize(start + batch.min(count - start), T::zeroed());
+            r.read_exact(bytemuck::cast_slice_mut(&mut items[start..]))?;
+        }
+
+        let mut reiterate = CopyReiterate::new(Vec::new());
+        reiterate.inner.get_mut().cache = items;
+        Ok(reiterate)
+    }
+}
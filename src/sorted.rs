@@ -0,0 +1,382 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A permutation of the cached items in sorted order
+pub(crate) struct SortedIndex {
+    // Cache indices in sorted order
+    perm: Vec<u32>,
+    // The position of each cache index in `perm`
+    ranks: Vec<u32>,
+}
+
+impl SortedIndex {
+    fn new(len: usize, mut cmp: impl FnMut(usize, usize) -> Ordering) -> Self {
+        let len = u32::try_from(len).expect("too many items to sort");
+        let mut perm: Vec<u32> = (0..len).collect();
+        // Stable, so that equal items stay in stream order
+        perm.sort_by(|&a, &b| cmp(a as usize, b as usize));
+        let mut ranks = vec![0; perm.len()];
+        for (rank, &idx) in perm.iter().enumerate() {
+            ranks[idx as usize] = rank as u32;
+        }
+        SortedIndex { perm, ranks }
+    }
+
+    fn len(&self) -> usize {
+        self.perm.len()
+    }
+
+    fn index(&self, rank: usize) -> Option<usize> {
+        self.perm.get(rank).map(|&idx| idx as usize)
+    }
+
+    fn rank(&self, index: usize) -> Option<usize> {
+        self.ranks.get(index).map(|&rank| rank as usize)
+    }
+
+    fn binary_search_by(&self, mut f: impl FnMut(usize) -> Ordering) -> Result<usize, usize> {
+        self.perm.binary_search_by(|&idx| f(idx as usize))
+    }
+}
+
+/// The sorted order remembered by `sorted_view()`.
+///
+/// Only the latest one is kept, and views share it, so an order computed before more
+/// items got cached is freed along with the last view using it.
+#[derive(Default)]
+pub(crate) struct SortedSlot(RefCell<Option<Arc<SortedIndex>>>);
+
+impl SortedSlot {
+    /// Get the sorted order of the first `len` cached items, computing it if the one
+    /// remembered is for a different number of items
+    fn get_or_sort(
+        &self,
+        len: usize,
+        cmp: impl FnMut(usize, usize) -> Ordering,
+    ) -> Arc<SortedIndex> {
+        if let Some(index) = &*self.0.borrow() {
+            if index.len() == len {
+                return index.clone();
+            }
+        }
+        // Not borrowed while sorting, in case the comparisons look at the cache's views
+        let index = Arc::new(SortedIndex::new(len, cmp));
+        *self.0.borrow_mut() = Some(index.clone());
+        index
+    }
+
+    /// Forget the sorted order, after the cache has been rewritten
+    pub(crate) fn clear(&mut self) {
+        *self.0.get_mut() = None;
+    }
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Exhaust the underlying iterator and get a view of the items in sorted order.
+    ///
+    /// The sorted order is computed once and remembered, so calling this again is cheap.
+    /// If more items have been cached since, e.g. because the underlying iterator
+    /// produced more items after having returned `None`, the order is recomputed to
+    /// include them. Views obtained before that keep covering the items they were built
+    /// for, and only the latest order is remembered, so the previous one is freed once no
+    /// view uses it.
+    ///
+    /// Equal items keep their stream order.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["c", "a", "d", "b"]);
+    /// let sorted = reiterate.sorted_view();
+    /// assert_eq!(sorted.iter().collect::<Vec<_>>(), ["a", "b", "c", "d"]);
+    /// // "c" is at index 0 of the stream, and comes third in sorted order
+    /// assert_eq!(sorted.rank(0), Some(2));
+    /// assert_eq!(sorted.binary_search("d"), Ok(3));
+    /// assert_eq!(sorted.binary_search("bb"), Err(2));
+    ///
+    /// // insertion order is still available
+    /// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), ["c", "a", "d", "b"]);
+    /// ```
+    ///
+    /// The view is rebuilt if more items get cached:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// // produces 3, 1, then ends, then produces 2 when asked again
+    /// let mut items = vec![Some(2), None, Some(1), Some(3)];
+    /// let reiterate = Reiterate::new(std::iter::from_fn(|| items.pop().flatten().map(Box::new)));
+    ///
+    /// let before = reiterate.sorted_view();
+    /// assert_eq!(before.iter().collect::<Vec<_>>(), [&1, &3]);
+    ///
+    /// // exhausting the iterator again picks up the late item
+    /// let after = reiterate.sorted_view();
+    /// assert_eq!(after.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    /// assert_eq!(before.iter().collect::<Vec<_>>(), [&1, &3]);
+    /// ```
+    pub fn sorted_view(&self) -> SortedView<'_, I>
+    where
+        <I::Item as Deref>::Target: Ord,
+    {
+        self.iter_tail().for_each(drop);
+        let len = self.visible_len();
+        let index = self
+            .sorted
+            .get_or_sort(len, |a, b| self.cache[a].cmp(&self.cache[b]));
+        SortedView {
+            iterable: self,
+            index,
+        }
+    }
+
+    /// Exhaust the underlying iterator and get a view of the items sorted by a key.
+    ///
+    /// Unlike `sorted_view()`, the order isn't remembered, since it depends on `key`.
+    /// Equal keys keep their stream order.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["ccc", "a", "bb"]);
+    /// let sorted = reiterate.sorted_view_by_key(|s| s.len());
+    /// assert_eq!(sorted.iter().collect::<Vec<_>>(), ["a", "bb", "ccc"]);
+    /// assert_eq!(sorted.binary_search_by_key(&2, |s| s.len()), Ok(1));
+    /// ```
+    pub fn sorted_view_by_key<K, F>(&self, mut key: F) -> SortedView<'_, I>
+    where
+        K: Ord,
+        F: FnMut(&<I::Item as Deref>::Target) -> K,
+    {
+        self.iter_tail().for_each(drop);
+        let index = SortedIndex::new(self.visible_len(), |a, b| {
+            key(&self.cache[a]).cmp(&key(&self.cache[b]))
+        });
+        SortedView {
+            iterable: self,
+            index: Arc::new(index),
+        }
+    }
+}
+
+/// A view of the items of a `Reiterate` in sorted order, produced by `sorted_view()`
+pub struct SortedView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    iterable: &'a Reiterate<I>,
+    index: Arc<SortedIndex>,
+}
+
+impl<'a, I> SortedView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// The number of items in the view
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the item at position `rank` in sorted order
+    pub fn get(&self, rank: usize) -> Option<&'a <I::Item as Deref>::Target> {
+        self.iterable.cache.get(self.index.index(rank)?)
+    }
+
+    /// Get the position in sorted order of the item at `index` in the stream
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        self.index.rank(index)
+    }
+
+    /// Iterate over the items in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = &'a <I::Item as Deref>::Target> + '_ {
+        let (iterable, index) = (self.iterable, &*self.index);
+        (0..index.len()).map(move |rank| &iterable.cache[index.index(rank).unwrap()])
+    }
+
+    /// Binary search for an item, returning its position in sorted order.
+    ///
+    /// This is only meaningful for views from `sorted_view()`, see
+    /// `binary_search_by_key()` for views sorted by a key.
+    pub fn binary_search(&self, item: &<I::Item as Deref>::Target) -> Result<usize, usize>
+    where
+        <I::Item as Deref>::Target: Ord,
+    {
+        self.binary_search_by(|probe| probe.cmp(item))
+    }
+
+    /// Binary search for a key, returning the position in sorted order of an item with that key
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&<I::Item as Deref>::Target) -> K,
+    {
+        self.binary_search_by(|probe| f(probe).cmp(key))
+    }
+
+    /// Binary search with a comparator, returning a position in sorted order
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&<I::Item as Deref>::Target) -> Ordering,
+    {
+        let cache = &self.iterable.cache;
+        self.index.binary_search_by(|idx| f(&cache[idx]))
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Exhaust the underlying iterator and get a view of the items in sorted order.
+    ///
+    /// The sorted order is computed once and remembered, so calling this again is cheap.
+    /// If more items have been cached since, e.g. because the underlying iterator
+    /// produced more items after having returned `None`, the order is recomputed to
+    /// include them. Views obtained before that keep covering the items they were built
+    /// for, and only the latest order is remembered, so the previous one is freed once no
+    /// view uses it.
+    ///
+    /// Equal items keep their stream order.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(vec![30, 10, 40, 20]);
+    /// let sorted = reiterate.sorted_view();
+    /// assert_eq!(sorted.iter().collect::<Vec<_>>(), [10, 20, 30, 40]);
+    /// assert_eq!(sorted.rank(0), Some(2));
+    /// assert_eq!(sorted.binary_search(&40), Ok(3));
+    /// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), [30, 10, 40, 20]);
+    /// ```
+    pub fn sorted_view(&self) -> CopySortedView<'_, I>
+    where
+        I::Item: Ord,
+    {
+        self.iter_tail().for_each(drop);
+        let len = self.visible_len();
+        let inner = self.inner.borrow();
+        let cache = &inner.cache;
+        let index = self.sorted.get_or_sort(len, |a, b| cache[a].cmp(&cache[b]));
+        CopySortedView {
+            iterable: self,
+            index,
+        }
+    }
+
+    /// Exhaust the underlying iterator and get a view of the items sorted by a key.
+    ///
+    /// Unlike `sorted_view()`, the order isn't remembered, since it depends on `key`.
+    /// Equal keys keep their stream order.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(vec![-3, 1, -2]);
+    /// let sorted = reiterate.sorted_view_by_key(|i: &i32| i.abs());
+    /// assert_eq!(sorted.iter().collect::<Vec<_>>(), [1, -2, -3]);
+    /// ```
+    pub fn sorted_view_by_key<K, F>(&self, mut key: F) -> CopySortedView<'_, I>
+    where
+        K: Ord,
+        F: FnMut(&I::Item) -> K,
+    {
+        self.iter_tail().for_each(drop);
+        let len = self.visible_len();
+        let inner = self.inner.borrow();
+        let cache = &inner.cache;
+        let index = SortedIndex::new(len, |a, b| key(&cache[a]).cmp(&key(&cache[b])));
+        CopySortedView {
+            iterable: self,
+            index: Arc::new(index),
+        }
+    }
+}
+
+/// A view of the items of a `CopyReiterate` in sorted order, produced by `sorted_view()`
+pub struct CopySortedView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    iterable: &'a CopyReiterate<I>,
+    index: Arc<SortedIndex>,
+}
+
+impl<'a, I> CopySortedView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// The number of items in the view
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the item at position `rank` in sorted order
+    pub fn get(&self, rank: usize) -> Option<I::Item> {
+        let idx = self.index.index(rank)?;
+        Some(self.iterable.inner.borrow().cache[idx])
+    }
+
+    /// Get the position in sorted order of the item at `index` in the stream
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        self.index.rank(index)
+    }
+
+    /// Iterate over the items in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = I::Item> + '_ {
+        let (iterable, index) = (self.iterable, &*self.index);
+        (0..index.len()).map(move |rank| iterable.inner.borrow().cache[index.index(rank).unwrap()])
+    }
+
+    /// Binary search for an item, returning its position in sorted order.
+    ///
+    /// This is only meaningful for views from `sorted_view()`, see
+    /// `binary_search_by_key()` for views sorted by a key.
+    pub fn binary_search(&self, item: &I::Item) -> Result<usize, usize>
+    where
+        I::Item: Ord,
+    {
+        self.binary_search_by(|probe| probe.cmp(item))
+    }
+
+    /// Binary search for a key, returning the position in sorted order of an item with that key
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&I::Item) -> K,
+    {
+        self.binary_search_by(|probe| f(probe).cmp(key))
+    }
+
+    /// Binary search with a comparator, returning a position in sorted order
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&I::Item) -> Ordering,
+    {
+        let inner = self.iterable.inner.borrow();
+        self.index.binary_search_by(|idx| f(&inner.cache[idx]))
+    }
+}
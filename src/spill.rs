@@ -0,0 +1,259 @@
+use crate::{ContentionAware, Reiterable};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use tempfile::NamedTempFile;
+
+/// An adaptor around an iterator that can produce multiple iterators sharing an
+/// underlying cache, keeping only the most recent items in memory and spilling
+/// older ones to a temporary file.
+///
+/// Since spilled items have to be read back from disk, iterators yield owned
+/// values: clones of the items still in memory, and freshly deserialized copies
+/// of the spilled ones. The temporary file is removed when the `SpillingReiterate`
+/// is dropped.
+///
+/// # Panics
+///
+/// Iterators panic if the temporary file can't be written to or read from.
+///
+/// ```rust
+/// use reiterate::SpillingReiterate;
+///
+/// let words = ["alpha", "beta", "gamma", "delta", "epsilon"];
+/// let reiterate = SpillingReiterate::new(words.iter().map(|w| w.to_string()), 2).unwrap();
+///
+/// let first: Vec<String> = reiterate.iter().collect();
+/// assert_eq!(first, words);
+/// // only "delta" and "epsilon" are still in memory
+/// assert_eq!(reiterate.spilled_len().ready(), Some(3));
+///
+/// // replaying reads the older items back from disk
+/// let second: Vec<String> = reiterate.iter().collect();
+/// assert_eq!(second, first);
+/// ```
+///
+/// Handles can be at different positions, some reading from memory and others from disk:
+///
+/// ```rust
+/// use reiterate::{Reiterate, SpillingReiterate};
+///
+/// let items = || (0..50u64).map(|i| (i, format!("item {}", i)));
+/// let spilling = SpillingReiterate::new(items(), 4).unwrap();
+/// let in_memory = Reiterate::new(items().map(Box::new));
+///
+/// let (mut ahead, mut behind) = (spilling.iter(), spilling.iter());
+/// let (mut expected_ahead, mut expected_behind) = (in_memory.into_iter(), in_memory.into_iter());
+/// for _ in 0..50 {
+///     assert_eq!(ahead.next().as_ref(), expected_ahead.next());
+///     assert_eq!(ahead.next().as_ref(), expected_ahead.next());
+///     assert_eq!(behind.next().as_ref(), expected_behind.next());
+/// }
+/// assert_eq!(spilling.spilled_len().ready(), Some(46));
+/// ```
+pub struct SpillingReiterate<I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    inner: RefCell<SpillingReiterateInner<I>>,
+    file: NamedTempFile,
+}
+
+struct SpillingReiterateInner<I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    iter: I,
+    budget: usize,
+    // The items after the spilled ones
+    memory: VecDeque<I::Item>,
+    // Spilled item `i` is stored at `offsets[i]..offsets[i + 1]`
+    offsets: Vec<u64>,
+}
+
+impl<I> SpillingReiterate<I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    /// Create a `SpillingReiterate` that keeps up to `budget` items in memory,
+    /// spilling older ones to a file in the system's temporary directory
+    pub fn new<T>(iter: T, budget: usize) -> io::Result<Self>
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Self::with_file(iter, budget, NamedTempFile::new()?)
+    }
+
+    /// Create a `SpillingReiterate` that keeps up to `budget` items in memory,
+    /// spilling older ones to a file in `dir`
+    ///
+    /// ```rust
+    /// use reiterate::SpillingReiterate;
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let reiterate = SpillingReiterate::new_in((0..100u32).map(|i| vec![i; 3]), 10, &dir).unwrap();
+    /// assert_eq!(reiterate.iter().count(), 100);
+    ///
+    /// let path = reiterate.spill_path().to_owned();
+    /// assert!(path.starts_with(&dir));
+    /// assert!(path.exists());
+    ///
+    /// // the file is cleaned up with the cache
+    /// drop(reiterate);
+    /// assert!(!path.exists());
+    /// ```
+    pub fn new_in<T, P>(iter: T, budget: usize, dir: P) -> io::Result<Self>
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+        P: AsRef<Path>,
+    {
+        Self::with_file(iter, budget, NamedTempFile::new_in(dir)?)
+    }
+
+    fn with_file<T>(iter: T, budget: usize, file: NamedTempFile) -> io::Result<Self>
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Ok(SpillingReiterate {
+            inner: RefCell::new(SpillingReiterateInner {
+                iter: iter.into_iter(),
+                budget,
+                memory: VecDeque::with_capacity(budget),
+                offsets: vec![0],
+            }),
+            file,
+        })
+    }
+
+    /// The number of cached items that have been spilled to disk, or `Contended` during a
+    /// pull
+    pub fn spilled_len(&self) -> ContentionAware<usize> {
+        ContentionAware::borrow(&self.inner).map(|inner| inner.offsets.len() - 1)
+    }
+
+    /// The path of the temporary file holding the spilled items
+    pub fn spill_path(&self) -> &Path {
+        self.file.path()
+    }
+
+    /// Get an iterator over owned copies of the items
+    pub fn iter(&self) -> SpillingReiterator<'_, I> {
+        SpillingReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<I::Item> {
+        let mut inner = self.inner.borrow_mut();
+        let spilled = inner.offsets.len() - 1;
+        if idx < spilled {
+            return Some(
+                inner
+                    .read(self.file.as_file(), idx)
+                    .expect("failed to read spilled item"),
+            );
+        }
+        if let Some(val) = inner.memory.get(idx - spilled) {
+            return Some(val.clone());
+        }
+        let val = inner.iter.next()?;
+        inner.memory.push_back(val.clone());
+        if inner.memory.len() > inner.budget {
+            let oldest = inner.memory.pop_front().unwrap();
+            inner
+                .spill(self.file.as_file(), &oldest)
+                .expect("failed to spill item");
+        }
+        Some(val)
+    }
+}
+
+impl<I> SpillingReiterateInner<I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    fn spill(&mut self, mut file: &File, val: &I::Item) -> io::Result<()> {
+        let bytes = bincode::serialize(val).map_err(io::Error::other)?;
+        let end = *self.offsets.last().unwrap();
+        file.seek(SeekFrom::Start(end))?;
+        file.write_all(&bytes)?;
+        self.offsets.push(end + bytes.len() as u64);
+        Ok(())
+    }
+
+    fn read(&self, mut file: &File, idx: usize) -> io::Result<I::Item> {
+        let (start, end) = (self.offsets[idx], self.offsets[idx + 1]);
+        let mut bytes = vec![0; (end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut bytes)?;
+        bincode::deserialize(&bytes).map_err(io::Error::other)
+    }
+}
+
+impl<'a, I> IntoIterator for &'a SpillingReiterate<I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    type IntoIter = SpillingReiterator<'a, I>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I> Reiterable for SpillingReiterate<I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = SpillingReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> SpillingReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.iter()` on a `SpillingReiterate` instance
+pub struct SpillingReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    iterable: &'a SpillingReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for SpillingReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Serialize + DeserializeOwned + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a view of the runs of items between the items matching `pred`, like `slice::split`.
+    ///
+    /// The delimiters are found lazily and remembered, so `pred` runs at most once per item
+    /// no matter how many times the view is iterated. Each segment is itself a lazy view
+    /// into the shared cache.
+    ///
+    /// As with `slice::split`, a leading delimiter produces an empty first segment, a
+    /// trailing one produces an empty last segment, and an empty stream has a single
+    /// empty segment.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let tokens = [",", "a", "b", ",", ",", "c", ","];
+    /// let calls = Cell::new(0);
+    /// let reiterate = Reiterate::new(tokens.to_vec());
+    /// let split = reiterate.split_view(|t| {
+    ///     calls.set(calls.get() + 1);
+    ///     t == ","
+    /// });
+    ///
+    /// let expected: Vec<Vec<&str>> = tokens.split(|t| *t == ",").map(|s| s.to_vec()).collect();
+    /// for _ in 0..2 {
+    ///     let segments: Vec<Vec<&str>> = split.iter().map(|s| s.collect()).collect();
+    ///     assert_eq!(segments, expected);
+    /// }
+    /// assert_eq!(calls.get(), tokens.len());
+    ///
+    /// let empty = Reiterate::new(Vec::<&str>::new());
+    /// let segments: Vec<Vec<&str>> = empty.split_view(|t| t == ",").iter().map(|s| s.collect()).collect();
+    /// assert_eq!(segments, [Vec::<&str>::new()]);
+    /// ```
+    ///
+    /// Segments can be consumed in any order:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "|", "b", "c", "|", "d"]);
+    /// let split = reiterate.split_view(|t| t == "|");
+    /// let mut segments = split.iter();
+    /// let (first, second, third) = (segments.next().unwrap(), segments.next().unwrap(), segments.next().unwrap());
+    /// assert!(segments.next().is_none());
+    ///
+    /// assert_eq!(third.collect::<Vec<_>>(), ["d"]);
+    /// assert_eq!(first.collect::<Vec<_>>(), ["a"]);
+    /// assert_eq!(second.collect::<Vec<_>>(), ["b", "c"]);
+    /// ```
+    pub fn split_view<P>(&self, pred: P) -> SplitView<'_, I, P>
+    where
+        P: Fn(&<I::Item as Deref>::Target) -> bool,
+    {
+        SplitView {
+            iterable: self,
+            pred,
+            scanned: Cell::new(0),
+            delims: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+/// A view of the segments of a `Reiterate` between delimiters, produced by `split_view()`
+pub struct SplitView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    iterable: &'a Reiterate<I>,
+    pred: P,
+    // How many items `pred` has been run on
+    scanned: Cell<usize>,
+    // The indices of the delimiters among the scanned items
+    delims: RefCell<Vec<usize>>,
+}
+
+impl<'a, I, P> SplitView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    /// Iterate over the segments
+    pub fn iter(&self) -> SplitReiterator<'_, 'a, I, P> {
+        SplitReiterator {
+            view: self,
+            segment: 0,
+        }
+    }
+
+    /// Run `pred` on the next item, pulling it if needed.
+    /// Returns `false` if the stream has ended.
+    fn scan(&self) -> bool {
+        let idx = self.scanned.get();
+        let item = match self.iterable.get_or_pull(idx) {
+            Some(item) => item,
+            None => return false,
+        };
+        if (self.pred)(item) {
+            self.delims.borrow_mut().push(idx);
+        }
+        self.scanned.set(idx + 1);
+        true
+    }
+
+    /// The index of the delimiter ending `segment`, if it has been found
+    fn delim(&self, segment: usize) -> Option<usize> {
+        self.delims.borrow().get(segment).copied()
+    }
+}
+
+impl<'v, 'a, I, P> IntoIterator for &'v SplitView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type IntoIter = SplitReiterator<'v, 'a, I, P>;
+    type Item = Segment<'v, 'a, I, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the segments of a `SplitView`, produced by calling `.iter()`
+pub struct SplitReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    view: &'v SplitView<'a, I, P>,
+    segment: usize,
+}
+
+impl<'v, 'a, I, P> Iterator for SplitReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type Item = Segment<'v, 'a, I, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let view = self.view;
+        // Every segment but the first one starts after a delimiter
+        let start = match self.segment.checked_sub(1) {
+            None => 0,
+            Some(prev) => loop {
+                if let Some(delim) = view.delim(prev) {
+                    break delim + 1;
+                }
+                if !view.scan() {
+                    return None;
+                }
+            },
+        };
+        let segment = Segment {
+            view,
+            segment: self.segment,
+            curr: start,
+        };
+        self.segment += 1;
+        Some(segment)
+    }
+}
+
+/// A lazy view over the items of a single segment, yielded by a `SplitReiterator`
+pub struct Segment<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    view: &'v SplitView<'a, I, P>,
+    segment: usize,
+    curr: usize,
+}
+
+impl<'v, 'a, I, P> Iterator for Segment<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let view = self.view;
+        let ended = |curr| view.delim(self.segment).is_some_and(|end| curr >= end);
+        if ended(self.curr) {
+            return None;
+        }
+        if self.curr >= view.scanned.get() {
+            if !view.scan() {
+                return None;
+            }
+            // The scanned item may have been the delimiter ending this segment
+            if ended(self.curr) {
+                return None;
+            }
+        }
+        let item = &view.iterable.cache[self.curr];
+        self.curr += 1;
+        Some(item)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{ReiterableItem, Reiterate, Reiterator};
+
+use std::iter::{Skip, Take};
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Split the stream into a view of its first `n` items and a view of the rest.
+    ///
+    /// Both views are backed by the cache, so each can be iterated any number of times
+    /// and the underlying iterator is still only traversed once, whichever is consumed
+    /// first. Iterating over the suffix pulls the items of the prefix into the cache
+    /// if they aren't there yet.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = Reiterate::new((0..6).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let (header, body) = reiterate.split_at_view(2);
+    ///
+    /// // the suffix first pulls through the prefix
+    /// assert_eq!(body.iter().next(), Some(&2));
+    /// assert_eq!(pulls.get(), 3);
+    /// for _ in 0..2 {
+    ///     assert_eq!(body.iter().copied().collect::<Vec<_>>(), [2, 3, 4, 5]);
+    ///     assert_eq!(header.iter().copied().collect::<Vec<_>>(), [0, 1]);
+    /// }
+    /// assert_eq!(pulls.get(), 6);
+    /// ```
+    ///
+    /// The prefix is shorter if the stream is, and either view can be empty:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec![Box::new('a'), Box::new('b')]);
+    /// let (prefix, suffix) = reiterate.split_at_view(0);
+    /// assert_eq!(prefix.iter().next(), None);
+    /// assert_eq!(suffix.iter().collect::<String>(), "ab");
+    ///
+    /// let (prefix, suffix) = reiterate.split_at_view(5);
+    /// assert_eq!(prefix.iter().collect::<String>(), "ab");
+    /// assert_eq!(suffix.iter().next(), None);
+    /// ```
+    pub fn split_at_view(&self, n: usize) -> (PrefixView<'_, I>, SuffixView<'_, I>) {
+        (
+            PrefixView { iterable: self, n },
+            SuffixView { iterable: self, n },
+        )
+    }
+}
+
+/// A view of the first items of a `Reiterate`, produced by `split_at_view()`
+pub struct PrefixView<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    n: usize,
+}
+
+impl<'a, I> PrefixView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> Take<Reiterator<'a, I>> {
+        self.iterable.into_iter().take(self.n)
+    }
+}
+
+impl<'a, I> IntoIterator for &PrefixView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type IntoIter = Take<Reiterator<'a, I>>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A view of the items of a `Reiterate` after the first ones, produced by
+/// `split_at_view()`
+pub struct SuffixView<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    n: usize,
+}
+
+impl<'a, I> SuffixView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> Skip<Reiterator<'a, I>> {
+        // `Skip` goes through `Reiterator::nth()`, which jumps over cached items
+        self.iterable.into_iter().skip(self.n)
+    }
+}
+
+impl<'a, I> IntoIterator for &SuffixView<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type IntoIter = Skip<Reiterator<'a, I>>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
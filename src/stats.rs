@@ -0,0 +1,112 @@
+use crate::{ReiterableItem, Reiterate};
+
+use std::cell::Cell;
+
+/// The counters kept by a `Reiterate` created with `with_stats()`
+#[derive(Default)]
+pub(crate) struct Counters {
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+    pulled: Cell<usize>,
+    peak_cached: Cell<usize>,
+}
+
+impl Counters {
+    #[inline]
+    pub(crate) fn hit(&self) {
+        self.hits.set(self.hits.get() + 1);
+    }
+
+    pub(crate) fn miss(&self) {
+        self.misses.set(self.misses.get() + 1);
+    }
+
+    /// Record an item pulled from the source, after which `cached` items are cached
+    pub(crate) fn pulled(&self, cached: usize) {
+        self.pulled.set(self.pulled.get() + 1);
+        self.peak_cached.set(self.peak_cached.get().max(cached));
+    }
+}
+
+/// How much the cache of a `Reiterate` created with `with_stats()` has been used, returned
+/// by `stats()`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of items handles got from the cache
+    pub hits: usize,
+    /// The number of times a handle reached the end of the cache and asked the source
+    pub misses: usize,
+    /// The number of items pulled from the source, including from queued sources
+    pub pulled: usize,
+    /// The largest number of items cached at once
+    pub peak_cached: usize,
+}
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Create a `Reiterate` that keeps statistics of how much its cache is used, for
+    /// `stats()`.
+    ///
+    /// A `Reiterate` created with `new()` keeps none. Items skipped over without being
+    /// yielded, like by `nth()` or `count()`, aren't counted as hits, and neither are items
+    /// looked up with methods other than those of the handles.
+    ///
+    /// ```rust
+    /// use reiterate::{CacheStats, Reiterate};
+    ///
+    /// // each item is an expensive call
+    /// let responses = Reiterate::with_stats((0..3).map(|i| format!("response {}", i)));
+    /// for _ in 0..4 {
+    ///     assert_eq!(responses.into_iter().collect::<Vec<_>>().len(), 3);
+    /// }
+    /// // every pass after the first is served from the cache, and then asks the source
+    /// // whether there is more
+    /// assert_eq!(
+    ///     responses.stats(),
+    ///     Some(CacheStats { hits: 9, misses: 7, pulled: 3, peak_cached: 3 })
+    /// );
+    ///
+    /// assert_eq!(Reiterate::new(vec!["untracked"]).stats(), None);
+    /// ```
+    pub fn with_stats<T>(iter: T) -> Self
+    where
+        I: Sized,
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        Reiterate {
+            stats: Some(Box::default()),
+            slow_path: Cell::new(true),
+            ..Reiterate::new(iter)
+        }
+    }
+
+    /// The statistics kept since the `Reiterate` was created with `with_stats()`, or `None`
+    /// if it wasn't.
+    ///
+    /// A miss that finds the source ended still counts, so a handle that goes through
+    /// every item counts one miss at the end.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::with_stats(vec![Box::new(1), Box::new(2)]);
+    /// let (mut first, mut second) = (reiterate.into_iter(), reiterate.into_iter());
+    /// first.next();
+    /// second.next();
+    /// second.next();
+    /// let stats = reiterate.stats().unwrap();
+    /// assert_eq!((stats.hits, stats.misses, stats.pulled), (1, 2, 2));
+    /// ```
+    pub fn stats(&self) -> Option<CacheStats> {
+        let counters = self.stats.as_deref()?;
+        Some(CacheStats {
+            hits: counters.hits.get(),
+            misses: counters.misses.get(),
+            pulled: counters.pulled.get(),
+            peak_cached: counters.peak_cached.get(),
+        })
+    }
+}
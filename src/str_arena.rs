@@ -0,0 +1,197 @@
+use crate::Reiterable;
+
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::{slice, str};
+
+// The size of the blocks strings are copied into
+const BLOCK_SIZE: usize = 64 * 1024;
+
+/// An adaptor around an iterator of `String`s that can produce multiple iterators
+/// sharing an underlying cache, storing the strings in large shared blocks.
+///
+/// Each string's bytes are copied into an append-only block as it is pulled, and the
+/// `String` itself is dropped right away. This keeps a handful of large allocations
+/// alive instead of one per string, which is much cheaper when caching many short strings.
+/// Strings longer than a block get a block of their own.
+///
+/// ```rust
+/// use reiterate::StrArenaReiterate;
+///
+/// let words = vec!["", "a", "few", "", "short", "words"];
+/// let reiterate = StrArenaReiterate::new(words.iter().map(|w| w.to_string()));
+/// for _ in 0..2 {
+///     assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), words);
+/// }
+/// assert_eq!(reiterate.block_count(), 1);
+/// ```
+///
+/// ```rust
+/// use reiterate::StrArenaReiterate;
+///
+/// let huge = "x".repeat(200_000);
+/// let strings = vec!["before".to_string(), huge.clone(), "after".to_string()];
+/// let reiterate = StrArenaReiterate::new(strings.clone());
+/// assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), strings);
+/// // "after" can't go back into the first block, which is no longer the current one
+/// assert_eq!(reiterate.block_count(), 3);
+/// ```
+pub struct StrArenaReiterate<I>
+where
+    I: Iterator<Item = String>,
+{
+    iter: RefCell<I>,
+    arena: RefCell<Arena>,
+}
+
+struct Arena {
+    // Blocks are never moved, reallocated or freed before the arena is dropped,
+    // and their bytes are never written again once a string has been copied in
+    blocks: Vec<(NonNull<u8>, usize)>,
+    // How many bytes of the last block are used
+    used: usize,
+    // The bytes of each cached string
+    strings: Vec<(NonNull<u8>, usize)>,
+}
+
+impl Arena {
+    fn alloc_block(&mut self, size: usize) -> NonNull<u8> {
+        let block = Box::into_raw(vec![0u8; size].into_boxed_slice());
+        // SAFETY: `Box::into_raw` never returns null
+        let block = unsafe { NonNull::new_unchecked(block.cast::<u8>()) };
+        self.blocks.push((block, size));
+        block
+    }
+
+    fn store(&mut self, s: &str) -> (NonNull<u8>, usize) {
+        let len = s.len();
+        if len == 0 {
+            return (NonNull::dangling(), 0);
+        }
+        let dst = match self.blocks.last() {
+            Some(&(block, size)) if size - self.used >= len => {
+                // SAFETY: the offset is in bounds of the block
+                let dst = unsafe { NonNull::new_unchecked(block.as_ptr().add(self.used)) };
+                self.used += len;
+                dst
+            }
+            _ => {
+                let dst = self.alloc_block(len.max(BLOCK_SIZE));
+                self.used = len;
+                dst
+            }
+        };
+        // SAFETY: `dst` is valid for `len` bytes that no cached string covers yet
+        unsafe { ptr::copy_nonoverlapping(s.as_ptr(), dst.as_ptr(), len) };
+        (dst, len)
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        for &(block, size) in &self.blocks {
+            // SAFETY: the block was allocated in `alloc_block` as a boxed slice of this size
+            drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(block.as_ptr(), size)) });
+        }
+    }
+}
+
+// The arena owns its blocks, like a `Vec<Box<[u8]>>` would
+unsafe impl<I> Send for StrArenaReiterate<I> where I: Iterator<Item = String> + Send {}
+
+impl<I> StrArenaReiterate<I>
+where
+    I: Iterator<Item = String>,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = String, IntoIter = I>,
+    {
+        StrArenaReiterate {
+            iter: RefCell::new(iter.into_iter()),
+            arena: RefCell::new(Arena {
+                blocks: Vec::new(),
+                used: 0,
+                strings: Vec::new(),
+            }),
+        }
+    }
+
+    /// The number of blocks holding the cached strings
+    pub fn block_count(&self) -> usize {
+        self.arena.borrow().blocks.len()
+    }
+
+    /// Get the string at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<&str> {
+        let cached = self.arena.borrow().strings.get(idx).copied();
+        let (bytes, len) = match cached {
+            Some(string) => string,
+            None => {
+                let s = self.iter.borrow_mut().next()?;
+                let mut arena = self.arena.borrow_mut();
+                let string = arena.store(&s);
+                arena.strings.push(string);
+                string
+            }
+        };
+        // SAFETY: the bytes were copied from a `str`, and live as long as the arena
+        Some(unsafe { str::from_utf8_unchecked(slice::from_raw_parts(bytes.as_ptr(), len)) })
+    }
+}
+
+impl<'a, I> IntoIterator for &'a StrArenaReiterate<I>
+where
+    I: Iterator<Item = String>,
+{
+    type IntoIter = StrArenaReiterator<'a, I>;
+    type Item = &'a str;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StrArenaReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I> Reiterable for StrArenaReiterate<I>
+where
+    I: Iterator<Item = String>,
+{
+    type Item<'a>
+        = &'a str
+    where
+        Self: 'a;
+    type Iter<'a>
+        = StrArenaReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> StrArenaReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&StrArenaReiterate` instance
+pub struct StrArenaReiterator<'a, I>
+where
+    I: Iterator<Item = String>,
+{
+    iterable: &'a StrArenaReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for StrArenaReiterator<'a, I>
+where
+    I: Iterator<Item = String>,
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
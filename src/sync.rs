@@ -0,0 +1,697 @@
+//! Thread-safe variants of `Reiterate`
+
+use crate::lock::Mutex;
+use crate::Reiterable;
+
+use elsa::sync::FrozenVec;
+
+use stable_deref_trait::StableDeref;
+use std::borrow::ToOwned;
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+
+/// A thread-safe adaptor around an iterator that can produce multiple iterators
+/// sharing an underlying cache, from any number of threads.
+///
+/// Like `Reiterate`, the underlying iterator must produce heap-allocated StableDeref values.
+/// Pulling from the underlying iterator is serialized by a lock, and every item is pulled
+/// exactly once no matter how many threads are iterating.
+///
+/// ```rust
+/// use reiterate::sync::SyncReiterate;
+/// use std::thread;
+///
+/// let reiterate = SyncReiterate::new((0..100).map(Box::new));
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| assert_eq!(reiterate.into_iter().sum::<i32>(), 4950));
+///     }
+/// });
+/// ```
+///
+/// A panic in the underlying iterator doesn't make the cached items unreadable, from
+/// the thread that panicked or any other:
+///
+/// ```rust
+/// use reiterate::sync::SyncReiterate;
+/// use std::thread;
+///
+/// let reiterate = SyncReiterate::new((0..4).map(|i| if i == 2 { panic!("broken source") } else { Box::new(i) }));
+/// thread::scope(|s| {
+///     assert!(s.spawn(|| reiterate.into_iter().count()).join().is_err());
+///     s.spawn(|| assert_eq!(reiterate.into_iter().take(2).sum::<i32>(), 1));
+/// });
+/// // the source picks up after the panic
+/// assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [0, 1, 3]);
+/// ```
+pub struct SyncReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    iter: Mutex<I>,
+    cache: FrozenVec<I::Item>,
+}
+
+impl<I> SyncReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        SyncReiterate {
+            iter: Mutex::new(iter.into_iter()),
+            cache: FrozenVec::new(),
+        }
+    }
+
+    /// Move this into an `Arc`, so that owned iterators can be handed to other threads
+    pub fn into_shared(self) -> ArcReiterate<I> {
+        ArcReiterate {
+            inner: Arc::new(self),
+        }
+    }
+
+    /// Run `f` on `workers` scoped threads, each with its own handle over the whole
+    /// stream, and collect what they return in worker order.
+    ///
+    /// The items are pulled once and shared however the workers interleave. `f` can
+    /// borrow from the caller, since every thread is joined before this returns. The
+    /// threads are named `fan-out-0`, `fan-out-1` and so on, in worker order. If workers
+    /// panic, the others still run to completion, and the panic that happened first is
+    /// resumed once they're all joined.
+    ///
+    /// ```rust
+    /// use reiterate::sync::SyncReiterate;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::thread;
+    ///
+    /// let pulls = AtomicUsize::new(0);
+    /// let source = (1..=100).map(|i| {
+    ///     pulls.fetch_add(1, Ordering::Relaxed);
+    ///     Box::new(i)
+    /// });
+    /// let reiterate = SyncReiterate::new(source);
+    /// let results = reiterate.fan_out(3, |iter| {
+    ///     let name = thread::current().name().unwrap().to_owned();
+    ///     (name, iter.sum::<i32>())
+    /// });
+    /// let expected: Vec<_> = (0..3).map(|n| (format!("fan-out-{}", n), 5050)).collect();
+    /// assert_eq!(results, expected);
+    /// assert_eq!(pulls.load(Ordering::Relaxed), 100);
+    /// ```
+    ///
+    /// A panicking worker doesn't stop the others:
+    ///
+    /// ```rust
+    /// use reiterate::sync::SyncReiterate;
+    /// use std::panic::{self, AssertUnwindSafe};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// let reiterate = SyncReiterate::new(vec![Box::new(1), Box::new(2), Box::new(3)]);
+    /// let finished = AtomicUsize::new(0);
+    /// let turn = AtomicUsize::new(0);
+    /// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     reiterate.fan_out(4, |iter| {
+    ///         match turn.fetch_add(1, Ordering::SeqCst) {
+    ///             1 => panic!("worker failed"),
+    ///             2 => {
+    ///                 thread::sleep(Duration::from_millis(50));
+    ///                 panic!("worker failed later");
+    ///             }
+    ///             _ => {}
+    ///         }
+    ///         let sum = iter.sum::<i32>();
+    ///         finished.fetch_add(1, Ordering::SeqCst);
+    ///         sum
+    ///     })
+    /// }));
+    /// let payload = result.unwrap_err();
+    /// assert_eq!(payload.downcast_ref::<&str>(), Some(&"worker failed"));
+    /// assert_eq!(finished.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn fan_out<R, F>(&self, workers: usize, f: F) -> Vec<R>
+    where
+        Self: Sync,
+        R: Send,
+        F: Fn(SyncReiterator<'_, I>) -> R + Sync,
+    {
+        // Panics are numbered in the order they happen, to resume the first one
+        let panics = AtomicUsize::new(0);
+        let results: Vec<_> = thread::scope(|s| {
+            let handles: Vec<_> = (0..workers)
+                .map(|n| {
+                    let (f, panics) = (&f, &panics);
+                    thread::Builder::new()
+                        .name(format!("fan-out-{}", n))
+                        .spawn_scoped(s, move || {
+                            panic::catch_unwind(AssertUnwindSafe(|| f(self.into_iter()))).map_err(
+                                |payload| (panics.fetch_add(1, AtomicOrdering::SeqCst), payload),
+                            )
+                        })
+                        .expect("failed to spawn a worker thread")
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("worker panics are caught"))
+                .collect()
+        });
+        let mut first = None;
+        let mut values = Vec::with_capacity(workers);
+        for result in results {
+            match result {
+                Ok(val) => values.push(val),
+                Err((nth, payload)) => match first {
+                    Some((earliest, _)) if earliest < nth => {}
+                    _ => first = Some((nth, payload)),
+                },
+            }
+        }
+        if let Some((_, payload)) = first {
+            panic::resume_unwind(payload);
+        }
+        values
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<&<I::Item as Deref>::Target> {
+        if let Some(val) = self.cache.get(idx) {
+            return Some(val);
+        }
+        // A panic in the underlying iterator leaves the cache untouched, so the
+        // lock is still fine to use afterwards
+        let mut iter = self.iter.lock();
+        // Another thread may have pulled while we were waiting for the lock
+        if let Some(val) = self.cache.get(idx) {
+            return Some(val);
+        }
+        let val = iter.next()?;
+        Some(self.cache.push_get(val))
+    }
+}
+
+impl<'a, I> IntoIterator for &'a SyncReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    type IntoIter = SyncReiterator<'a, I>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SyncReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I> Reiterable for SyncReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    type Item<'a>
+        = &'a <I::Item as Deref>::Target
+    where
+        Self: 'a;
+    type Iter<'a>
+        = SyncReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> SyncReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&SyncReiterate` instance
+pub struct SyncReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    iterable: &'a SyncReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for SyncReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
+
+/// A `SyncReiterate` shared through an `Arc`, produced by `SyncReiterate::into_shared()`.
+///
+/// Cloning it is cheap and shares the same cache. Its owned iterators aren't tied to
+/// any borrow, so they can be sent to other threads (including `'static` ones).
+///
+/// Since owned iterators can't hand out references into the cache that outlive
+/// themselves, they yield owned copies of the cached items via `ToOwned`, e.g. `String`
+/// for a cache of `String`s or `Box<str>`s. If copying items is expensive and the threads
+/// don't need to be `'static`, use borrowing iterators from `iter()` in scoped threads instead.
+///
+/// ```rust
+/// use reiterate::sync::SyncReiterate;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let pulls = Arc::new(AtomicUsize::new(0));
+/// let source = {
+///     let pulls = pulls.clone();
+///     (0..1000u64).map(move |i| {
+///         pulls.fetch_add(1, Ordering::Relaxed);
+///         Box::new(i)
+///     })
+/// };
+/// let shared = SyncReiterate::new(source).into_shared();
+///
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let iter = shared.iter_owned();
+///         thread::spawn(move || iter.sum::<u64>())
+///     })
+///     .collect();
+/// for worker in workers {
+///     assert_eq!(worker.join().unwrap(), 499500);
+/// }
+/// assert_eq!(pulls.load(Ordering::Relaxed), 1000);
+/// ```
+pub struct ArcReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    inner: Arc<SyncReiterate<I>>,
+}
+
+impl<I> ArcReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    /// Get an iterator owning a reference to the shared cache
+    pub fn iter_owned(&self) -> OwnedSyncReiterator<I> {
+        OwnedSyncReiterator {
+            iterable: self.inner.clone(),
+            curr: 0,
+        }
+    }
+
+    /// Get an iterator borrowing the shared cache
+    pub fn iter(&self) -> SyncReiterator<'_, I> {
+        self.inner.into_iter()
+    }
+}
+
+impl<I> Clone for ArcReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    fn clone(&self) -> Self {
+        ArcReiterate {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I> Deref for ArcReiterate<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    type Target = SyncReiterate<I>;
+
+    fn deref(&self) -> &SyncReiterate<I> {
+        &self.inner
+    }
+}
+
+/// An iterator owning a reference to the cache of an `ArcReiterate`, produced by
+/// calling `.iter_owned()`. It yields owned copies of the cached items.
+pub struct OwnedSyncReiterator<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    iterable: Arc<SyncReiterate<I>>,
+    curr: usize,
+}
+
+impl<I> Iterator for OwnedSyncReiterator<I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    <I::Item as Deref>::Target: ToOwned,
+{
+    type Item = <<I::Item as Deref>::Target as ToOwned>::Owned;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iterable.get(self.curr)?.to_owned();
+        self.curr += 1;
+        Some(val)
+    }
+}
+
+/// A `SyncReiterate` created on first access, like a `LazyLock`, so that it can be a
+/// `static`.
+///
+/// `init` runs on the first call to `iter()`, or to anything else that needs the
+/// `SyncReiterate`, from whichever thread gets there first. Threads racing for the first
+/// access wait for that one construction to finish, so `init` runs exactly once; if it
+/// panics, nothing is stored, and the next access tries again.
+///
+/// ```rust
+/// use reiterate::sync::LazyReiterate;
+/// use std::thread;
+///
+/// static WORDS: LazyReiterate<std::vec::IntoIter<Box<str>>> = LazyReiterate::new(|| {
+///     vec!["alpha".into(), "beta".into(), "gamma".into()].into_iter()
+/// });
+///
+/// let workers: Vec<_> = (0..2)
+///     .map(|_| thread::spawn(|| WORDS.iter().map(str::len).sum::<usize>()))
+///     .collect();
+/// for worker in workers {
+///     assert_eq!(worker.join().unwrap(), 14);
+/// }
+/// assert_eq!(WORDS.iter().collect::<Vec<_>>(), ["alpha", "beta", "gamma"]);
+/// ```
+///
+/// Racing threads construct it once:
+///
+/// ```rust
+/// use reiterate::sync::LazyReiterate;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Barrier;
+/// use std::thread;
+///
+/// static CONSTRUCTIONS: AtomicUsize = AtomicUsize::new(0);
+/// static SQUARES: LazyReiterate<Box<dyn Iterator<Item = Box<u64>> + Send>> = LazyReiterate::new(|| {
+///     CONSTRUCTIONS.fetch_add(1, Ordering::SeqCst);
+///     Box::new((0..100u64).map(|i| Box::new(i * i)))
+/// });
+///
+/// assert!(SQUARES.get().is_none());
+/// let barrier = Barrier::new(8);
+/// thread::scope(|s| {
+///     for _ in 0..8 {
+///         s.spawn(|| {
+///             barrier.wait();
+///             assert_eq!(SQUARES.iter().count(), 100);
+///         });
+///     }
+/// });
+/// assert_eq!(CONSTRUCTIONS.load(Ordering::SeqCst), 1);
+/// assert!(SQUARES.get().is_some());
+/// ```
+pub struct LazyReiterate<I, F = fn() -> I>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+{
+    cell: OnceLock<SyncReiterate<I>>,
+    init: F,
+}
+
+impl<I, F> LazyReiterate<I, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: Fn() -> I,
+{
+    /// Create a `LazyReiterate` whose underlying iterator is created by `init`
+    pub const fn new(init: F) -> Self {
+        LazyReiterate {
+            cell: OnceLock::new(),
+            init,
+        }
+    }
+
+    /// Get the `SyncReiterate`, creating it if this is the first access
+    pub fn force(&self) -> &SyncReiterate<I> {
+        self.cell.get_or_init(|| SyncReiterate::new((self.init)()))
+    }
+
+    /// Get the `SyncReiterate`, if it has been created
+    pub fn get(&self) -> Option<&SyncReiterate<I>> {
+        self.cell.get()
+    }
+
+    /// Get an iterator over the shared cache, creating it if this is the first access
+    pub fn iter(&self) -> SyncReiterator<'_, I> {
+        self.force().into_iter()
+    }
+}
+
+impl<I, F> Deref for LazyReiterate<I, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: Fn() -> I,
+{
+    type Target = SyncReiterate<I>;
+
+    fn deref(&self) -> &SyncReiterate<I> {
+        self.force()
+    }
+}
+
+impl<'a, I, F> IntoIterator for &'a LazyReiterate<I, F>
+where
+    I: Iterator,
+    I::Item: StableDeref,
+    F: Fn() -> I,
+{
+    type IntoIter = SyncReiterator<'a, I>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// The first segment holds `1 << SEGMENT_SHIFT` items, and each one after that is twice as
+// large as the previous one, so that the segment table never needs to grow
+const SEGMENT_SHIFT: u32 = 5;
+const SEGMENTS: usize = (usize::BITS - SEGMENT_SHIFT) as usize;
+
+/// The segment holding `idx`, the position of `idx` in it, and the size of the segment
+fn segment_of(idx: usize) -> (usize, usize, usize) {
+    let pos = idx + (1 << SEGMENT_SHIFT);
+    let bit = usize::BITS - 1 - pos.leading_zeros();
+    let size = 1 << bit;
+    ((bit - SEGMENT_SHIFT) as usize, pos - size, size)
+}
+
+/// A thread-safe adaptor around an iterator of `Copy` values that can produce multiple
+/// iterators sharing an underlying cache, from any number of threads.
+///
+/// Reading cached items never takes a lock: the cache is made of segments that
+/// are never moved once allocated, and items are published through an atomic length.
+/// Only pulling new items from the underlying iterator is serialized.
+///
+/// ```rust
+/// use reiterate::sync::SyncCopyReiterate;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+///
+/// let pulls = AtomicUsize::new(0);
+/// let reiterate = SyncCopyReiterate::new((0..1000u64).inspect(|_| {
+///     pulls.fetch_add(1, Ordering::Relaxed);
+/// }));
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             let items: Vec<_> = reiterate.into_iter().collect();
+///             assert_eq!(items, (0..1000).collect::<Vec<_>>());
+///         });
+///     }
+/// });
+/// assert_eq!(pulls.load(Ordering::Relaxed), 1000);
+/// ```
+pub struct SyncCopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    iter: Mutex<I>,
+    segments: [AtomicPtr<MaybeUninit<I::Item>>; SEGMENTS],
+    // Every slot below `len` has been written, and is never written again
+    len: AtomicUsize,
+}
+
+// Items are written by whichever thread pulls them and copied out by every other thread
+unsafe impl<I> Send for SyncCopyReiterate<I>
+where
+    I: Iterator + Send,
+    I::Item: Copy + Send,
+{
+}
+
+unsafe impl<I> Sync for SyncCopyReiterate<I>
+where
+    I: Iterator + Send,
+    I::Item: Copy + Send + Sync,
+{
+}
+
+impl<I> SyncCopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        SyncCopyReiterate {
+            iter: Mutex::new(iter.into_iter()),
+            segments: std::array::from_fn(|_| AtomicPtr::new(ptr::null_mut())),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Get the item at `idx` if it has been cached
+    fn get_cached(&self, idx: usize) -> Option<I::Item> {
+        if idx >= self.len.load(AtomicOrdering::Acquire) {
+            return None;
+        }
+        let (segment, offset, _) = segment_of(idx);
+        // The segment was allocated before `len` was released past `idx`
+        let slots = self.segments[segment].load(AtomicOrdering::Acquire);
+        // SAFETY: the slot is in bounds of its segment, and was written before `len`
+        // was released past `idx`. Written slots are never written to again.
+        Some(unsafe { (*slots.add(offset)).assume_init() })
+    }
+
+    /// Get the item at `idx`, pulling from the underlying iterator if the handle asking
+    /// for it has reached the end of the cache
+    fn get(&self, idx: usize) -> Option<I::Item> {
+        if let Some(val) = self.get_cached(idx) {
+            return Some(val);
+        }
+        // A panic in the underlying iterator happens before anything is published, so the
+        // lock is still fine to use afterwards
+        let mut iter = self.iter.lock();
+        // Another thread may have pulled while we were waiting for the lock
+        if let Some(val) = self.get_cached(idx) {
+            return Some(val);
+        }
+        let val = iter.next()?;
+        // Only the lock holder changes `len`, and the lock orders it with previous stores
+        let len = self.len.load(AtomicOrdering::Relaxed);
+        let (segment, offset, size) = segment_of(len);
+        let mut slots = self.segments[segment].load(AtomicOrdering::Relaxed);
+        if slots.is_null() {
+            slots = Box::into_raw(Box::<[I::Item]>::new_uninit_slice(size)).cast();
+            self.segments[segment].store(slots, AtomicOrdering::Release);
+        }
+        // SAFETY: the slot is in bounds, and no reader looks at it until `len` is released
+        // past it below. No other writer exists while we hold the lock.
+        unsafe { slots.add(offset).write(MaybeUninit::new(val)) };
+        self.len.store(len + 1, AtomicOrdering::Release);
+        Some(val)
+    }
+}
+
+impl<I> Drop for SyncCopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    fn drop(&mut self) {
+        for (segment, slots) in self.segments.iter_mut().enumerate() {
+            let slots = *slots.get_mut();
+            if slots.is_null() {
+                // Segments are allocated in order
+                break;
+            }
+            let size = 1 << (segment as u32 + SEGMENT_SHIFT);
+            // SAFETY: the segment was allocated in `get()` as a boxed slice of this size.
+            // Items are `Copy`, so there is nothing to drop in them.
+            drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(slots, size)) });
+        }
+    }
+}
+
+impl<'a, I> IntoIterator for &'a SyncCopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type IntoIter = SyncCopyReiterator<'a, I>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SyncCopyReiterator {
+            iterable: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<I> Reiterable for SyncCopyReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = SyncCopyReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> SyncCopyReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An individual iterator, produced by calling `.into_iter()` on an `&SyncCopyReiterate` instance
+pub struct SyncCopyReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    iterable: &'a SyncCopyReiterate<I>,
+    curr: usize,
+}
+
+impl<'a, I> Iterator for SyncCopyReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let val = self.iterable.get(self.curr)?;
+        self.curr += 1;
+        Some(val)
+    }
+}
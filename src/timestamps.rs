@@ -0,0 +1,147 @@
+use crate::{ContentionAware, ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+use std::time::Instant;
+
+/// An iterator recording when each of its items was pulled, used as the source of
+/// `Reiterate::with_timestamps` and `Reiterate::with_clock`.
+pub struct Timestamped<I, C = fn() -> Instant> {
+    iter: I,
+    clock: C,
+    // The time each item yielded so far was pulled at
+    times: Vec<Instant>,
+}
+
+impl<I, C> Iterator for Timestamped<I, C>
+where
+    I: Iterator,
+    C: FnMut() -> Instant,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        self.times.push((self.clock)());
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> Reiterate<Timestamped<I>>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+{
+    /// Create a `Reiterate` that records when each item gets pulled from `iter`, as
+    /// opposed to replayed, with `Instant::now()`.
+    ///
+    /// The times are kept next to the cache, so a `Reiterate` created with `new()` doesn't
+    /// pay for them. Items from sources queued with `queue_source()` have no time.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::with_timestamps(vec![Box::new('a'), Box::new('b')]);
+    /// let mut iter = reiterate.into_iter();
+    /// iter.next();
+    /// assert!(reiterate.pulled_at(0).ready().flatten().is_some());
+    /// assert_eq!(reiterate.pulled_at(1).ready(), Some(None));
+    /// iter.next();
+    /// assert!(reiterate.pulled_at(0).ready() <= reiterate.pulled_at(1).ready());
+    /// ```
+    pub fn with_timestamps<S>(iter: S) -> Self
+    where
+        S: IntoIterator<IntoIter = I>,
+    {
+        Reiterate::with_clock(iter, Instant::now)
+    }
+}
+
+impl<I, C> Reiterate<Timestamped<I, C>>
+where
+    I: Iterator,
+    I::Item: ReiterableItem,
+    C: FnMut() -> Instant,
+{
+    /// Create a `Reiterate` that records when each item gets pulled from `iter`, as told
+    /// by `clock`.
+    ///
+    /// `clock` is called once per item pulled, and never when replaying.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let ticks = Cell::new(0);
+    /// let clock = || {
+    ///     ticks.set(ticks.get() + 1);
+    ///     start + Duration::from_secs(ticks.get())
+    /// };
+    ///
+    /// let reiterate = Reiterate::with_clock((0..3).map(Box::new), clock);
+    /// for _ in 0..3 {
+    ///     let times: Vec<_> = reiterate.iter_with_times().map(|(&i, t)| (i, t - start)).collect();
+    ///     assert_eq!(times, [(0, Duration::from_secs(1)), (1, Duration::from_secs(2)), (2, Duration::from_secs(3))]);
+    /// }
+    /// // one timestamp per item, however many times it is replayed
+    /// assert_eq!(ticks.get(), 3);
+    /// assert_eq!(reiterate.pulled_at(3).ready(), Some(None));
+    /// ```
+    pub fn with_clock<S>(iter: S, clock: C) -> Self
+    where
+        S: IntoIterator<IntoIter = I>,
+    {
+        Reiterate::new(Timestamped {
+            iter: iter.into_iter(),
+            clock,
+            times: Vec::new(),
+        })
+    }
+
+    /// The time the item at `idx` was pulled at, if it has been pulled, or `Contended`
+    /// during a pull
+    pub fn pulled_at(&self, idx: usize) -> ContentionAware<Option<Instant>> {
+        let cached = idx < self.visible_len();
+        ContentionAware::borrow(&self.iter)
+            .map(|iter| iter.times.get(idx).copied().filter(|_| cached))
+    }
+
+    /// Get an iterator over the items along with the time they were pulled at
+    pub fn iter_with_times(&self) -> TimedReiterator<'_, I, C> {
+        TimedReiterator {
+            iter: self.into_iter(),
+        }
+    }
+}
+
+/// An iterator over the items of a `Reiterate` and the time they were pulled at, produced
+/// by `iter_with_times()`
+///
+/// It ends at the first item that has no time, from a queued source.
+pub struct TimedReiterator<'a, I, C>
+where
+    I: Iterator,
+    C: FnMut() -> Instant,
+{
+    iter: Reiterator<'a, Timestamped<I, C>>,
+}
+
+impl<'a, I, C> Iterator for TimedReiterator<'a, I, C>
+where
+    I: Iterator,
+    I::Item: ReiterableItem + 'a,
+    C: FnMut() -> Instant,
+{
+    type Item = (&'a <I::Item as Deref>::Target, Instant);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.iter.curr;
+        let item = self.iter.next()?;
+        Some((item, self.iter.iterable.pulled_at(idx).ready()??))
+    }
+}
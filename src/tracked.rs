@@ -0,0 +1,315 @@
+use crate::Reiterable;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+/// A buffer between an iterator and a set of consumers that move forward together,
+/// which can forget the items they have all passed.
+///
+/// Like `CopyReiterate`, items must be `Copy`. Every handle is tracked: its position
+/// is known to the `TrackedReiterate` until it is dropped, and `forget_consumed()`
+/// drops the cached items before the position of the handle furthest behind.
+/// Positions stay the same once items are forgotten; new handles start at the oldest
+/// item still cached.
+///
+/// ```rust
+/// use reiterate::TrackedReiterate;
+///
+/// let buffer = TrackedReiterate::new(0..10);
+/// let (mut ahead, mut behind) = (buffer.into_iter(), buffer.into_iter());
+/// assert_eq!(ahead.by_ref().take(6).collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+/// assert_eq!(behind.nth(2), Some(2));
+/// assert_eq!(buffer.forget_consumed(), 3);
+/// assert_eq!(buffer.cached_len(), 3);
+/// // a new handle starts at the oldest item still cached
+/// assert_eq!(buffer.into_iter().next(), Some(3));
+///
+/// drop(behind);
+/// assert_eq!(buffer.forget_consumed(), 3);
+/// assert_eq!(buffer.forgotten(), 6);
+/// assert_eq!(ahead.mark(), 6);
+/// assert_eq!(ahead.collect::<Vec<_>>(), [6, 7, 8, 9]);
+/// ```
+pub struct TrackedReiterate<I>
+where
+    I: Iterator,
+{
+    inner: RefCell<TrackedReiterateInner<I>>,
+}
+
+struct TrackedReiterateInner<I>
+where
+    I: Iterator,
+{
+    // The items from position `base` on
+    cache: VecDeque<I::Item>,
+    base: usize,
+    // The oldest items are evicted past this many
+    max_cached: usize,
+    // Whether items are forgotten as soon as every live handle has passed them
+    auto_forget: bool,
+    // The position of each live handle, by slot
+    positions: Vec<Option<usize>>,
+    iter: I,
+}
+
+impl<I> TrackedReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        TrackedReiterate {
+            inner: RefCell::new(TrackedReiterateInner {
+                cache: VecDeque::new(),
+                base: 0,
+                max_cached: usize::MAX,
+                auto_forget: false,
+                positions: Vec::new(),
+                iter: iter.into_iter(),
+            }),
+        }
+    }
+
+    /// Create a `TrackedReiterate` that never caches more than `max_cached` items, for
+    /// sources too long to keep even the items the slowest handle hasn't reached.
+    ///
+    /// Once the limit is hit, pulling an item evicts the oldest one, whether or not every
+    /// handle has passed it. A handle that falls behind the oldest cached item this way
+    /// yields `None` from then on, and `fell_behind()` tells that apart from the end of the
+    /// stream. With a limit of 0 nothing is cached, and only the handle furthest ahead
+    /// sees each item.
+    ///
+    /// ```rust
+    /// use reiterate::TrackedReiterate;
+    ///
+    /// let buffer = TrackedReiterate::with_max_cached(0.., 3);
+    /// let (mut ahead, mut behind) = (buffer.into_iter(), buffer.into_iter());
+    /// assert_eq!(ahead.nth(3), Some(3));
+    /// assert_eq!(buffer.cached_len(), 3);
+    /// assert_eq!(buffer.forgotten(), 1);
+    ///
+    /// // `behind` is still at 0, which was evicted
+    /// assert_eq!(behind.next(), None);
+    /// assert!(behind.fell_behind());
+    /// assert!(!ahead.fell_behind());
+    /// // a new handle starts at the oldest item still cached
+    /// assert_eq!(buffer.into_iter().take(4).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    ///
+    /// // memory stays bounded over an endless source
+    /// assert_eq!(ahead.nth(1_000_000), Some(1_000_004));
+    /// assert_eq!(buffer.cached_len(), 3);
+    /// assert_eq!(buffer.forget_consumed(), 0);
+    /// ```
+    pub fn with_max_cached<T>(iter: T, max_cached: usize) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        let buffer = TrackedReiterate::new(iter);
+        buffer.inner.borrow_mut().max_cached = max_cached;
+        buffer
+    }
+
+    /// Drop the cached items before the position of every live handle, returning how
+    /// many were dropped.
+    ///
+    /// Without any live handle, nothing is dropped, since the next handle would start
+    /// at the oldest cached item.
+    pub fn forget_consumed(&self) -> usize {
+        self.inner.borrow_mut().forget_consumed()
+    }
+
+    /// Get `N` handles that forget every item as soon as all of them have passed it, like
+    /// `itertools::tee()`, trading replayability for memory bounded by how far apart they
+    /// are.
+    ///
+    /// From then on, this buffer does what `forget_consumed()` does on every step of a
+    /// handle, and when a handle is dropped. Handles created later start at the oldest item
+    /// still cached, if any.
+    ///
+    /// ```rust
+    /// use reiterate::TrackedReiterate;
+    ///
+    /// let buffer = TrackedReiterate::new(0..1_000_000u64);
+    /// let [mut fast, mut slow] = buffer.tee();
+    /// let (mut fast_sum, mut slow_sum) = (0, 0);
+    /// // `slow` follows one item behind
+    /// fast_sum += fast.next().unwrap();
+    /// for item in fast.by_ref() {
+    ///     fast_sum += item;
+    ///     slow_sum += slow.next().unwrap();
+    ///     assert!(buffer.cached_len() <= 2);
+    /// }
+    /// slow_sum += slow.sum::<u64>();
+    /// assert_eq!((fast_sum, slow_sum), (499_999_500_000, 499_999_500_000));
+    /// assert_eq!(buffer.cached_len(), 0);
+    ///
+    /// // a handle that stays behind holds items back until it is dropped
+    /// let buffer = TrackedReiterate::new(0..10);
+    /// let [mut ahead, behind] = buffer.tee();
+    /// assert_eq!(ahead.nth(2), Some(2));
+    /// assert_eq!(buffer.cached_len(), 3);
+    /// drop(behind);
+    /// assert_eq!(buffer.cached_len(), 0);
+    /// ```
+    pub fn tee<const N: usize>(&self) -> [TrackedReiterator<'_, I>; N] {
+        self.inner.borrow_mut().auto_forget = true;
+        std::array::from_fn(|_| self.into_iter())
+    }
+
+    /// The number of items forgotten so far, which is also the position of the oldest
+    /// cached item
+    pub fn forgotten(&self) -> usize {
+        self.inner.borrow().base
+    }
+
+    /// The number of items still cached
+    pub fn cached_len(&self) -> usize {
+        self.inner.borrow().cache.len()
+    }
+}
+
+impl<I> TrackedReiterateInner<I>
+where
+    I: Iterator,
+{
+    /// Drop the cached items before the position of every live handle, returning how
+    /// many were dropped
+    fn forget_consumed(&mut self) -> usize {
+        let behind = match self.positions.iter().flatten().min() {
+            Some(&behind) => behind,
+            None => return 0,
+        };
+        // A handle that fell behind the eviction horizon holds nothing back
+        let forget = behind.saturating_sub(self.base).min(self.cache.len());
+        self.cache.drain(..forget);
+        self.base += forget;
+        forget
+    }
+}
+
+impl<'a, I> IntoIterator for &'a TrackedReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type IntoIter = TrackedReiterator<'a, I>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut inner = self.inner.borrow_mut();
+        let base = inner.base;
+        let slot = match inner.positions.iter().position(Option::is_none) {
+            Some(slot) => {
+                inner.positions[slot] = Some(base);
+                slot
+            }
+            None => {
+                inner.positions.push(Some(base));
+                inner.positions.len() - 1
+            }
+        };
+        TrackedReiterator {
+            iterable: self,
+            slot,
+        }
+    }
+}
+
+impl<I> Reiterable for TrackedReiterate<I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = TrackedReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> TrackedReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+/// An iterator over a `TrackedReiterate`, produced by calling `.into_iter()`
+pub struct TrackedReiterator<'a, I>
+where
+    I: Iterator,
+{
+    iterable: &'a TrackedReiterate<I>,
+    // Where this handle's position is kept
+    slot: usize,
+}
+
+impl<'a, I> TrackedReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    /// The position of the next item this handle yields
+    pub fn mark(&self) -> usize {
+        self.iterable.inner.borrow().positions[self.slot].expect("handle is live")
+    }
+
+    /// Whether the item this handle would yield next has been evicted, which only happens
+    /// with `TrackedReiterate::with_max_cached()`
+    pub fn fell_behind(&self) -> bool {
+        self.mark() < self.iterable.forgotten()
+    }
+}
+
+impl<'a, I> Iterator for TrackedReiterator<'a, I>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut inner = self.iterable.inner.borrow_mut();
+        let pos = inner.positions[self.slot].expect("handle is live");
+        // Past the eviction horizon
+        let idx = pos.checked_sub(inner.base)?;
+        let val = match inner.cache.get(idx) {
+            Some(&val) => val,
+            None => {
+                let val = inner.iter.next()?;
+                inner.cache.push_back(val);
+                if inner.cache.len() > inner.max_cached {
+                    inner.cache.pop_front();
+                    inner.base += 1;
+                }
+                val
+            }
+        };
+        inner.positions[self.slot] = Some(pos + 1);
+        // Only the handle furthest behind can hold back the oldest item
+        if inner.auto_forget && pos == inner.base {
+            inner.forget_consumed();
+        }
+        Some(val)
+    }
+}
+
+impl<'a, I> Drop for TrackedReiterator<'a, I>
+where
+    I: Iterator,
+{
+    fn drop(&mut self) {
+        // Stop holding back `forget_consumed()`. A handle dropped during a pull, from
+        // the underlying iterator, keeps holding it back
+        if let Ok(mut inner) = self.iterable.inner.try_borrow_mut() {
+            inner.positions[self.slot] = None;
+            if inner.auto_forget {
+                inner.forget_consumed();
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{CopyReiterate, ReiterableItem, Reiterate};
+
+use std::mem;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// End the stream after its first `n` items, for every existing and future handle.
+    ///
+    /// Handles stop at index `n` without pulling from the underlying iterator, so nothing
+    /// past the first `n` items is ever pulled once this is called. Items past `n` that
+    /// were already cached stay in memory but are no longer yielded, and handles that had
+    /// gone past `n` return `None` from then on.
+    ///
+    /// The length can only shrink: calling this with an `n` larger than an earlier one
+    /// has no effect.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = Reiterate::new((0..100).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let mut early = reiterate.into_iter();
+    /// assert_eq!(early.next(), Some(&0));
+    ///
+    /// reiterate.truncate(3);
+    /// assert_eq!(early.by_ref().cloned().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(early.next(), None);
+    /// assert_eq!(reiterate.into_iter().count(), 3);
+    /// assert_eq!(pulls.get(), 3);
+    ///
+    /// // growing the length back is ignored
+    /// reiterate.truncate(10);
+    /// assert_eq!(reiterate.into_iter().count(), 3);
+    /// assert_eq!(reiterate.iter_tail().next(), None);
+    /// assert_eq!(pulls.get(), 3);
+    /// ```
+    ///
+    /// Handles that got ahead before the call stop right away:
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c", "d", "e"]);
+    /// let mut ahead = reiterate.into_iter();
+    /// assert_eq!(ahead.nth(3), Some("d"));
+    /// let mut behind = reiterate.into_iter();
+    /// assert_eq!(behind.next(), Some("a"));
+    ///
+    /// reiterate.truncate(2);
+    /// assert_eq!(ahead.next(), None);
+    /// // "c" and "d" are cached, but past the end
+    /// assert_eq!(behind.collect::<Vec<_>>(), ["b"]);
+    /// assert_eq!(reiterate.sorted_view().iter().collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    pub fn truncate(&self, n: usize) {
+        if n < self.limit.get() {
+            self.limit.set(n);
+            self.slow_path.set(true);
+        }
+    }
+
+    /// Drop the cached items hidden by `truncate()` and lift the truncation, so that the
+    /// stream can carry on after the visible items
+    pub(crate) fn lift_truncation(&mut self) {
+        let limit = mem::replace(self.limit.get_mut(), usize::MAX);
+        *self.slow_path.get_mut() = self.stats.is_some();
+        if limit < self.cache.len() {
+            self.discard_cached_from(limit);
+        }
+    }
+
+    /// Drop the cached items from `len` on, and everything remembered about them
+    pub(crate) fn discard_cached_from(&mut self, len: usize) {
+        let measurer = self.measurer;
+        let heap_size = self.heap_size.get_mut();
+        for item in self.cache.as_mut().drain(len..) {
+            if let Some(measurer) = measurer {
+                *heap_size = heap_size.saturating_sub(measurer(&item));
+            }
+        }
+        self.sorted.clear();
+        self.addresses.get_mut().clear();
+        self.reset_aliasing();
+        let primary = self.primary.get_mut();
+        *primary = (*primary).min(len);
+        self.generation += 1;
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// End the stream after its first `n` items, for every existing and future handle.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::truncate()`, and can only shrink
+    /// the length in the same way. A `lazy_caching()` one starts caching, so that the
+    /// first handle stops at the new length too. Like every index, `n` counts from the
+    /// first cached item, which comes `missed_prefix_len()` items into the stream.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = CopyReiterate::new((0..).inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let mut ahead = reiterate.into_iter();
+    /// assert_eq!(ahead.nth(5), Some(5));
+    ///
+    /// reiterate.truncate(4);
+    /// assert_eq!(ahead.next(), None);
+    /// assert_eq!(reiterate.into_iter().collect_vec(), [0, 1, 2, 3]);
+    /// assert_eq!(reiterate.into_iter().size_hint(), (4, Some(4)));
+    ///
+    /// reiterate.truncate(2);
+    /// reiterate.truncate(3);
+    /// let mut iter = reiterate.into_iter();
+    /// assert_eq!(iter.by_ref().collect::<Vec<_>>(), [0, 1]);
+    /// assert_eq!(iter.size_hint(), (0, Some(0)));
+    /// assert_eq!(pulls.get(), 6);
+    ///
+    /// let lazy = CopyReiterate::lazy_caching(0..10);
+    /// let mut first = lazy.into_iter();
+    /// assert_eq!(first.by_ref().take(3).collect::<Vec<_>>(), [0, 1, 2]);
+    /// lazy.truncate(2);
+    /// // the two items after the 3 streamed ones
+    /// assert_eq!(first.take(4).collect::<Vec<_>>(), [3, 4]);
+    /// assert_eq!(lazy.missed_prefix_len(), 3);
+    /// assert_eq!(lazy.into_iter().collect_vec(), [3, 4]);
+    /// ```
+    pub fn truncate(&self, n: usize) {
+        self.stop_streaming();
+        self.limit.set(self.limit.get().min(n));
+    }
+
+    /// Drop the cached items hidden by `truncate()` and lift the truncation, like for
+    /// `Reiterate`
+    pub(crate) fn lift_truncation(&mut self) {
+        let limit = mem::replace(self.limit.get_mut(), usize::MAX);
+        if limit < self.inner.get_mut().cache.len() {
+            self.discard_cached_from(limit);
+        }
+    }
+
+    /// Drop the cached items from `len` on, and everything remembered about them
+    pub(crate) fn discard_cached_from(&mut self, len: usize) {
+        self.inner.get_mut().cache.truncate(len);
+        self.sorted.clear();
+        let primary = self.primary.get_mut();
+        *primary = (*primary).min(len);
+        self.generation += 1;
+    }
+}
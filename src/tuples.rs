@@ -0,0 +1,273 @@
+use crate::{CopyReiterate, CopyReiterator, ReiterableItem, Reiterate, Reiterator};
+
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Iterate over overlapping pairs of consecutive items.
+    ///
+    /// This is `tuple_windows::<2>()` with tuples instead of arrays.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new(vec!["a", "b", "c"]);
+    /// let pairs: Vec<_> = reiterate.pairs().collect();
+    /// assert_eq!(pairs, [("a", "b"), ("b", "c")]);
+    /// ```
+    pub fn pairs(
+        &self,
+    ) -> impl Iterator<Item = (&<I::Item as Deref>::Target, &<I::Item as Deref>::Target)> + '_ {
+        self.tuple_windows::<2>().map(|[a, b]| (a, b))
+    }
+
+    /// Iterate over overlapping windows of `N` consecutive items.
+    ///
+    /// Windows are produced as the stream is pulled, one item at a time, so this never
+    /// forces the underlying iterator to be exhausted. A stream shorter than `N` yields
+    /// no windows at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = Reiterate::new((1..).map(Box::new).inspect(|_| pulls.set(pulls.get() + 1)));
+    ///
+    /// let mut windows = reiterate.tuple_windows::<3>();
+    /// assert_eq!(windows.next(), Some([&1, &2, &3]));
+    /// assert_eq!(pulls.get(), 3);
+    /// assert_eq!(windows.next(), Some([&2, &3, &4]));
+    /// assert_eq!(pulls.get(), 4);
+    ///
+    /// // replaying comes from the cache
+    /// let replay: Vec<_> = reiterate.tuple_windows::<3>().take(2).collect();
+    /// assert_eq!(replay, [[&1, &2, &3], [&2, &3, &4]]);
+    /// assert_eq!(pulls.get(), 4);
+    ///
+    /// let short = Reiterate::new(vec!["a", "b"]);
+    /// assert_eq!(short.tuple_windows::<3>().next(), None);
+    /// ```
+    pub fn tuple_windows<const N: usize>(&self) -> TupleWindows<'_, I, N> {
+        assert!(N > 0, "window size must be non-zero");
+        TupleWindows {
+            ahead: self.into_iter(),
+            start: 0,
+        }
+    }
+}
+
+/// An iterator over overlapping windows of a `Reiterate`, produced by `tuple_windows()`
+pub struct TupleWindows<'a, I, const N: usize>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    // Positioned after the last item of the next window, once it is pulled
+    ahead: Reiterator<'a, I>,
+    start: usize,
+}
+
+impl<'a, I, const N: usize> Iterator for TupleWindows<'a, I, N>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    type Item = [&'a <I::Item as Deref>::Target; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.ahead.curr < self.start + N {
+            self.ahead.next()?;
+        }
+        let cache = &self.ahead.iterable.cache;
+        let start = self.start;
+        self.start += 1;
+        Some(std::array::from_fn(|i| &cache[start + i]))
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Iterate over overlapping pairs of consecutive items.
+    ///
+    /// This is `tuple_windows::<2>()` with tuples instead of arrays.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new(vec![1, 4, 9, 16]);
+    /// let gaps: Vec<_> = reiterate.pairs().map(|(a, b)| b - a).collect();
+    /// assert_eq!(gaps, [3, 5, 7]);
+    /// ```
+    pub fn pairs(&self) -> impl Iterator<Item = (I::Item, I::Item)> + '_ {
+        self.tuple_windows::<2>().map(|[a, b]| (a, b))
+    }
+
+    /// Iterate over overlapping windows of `N` consecutive items.
+    ///
+    /// Windows are produced as the stream is pulled, one item at a time, so this never
+    /// forces the underlying iterator to be exhausted. A stream shorter than `N` yields
+    /// no windows at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let pulls = Cell::new(0);
+    /// let reiterate = CopyReiterate::new((1..).inspect(|_| pulls.set(pulls.get() + 1)));
+    ///
+    /// let first: Vec<_> = reiterate.tuple_windows::<3>().take(3).collect();
+    /// assert_eq!(first, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    /// assert_eq!(pulls.get(), 5);
+    ///
+    /// let replay: Vec<_> = reiterate.tuple_windows::<3>().take(3).collect();
+    /// assert_eq!(replay, first);
+    /// assert_eq!(pulls.get(), 5);
+    /// ```
+    pub fn tuple_windows<const N: usize>(&self) -> CopyTupleWindows<'_, I, N> {
+        assert!(N > 0, "window size must be non-zero");
+        self.stop_streaming();
+        CopyTupleWindows {
+            ahead: self.into_iter(),
+            start: 0,
+        }
+    }
+
+    /// Get overlapping windows of `n` consecutive items, as slices.
+    ///
+    /// This is `tuple_windows()` for a window size only known at runtime. The last `n`
+    /// items are kept in a scratch buffer so that each window is contiguous, while the
+    /// cache still records every item: windows over items that were already cached are
+    /// read from the cache without pulling. The windows borrow the buffer, so they're
+    /// produced by `next_window()` rather than by an `Iterator` impl.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let data: Vec<u32> = (0..10).map(|i| i * i).collect();
+    /// let pulls = Cell::new(0);
+    /// let reiterate = CopyReiterate::new(data.iter().copied().inspect(|_| pulls.set(pulls.get() + 1)));
+    ///
+    /// // a live pass, stopped early
+    /// let mut live = reiterate.windows_lazy(3);
+    /// let mut sums = Vec::new();
+    /// while let Some(window) = live.next_window() {
+    ///     sums.push(window.iter().sum::<u32>());
+    ///     if sums.len() == 4 {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(pulls.get(), 6);
+    ///
+    /// // a full replay agrees with it, and with `slice::windows`
+    /// let mut replay = reiterate.windows_lazy(3);
+    /// let mut windows = data.windows(3);
+    /// while let Some(window) = replay.next_window() {
+    ///     assert_eq!(Some(window), windows.next());
+    /// }
+    /// assert_eq!(windows.next(), None);
+    /// assert_eq!(pulls.get(), 10);
+    /// let replayed: Vec<u32> = data.windows(3).take(4).map(|w| w.iter().sum()).collect();
+    /// assert_eq!(sums, replayed);
+    ///
+    /// // a stream shorter than `n` has no windows
+    /// assert_eq!(reiterate.windows_lazy(11).next_window(), None);
+    /// ```
+    pub fn windows_lazy(&self, n: usize) -> LazyWindows<'_, I> {
+        assert!(n > 0, "window size must be non-zero");
+        self.stop_streaming();
+        LazyWindows {
+            ahead: self.into_iter(),
+            n,
+            buf: Vec::new(),
+            started: false,
+        }
+    }
+}
+
+/// An iterator over overlapping windows of a `CopyReiterate`, produced by `tuple_windows()`
+pub struct CopyTupleWindows<'a, I, const N: usize>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    // Positioned after the last item of the next window, once it is pulled
+    ahead: CopyReiterator<'a, I>,
+    start: usize,
+}
+
+impl<'a, I, const N: usize> Iterator for CopyTupleWindows<'a, I, N>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.ahead.curr < self.start + N {
+            self.ahead.next()?;
+        }
+        let inner = self.ahead.iterable.inner.borrow();
+        let start = self.start;
+        self.start += 1;
+        Some(std::array::from_fn(|i| inner.cache[start + i]))
+    }
+}
+
+/// Overlapping windows of a `CopyReiterate` produced as slices, by `windows_lazy()`
+pub struct LazyWindows<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    ahead: CopyReiterator<'a, I>,
+    n: usize,
+    // The current window is made of the last `n` items; older ones are only dropped
+    // once there are `2 * n`, so each item gets moved at most once on average
+    buf: Vec<I::Item>,
+    // Whether the first window has been filled
+    started: bool,
+}
+
+impl<'a, I> LazyWindows<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Advance to the next window
+    pub fn next_window(&mut self) -> Option<&[I::Item]> {
+        if self.started {
+            let item = self.ahead.next()?;
+            if self.buf.len() >= self.n.saturating_mul(2) {
+                self.buf.drain(..self.buf.len() - (self.n - 1));
+            }
+            self.buf.push(item);
+        } else {
+            while self.buf.len() < self.n {
+                let item = self.ahead.next()?;
+                self.buf.push(item);
+            }
+            self.started = true;
+        }
+        Some(&self.buf[self.buf.len() - self.n..])
+    }
+}
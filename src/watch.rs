@@ -0,0 +1,142 @@
+use crate::{ContentionAware, CopyReiterate, ReiterableItem, Reiterate};
+
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a watcher that sees the items as they get cached by the handles, without ever
+    /// pulling from the underlying iterator itself.
+    ///
+    /// The watcher starts at the items cached from now on; use a handle to replay the
+    /// items cached so far.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    ///
+    /// let reiterate = Reiterate::new((0..10).map(Box::new));
+    /// let mut driver = reiterate.into_iter();
+    /// driver.next();
+    ///
+    /// let mut fast = reiterate.watch();
+    /// let mut slow = reiterate.watch();
+    /// let (mut seen_fast, mut seen_slow) = (Vec::new(), Vec::new());
+    /// for step in 0..9 {
+    ///     driver.next();
+    ///     seen_fast.extend(fast.poll_new().copied());
+    ///     if step % 4 == 0 {
+    ///         seen_slow.extend(slow.poll_new().copied());
+    ///     }
+    /// }
+    /// seen_slow.extend(slow.poll_new().copied());
+    ///
+    /// assert_eq!(seen_fast, (1..10).collect::<Vec<_>>());
+    /// assert_eq!(seen_slow, (1..10).collect::<Vec<_>>());
+    /// // watching never pulls
+    /// assert_eq!(fast.poll_new().count(), 0);
+    /// assert_eq!(reiterate.iter_tail().next(), None);
+    /// ```
+    pub fn watch(&self) -> Watcher<'_, I> {
+        Watcher {
+            iterable: self,
+            seen: self.visible_len(),
+        }
+    }
+}
+
+/// An observer of the items cached by the handles of a `Reiterate`, produced by `watch()`
+pub struct Watcher<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a Reiterate<I>,
+    // The number of cached items when last polled
+    seen: usize,
+}
+
+impl<'a, I> Watcher<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Iterate over the items cached since the last poll.
+    ///
+    /// If the `Reiterate` was truncated below what had been seen, the items cached again
+    /// after that count as new.
+    pub fn poll_new(&mut self) -> impl Iterator<Item = &'a <I::Item as Deref>::Target> {
+        let iterable = self.iterable;
+        let len = iterable.visible_len();
+        let start = self.seen.min(len);
+        self.seen = len;
+        (start..len).map(move |idx| &iterable.cache[idx])
+    }
+}
+
+impl<I> CopyReiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Get a watcher that sees the items as they get cached by the handles, without ever
+    /// pulling from the underlying iterator itself.
+    ///
+    /// This is the `Copy` counterpart of `Reiterate::watch()`.
+    ///
+    /// ```rust
+    /// use reiterate::CopyReiterate;
+    ///
+    /// let reiterate = CopyReiterate::new("abc".chars());
+    /// let mut watcher = reiterate.watch();
+    /// let mut driver = reiterate.into_iter();
+    /// assert_eq!(driver.next(), Some('a'));
+    /// assert_eq!(watcher.poll_new().collect::<String>(), "a");
+    /// assert_eq!(driver.count(), 2);
+    /// assert_eq!(watcher.poll_new().collect::<String>(), "bc");
+    /// ```
+    pub fn watch(&self) -> CopyWatcher<'_, I> {
+        // The watcher needs everything to be cached
+        self.stop_streaming();
+        CopyWatcher {
+            iterable: self,
+            seen: self.try_visible_len().ready(),
+        }
+    }
+}
+
+/// An observer of the items cached by the handles of a `CopyReiterate`, produced by
+/// `watch()`
+pub struct CopyWatcher<'a, I>
+where
+    I: Iterator + ?Sized,
+{
+    iterable: &'a CopyReiterate<I>,
+    // The number of cached items when last polled, unless the cache couldn't be looked
+    // at yet because an item was being pulled
+    seen: Option<usize>,
+}
+
+impl<'a, I> CopyWatcher<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Copy,
+{
+    /// Iterate over the items cached since the last poll.
+    ///
+    /// This is the `Copy` counterpart of `Watcher::poll_new()`. During a pull, nothing is
+    /// new yet: the items show up in the next poll.
+    pub fn poll_new(&mut self) -> impl Iterator<Item = I::Item> {
+        let limit = self.iterable.limit.get();
+        let new = match ContentionAware::borrow(&self.iterable.inner) {
+            ContentionAware::Ready(inner) => {
+                let len = inner.cache.len().min(limit);
+                let start = self.seen.unwrap_or(len).min(len);
+                self.seen = Some(len);
+                inner.cache[start..len].to_vec()
+            }
+            ContentionAware::Contended => Vec::new(),
+        };
+        new.into_iter()
+    }
+}
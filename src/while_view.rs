@@ -0,0 +1,271 @@
+use crate::{ReiterableItem, Reiterate, Reiterator};
+
+use std::cell::Cell;
+use std::ops::Deref;
+
+impl<I> Reiterate<I>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+{
+    /// Get a view of the items after the leading ones matching `pred`, like `skip_while`.
+    ///
+    /// Where the view starts is found once and remembered, so `pred` runs at most once per
+    /// item no matter how many times the view is iterated, and nothing past the first item
+    /// not matching `pred` is pulled before it's needed.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let lines = vec!["", "", "title", "", "body"];
+    /// let reiterate = Reiterate::new(lines.clone());
+    /// let trimmed = reiterate.skip_while_view(|line| {
+    ///     calls.set(calls.get() + 1);
+    ///     line.is_empty()
+    /// });
+    /// for _ in 0..3 {
+    ///     assert_eq!(trimmed.iter().collect::<Vec<_>>(), ["title", "", "body"]);
+    /// }
+    /// assert_eq!(calls.get(), 3);
+    ///
+    /// // a view made after the items are cached agrees
+    /// let late = reiterate.skip_while_view(|line| line.is_empty());
+    /// assert_eq!(late.iter().collect::<Vec<_>>(), ["title", "", "body"]);
+    ///
+    /// // if every item matches, the view is empty
+    /// let blank = Reiterate::new(vec!["", ""]);
+    /// assert_eq!(blank.skip_while_view(|line| line.is_empty()).iter().next(), None);
+    /// ```
+    pub fn skip_while_view<P>(&self, pred: P) -> SkipWhileView<'_, I, P>
+    where
+        P: Fn(&<I::Item as Deref>::Target) -> bool,
+    {
+        SkipWhileView {
+            iterable: self,
+            pred,
+            scanned: Cell::new(0),
+            start: Cell::new(None),
+        }
+    }
+
+    /// Get a view of the leading items matching `pred`, like `take_while`.
+    ///
+    /// Where the view ends is found once and remembered, so `pred` runs at most once per
+    /// item no matter how many times the view is iterated, and nothing past the end of the
+    /// view is pulled.
+    ///
+    /// ```rust
+    /// use reiterate::Reiterate;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let pulls = Cell::new(0);
+    /// let headers = vec!["Host: a", "Accept: b", "", "body", "more body"];
+    /// let reiterate = Reiterate::new(headers.into_iter().inspect(|_| pulls.set(pulls.get() + 1)));
+    /// let head = reiterate.take_while_view(|line| {
+    ///     calls.set(calls.get() + 1);
+    ///     !line.is_empty()
+    /// });
+    /// for _ in 0..3 {
+    ///     assert_eq!(head.iter().collect::<Vec<_>>(), ["Host: a", "Accept: b"]);
+    /// }
+    /// assert_eq!(calls.get(), 3);
+    /// assert_eq!(pulls.get(), 3);
+    ///
+    /// // a view made after the items are cached agrees
+    /// reiterate.into_iter().for_each(drop);
+    /// let late = reiterate.take_while_view(|line| !line.is_empty());
+    /// assert_eq!(late.iter().collect::<Vec<_>>(), ["Host: a", "Accept: b"]);
+    ///
+    /// // if every item matches, the view has everything
+    /// let all = reiterate.take_while_view(|_| true);
+    /// assert_eq!(all.iter().count(), 5);
+    /// ```
+    pub fn take_while_view<P>(&self, pred: P) -> TakeWhileView<'_, I, P>
+    where
+        P: Fn(&<I::Item as Deref>::Target) -> bool,
+    {
+        TakeWhileView {
+            iterable: self,
+            pred,
+            scanned: Cell::new(0),
+            end: Cell::new(None),
+        }
+    }
+}
+
+/// A view of a `Reiterate` without its leading items matching a predicate,
+/// produced by `skip_while_view()`
+pub struct SkipWhileView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    iterable: &'a Reiterate<I>,
+    pred: P,
+    // How many leading items are known to match `pred`
+    scanned: Cell<usize>,
+    // The index of the first item not matching `pred`, once found
+    start: Cell<Option<usize>>,
+}
+
+impl<'a, I, P> SkipWhileView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> SkipWhileReiterator<'_, 'a, I, P> {
+        SkipWhileReiterator {
+            view: self,
+            iter: None,
+        }
+    }
+
+    /// Find the index of the first item not matching `pred`, returning `None` if the
+    /// stream ends first
+    fn start(&self) -> Option<usize> {
+        loop {
+            if let Some(start) = self.start.get() {
+                return Some(start);
+            }
+            let idx = self.scanned.get();
+            if (self.pred)(self.iterable.get_or_pull(idx)?) {
+                self.scanned.set(idx + 1);
+            } else {
+                self.start.set(Some(idx));
+            }
+        }
+    }
+}
+
+impl<'v, 'a, I, P> IntoIterator for &'v SkipWhileView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type IntoIter = SkipWhileReiterator<'v, 'a, I, P>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `SkipWhileView`, produced by calling `.iter()`
+pub struct SkipWhileReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    view: &'v SkipWhileView<'a, I, P>,
+    // A handle positioned past the skipped items, once they've been found
+    iter: Option<Reiterator<'a, I>>,
+}
+
+impl<'v, 'a, I, P> Iterator for SkipWhileReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.is_none() {
+            self.iter = Some(Reiterator {
+                iterable: self.view.iterable,
+                curr: self.view.start()?,
+            });
+        }
+        self.iter.as_mut()?.next()
+    }
+}
+
+/// A view of the leading items of a `Reiterate` matching a predicate,
+/// produced by `take_while_view()`
+pub struct TakeWhileView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    iterable: &'a Reiterate<I>,
+    pred: P,
+    // How many leading items are known to match `pred`
+    scanned: Cell<usize>,
+    // The index of the first item not matching `pred`, once found
+    end: Cell<Option<usize>>,
+}
+
+impl<'a, I, P> TakeWhileView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    /// Iterate over the view
+    pub fn iter(&self) -> TakeWhileReiterator<'_, 'a, I, P> {
+        TakeWhileReiterator {
+            view: self,
+            curr: 0,
+        }
+    }
+}
+
+impl<'v, 'a, I, P> IntoIterator for &'v TakeWhileView<'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type IntoIter = TakeWhileReiterator<'v, 'a, I, P>;
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a `TakeWhileView`, produced by calling `.iter()`
+pub struct TakeWhileReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    view: &'v TakeWhileView<'a, I, P>,
+    curr: usize,
+}
+
+impl<'v, 'a, I, P> Iterator for TakeWhileReiterator<'v, 'a, I, P>
+where
+    I: Iterator + ?Sized,
+    I::Item: ReiterableItem,
+    P: Fn(&<I::Item as Deref>::Target) -> bool,
+{
+    type Item = &'a <I::Item as Deref>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let view = self.view;
+        if view.end.get().is_some_and(|end| self.curr >= end) {
+            return None;
+        }
+        let item = view.iterable.get_or_pull(self.curr)?;
+        if self.curr >= view.scanned.get() {
+            if !(view.pred)(item) {
+                view.end.set(Some(self.curr));
+                return None;
+            }
+            view.scanned.set(self.curr + 1);
+        }
+        self.curr += 1;
+        Some(item)
+    }
+}
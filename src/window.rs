@@ -0,0 +1,96 @@
+use crate::{Reiterable, TrackedReiterate, TrackedReiterator};
+
+use std::ops::Deref;
+
+/// An adaptor around an iterator over Copy items that only keeps the last `K` items it
+/// pulled replayable, for endless streams like telemetry.
+///
+/// This is a `TrackedReiterate::with_max_cached()` with the window size in the type, and
+/// derefs to it. The items are kept in a ring buffer: once `K` are cached, pulling an
+/// item evicts the oldest one. New cursors start at the oldest item still retained, so
+/// they replay the whole window before catching up with the stream, and a cursor that
+/// falls behind the window yields `None` from then on.
+///
+/// ```rust
+/// use reiterate::WindowedReiterate;
+///
+/// let readings: WindowedReiterate<_, 3> = WindowedReiterate::new((0..).map(|i| i * 10));
+/// let mut live = readings.into_iter();
+/// assert_eq!(live.nth(5), Some(50));
+/// assert_eq!(readings.cached_len(), 3);
+///
+/// // a new cursor starts at the oldest retained item
+/// let mut late = readings.into_iter();
+/// assert_eq!(late.by_ref().take(3).collect::<Vec<_>>(), [30, 40, 50]);
+/// assert_eq!(live.next(), Some(60));
+/// assert_eq!(late.next(), Some(60));
+///
+/// // memory stays bounded however far the stream goes
+/// assert_eq!(live.nth(1_000_000), Some(10_000_070));
+/// assert_eq!(readings.cached_len(), 3);
+/// assert!(late.fell_behind());
+/// ```
+pub struct WindowedReiterate<I, const K: usize>
+where
+    I: Iterator,
+{
+    inner: TrackedReiterate<I>,
+}
+
+impl<I, const K: usize> WindowedReiterate<I, K>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = I::Item, IntoIter = I>,
+    {
+        WindowedReiterate {
+            inner: TrackedReiterate::with_max_cached(iter, K),
+        }
+    }
+}
+
+impl<'a, I, const K: usize> IntoIterator for &'a WindowedReiterate<I, K>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type IntoIter = TrackedReiterator<'a, I>;
+    type Item = I::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<I, const K: usize> Reiterable for WindowedReiterate<I, K>
+where
+    I: Iterator,
+    I::Item: Copy,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = TrackedReiterator<'a, I>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> TrackedReiterator<'_, I> {
+        self.into_iter()
+    }
+}
+
+impl<I, const K: usize> Deref for WindowedReiterate<I, K>
+where
+    I: Iterator,
+{
+    type Target = TrackedReiterate<I>;
+
+    fn deref(&self) -> &TrackedReiterate<I> {
+        &self.inner
+    }
+}
@@ -0,0 +1,349 @@
+//! Handles behave like the iterator they wrap under std adapters: `assert_iter_behaves`
+//! runs a matrix of adapter chains over a plain source and over handles of a fresh
+//! `Reiterate` and `CopyReiterate` of the same source, and checks that they yield the
+//! same items, report the same size hints, and call `next()` on the source as many times.
+
+use reiterate::{CopyReiterate, Reiterate};
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// A source that counts the calls to its `next()`
+struct Counted<I> {
+    iter: I,
+    calls: Rc<Cell<usize>>,
+}
+
+impl<I: Iterator> Iterator for Counted<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.calls.set(self.calls.get() + 1);
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+type Source<'a> = Box<dyn Iterator<Item = u32> + 'a>;
+
+/// Something observed about an iterator, to be compared across implementations
+type Run = Box<dyn Fn(Source<'_>) -> Vec<String>>;
+
+fn observe<T: Debug>(val: T) -> String {
+    format!("{:?}", val)
+}
+
+/// Run `run` over `make()` as a plain iterator, then over handles of a fresh
+/// `Reiterate` and `CopyReiterate`, asserting that they observe the same things with the
+/// same number of calls to the source.
+///
+/// For `fused` sources, replays are checked to yield the same items too; the cache
+/// doesn't record where other sources returned `None`.
+fn assert_iter_behaves<I>(name: &str, make: impl Fn() -> I, run: &Run, fused: bool)
+where
+    I: Iterator<Item = u32>,
+{
+    let counted = || {
+        let calls = Rc::new(Cell::new(0));
+        let iter = Counted {
+            iter: make(),
+            calls: calls.clone(),
+        };
+        (iter, calls)
+    };
+    // Size hints can be tighter on replay, once the cache knows how many items there are
+    let check_replay = |replayed: Vec<String>, expected: &[String], flavor| {
+        if fused {
+            assert_eq!(
+                items(&replayed),
+                items(expected),
+                "{}: {} replay",
+                name,
+                flavor
+            );
+        }
+    };
+
+    let (plain, plain_calls) = counted();
+    let expected = run(Box::new(plain));
+
+    let (source, calls) = counted();
+    let reiterate = Reiterate::new(source.map(Box::new));
+    let observed = run(Box::new(reiterate.into_iter().copied()));
+    assert_eq!(observed, expected, "{}: Reiterator", name);
+    assert_eq!(calls.get(), plain_calls.get(), "{}: Reiterator pulls", name);
+    check_replay(
+        run(Box::new(reiterate.into_iter().copied())),
+        &expected,
+        "Reiterator",
+    );
+    check_replay(
+        run(Box::new(reiterate.into_iter().to_owned_iter())),
+        &expected,
+        "ClonedReiterator",
+    );
+
+    let (source, calls) = counted();
+    let reiterate = CopyReiterate::new(source);
+    let observed = run(Box::new(reiterate.into_iter()));
+    assert_eq!(observed, expected, "{}: CopyReiterator", name);
+    assert_eq!(
+        calls.get(),
+        plain_calls.get(),
+        "{}: CopyReiterator pulls",
+        name
+    );
+    check_replay(
+        run(Box::new(reiterate.into_iter())),
+        &expected,
+        "CopyReiterator",
+    );
+    check_replay(
+        run(Box::new(reiterate.into_iter().copied_compat())),
+        &expected,
+        "CopiedReiterator",
+    );
+
+    // the first handle of a lazily caching one streams the items instead
+    let (source, calls) = counted();
+    let reiterate = CopyReiterate::lazy_caching(source);
+    let observed = run(Box::new(reiterate.into_iter()));
+    assert_eq!(observed, expected, "{}: streaming CopyReiterator", name);
+    assert_eq!(calls.get(), plain_calls.get(), "{}: streaming pulls", name);
+}
+
+/// What `run` observed, without the size hints
+fn items(run: &[String]) -> Vec<&String> {
+    run.iter().filter(|seen| !seen.starts_with('(')).collect()
+}
+
+/// Step through `iter`, recording each item and the size hint before it
+fn trace(mut iter: impl Iterator<Item = u32>) -> Vec<String> {
+    let mut trace = Vec::new();
+    loop {
+        trace.push(observe(iter.size_hint()));
+        match iter.next() {
+            Some(item) => trace.push(observe(item)),
+            None => break,
+        }
+    }
+    // `next()` after `None` keeps returning `None` for fused sources
+    for _ in 0..3 {
+        trace.push(observe(iter.next()));
+    }
+    trace
+}
+
+fn runs() -> Vec<(String, Run)> {
+    let mut runs: Vec<(String, Run)> = vec![
+        ("trace".into(), Box::new(|it| trace(it))),
+        ("count".into(), Box::new(|it| vec![observe(it.count())])),
+        ("last".into(), Box::new(|it| vec![observe(it.last())])),
+        ("sum".into(), Box::new(|it| vec![observe(it.sum::<u32>())])),
+        (
+            "find then count".into(),
+            Box::new(|mut it| vec![observe(it.find(|&i| i == 3)), observe(it.count())]),
+        ),
+        (
+            "position then max".into(),
+            Box::new(|mut it| vec![observe(it.position(|i| i > 1)), observe(it.max())]),
+        ),
+        (
+            "chain empty".into(),
+            Box::new(|it| trace(it.chain(std::iter::empty()))),
+        ),
+        (
+            "empty chain".into(),
+            Box::new(|it| trace(std::iter::empty().chain(it))),
+        ),
+        (
+            "peekable".into(),
+            Box::new(|it| {
+                let mut it = it.peekable();
+                let mut out = Vec::new();
+                while let Some(&item) = it.peek() {
+                    out.push(observe(item));
+                    if item % 2 == 0 {
+                        out.push(observe(it.next()));
+                    } else {
+                        out.push(observe(it.nth(1)));
+                    }
+                }
+                out.push(observe(it.next()));
+                out
+            }),
+        ),
+        (
+            "skip_while take_while".into(),
+            Box::new(|it| trace(it.skip_while(|&i| i < 2).take_while(|&i| i < 7))),
+        ),
+        (
+            "by_ref take then rest".into(),
+            Box::new(|mut it| {
+                let mut out = trace(it.by_ref().take(2));
+                out.extend(trace(it));
+                out
+            }),
+        ),
+        (
+            "zip with self-paced".into(),
+            Box::new(|it| trace(it.zip(100..).map(|(a, b)| a + b))),
+        ),
+    ];
+    for n in [0, 1, 3, 50] {
+        runs.push((
+            format!("skip({})", n),
+            Box::new(move |it| trace(it.skip(n))),
+        ));
+        runs.push((
+            format!("take({})", n),
+            Box::new(move |it| trace(it.take(n))),
+        ));
+        runs.push((
+            format!("skip({}) take(2)", n),
+            Box::new(move |it| trace(it.skip(n).take(2))),
+        ));
+        runs.push((
+            format!("nth({})", n),
+            Box::new(move |mut it| {
+                vec![observe(it.nth(n)), observe(it.nth(n)), observe(it.next())]
+            }),
+        ));
+        runs.push((
+            format!("zip short {}", n),
+            Box::new(move |it| trace(it.zip(0..n as u32).map(|(a, b)| a * 100 + b))),
+        ));
+        runs.push((
+            format!("short {} zip", n),
+            Box::new(move |it| trace((0..n as u32).zip(it).map(|(a, b)| a * 100 + b))),
+        ));
+    }
+    for step in [1, 2, 3, 7] {
+        runs.push((
+            format!("step_by({})", step),
+            Box::new(move |it| trace(it.step_by(step))),
+        ));
+        runs.push((
+            format!("skip(1) step_by({})", step),
+            Box::new(move |it| trace(it.skip(1).step_by(step))),
+        ));
+    }
+    runs
+}
+
+#[test]
+fn adapters_over_ranges() {
+    for len in [0, 1, 2, 5, 20] {
+        for (name, run) in &runs() {
+            assert_iter_behaves(&format!("{} over 0..{}", name, len), || 0..len, run, true);
+        }
+    }
+}
+
+/// A source that isn't fused: it returns `None` after every third item, then resumes
+struct Stuttering {
+    next: u32,
+    len: u32,
+    paused: bool,
+}
+
+impl Iterator for Stuttering {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.next >= self.len || (self.next.is_multiple_of(3) && !self.paused && self.next > 0) {
+            self.paused = true;
+            return None;
+        }
+        self.paused = false;
+        self.next += 1;
+        Some(self.next - 1)
+    }
+}
+
+#[test]
+fn adapters_over_unfused_source() {
+    for len in [0, 4, 10] {
+        for (name, run) in &runs() {
+            let stuttering = move || Stuttering {
+                next: 0,
+                len,
+                paused: false,
+            };
+            assert_iter_behaves(
+                &format!("{} over stuttering {}", name, len),
+                stuttering,
+                run,
+                false,
+            );
+        }
+    }
+}
+
+#[test]
+fn adapters_over_unsized_hints() {
+    // a source whose size hint is only a bound
+    for len in [0, 3, 10] {
+        for (name, run) in &runs() {
+            let filtered = move || (0..len * 2).filter(|i| i % 2 == 0);
+            assert_iter_behaves(
+                &format!("{} over filter {}", name, len),
+                filtered,
+                run,
+                true,
+            );
+        }
+    }
+}
+
+#[test]
+fn size_hints_add_the_cached_items_to_the_source_hint() {
+    let filtered = || (0..20u32).filter(|i| i % 3 == 0);
+
+    let reiterate = Reiterate::new(filtered().map(Box::new));
+    let mut iter = reiterate.into_iter();
+    iter.next();
+    // until then, the source only gives a bound
+    assert_eq!(reiterate.into_iter().size_hint(), (1, Some(20)));
+    assert_eq!(iter.count(), 6);
+    assert_eq!(reiterate.into_iter().size_hint(), (7, Some(7)));
+    assert_eq!(reiterate.into_iter().skip(2).size_hint(), (5, Some(5)));
+    // a queued source makes the stream go on
+    reiterate.queue_source(filtered().map(Box::new));
+    assert_eq!(reiterate.into_iter().size_hint(), (7, Some(27)));
+
+    let reiterate = CopyReiterate::new(filtered());
+    assert_eq!(reiterate.into_iter().size_hint(), (0, Some(20)));
+    assert_eq!(reiterate.exhaust(), 7);
+    assert_eq!(reiterate.into_iter().size_hint(), (7, Some(7)));
+    reiterate.queue_source(filtered());
+    assert_eq!(reiterate.into_iter().size_hint(), (7, Some(27)));
+}
+
+#[test]
+fn count_and_last_skip_the_cached_items() {
+    let reiterate = Reiterate::with_stats((0..100u32).map(Box::new));
+    let mut iter = reiterate.into_iter();
+    assert_eq!(iter.nth(49), Some(&49));
+    // the rest of the stream is pulled and cached for the other handles
+    assert_eq!(reiterate.into_iter().skip(10).last(), Some(&99));
+    assert_eq!(reiterate.cached_len(), 100);
+    assert_eq!(iter.next(), Some(&50));
+    assert_eq!(reiterate.into_iter().skip(99).last(), Some(&99));
+
+    let hits = reiterate.stats().unwrap().hits;
+    assert_eq!(reiterate.into_iter().count(), 100);
+    assert_eq!(reiterate.into_iter().last(), Some(&99));
+    assert_eq!(reiterate.iter_tail().last(), None);
+    // only the last item was read
+    assert_eq!(reiterate.stats().unwrap().hits, hits + 1);
+
+    let reiterate = CopyReiterate::new(0..10u32);
+    assert_eq!(reiterate.into_iter().take(3).last(), Some(2));
+    assert_eq!(reiterate.into_iter().last(), Some(9));
+    assert_eq!(reiterate.into_iter().skip(9).last(), Some(9));
+    assert_eq!(reiterate.iter_tail().last(), None);
+}
@@ -0,0 +1,171 @@
+//! Creating and rewinding handles never allocates, so hot loops can make a fresh handle
+//! per request, or keep rewinding one, `compact()` gives back the memory reserved
+//! beyond the cached items, and `with_capacity()` reserves it up front.
+
+use reiterate::{CopyReiterate, Reiterate};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The system allocator, counting the allocations of each thread and the bytes they
+/// hold, since tests run in parallel
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        LIVE_BYTES.with(|bytes| bytes.set(bytes.get() + layout.size() as isize));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.with(|bytes| bytes.set(bytes.get() - layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// The number of allocations made by `f` on this thread
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    drop(f());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// The number of bytes `f` freed on this thread, net of what it allocated
+fn freed_bytes(f: impl FnOnce()) -> isize {
+    let before = LIVE_BYTES.with(Cell::get);
+    f();
+    before - LIVE_BYTES.with(Cell::get)
+}
+
+#[test]
+fn handle_creation_is_allocation_free() {
+    let reiterate = Reiterate::new((0..100).map(Box::new));
+    assert_eq!(reiterate.into_iter().count(), 100);
+    let replayed = allocations(|| {
+        let mut sum = 0;
+        for _ in 0..1000 {
+            sum += (&reiterate).into_iter().take(10).sum::<i32>();
+        }
+        sum
+    });
+    assert_eq!(replayed, 0);
+    assert_eq!(allocations(|| reiterate.iter_tail()), 0);
+
+    let reiterate = CopyReiterate::new(0..100);
+    assert_eq!(reiterate.into_iter().count(), 100);
+    let replayed = allocations(|| {
+        let mut sum = 0;
+        for _ in 0..1000 {
+            sum += (&reiterate).into_iter().take(10).sum::<i32>();
+        }
+        sum
+    });
+    assert_eq!(replayed, 0);
+
+    // the first handle of a lazily caching one doesn't allocate either
+    let lazy = CopyReiterate::lazy_caching(0..100);
+    assert_eq!(allocations(|| lazy.into_iter().sum::<i32>()), 0);
+    assert_eq!(allocations(|| lazy.into_iter()), 0);
+}
+
+#[test]
+fn rewind_is_allocation_free() {
+    let reiterate = Reiterate::new((0..100).map(Box::new));
+    let mut iter = reiterate.into_iter();
+    assert_eq!(iter.by_ref().count(), 100);
+    let rewound = allocations(|| {
+        for _ in 0..1000 {
+            iter.rewind();
+            assert_eq!(iter.nth(50), Some(&50));
+            iter.rewind_to(99);
+            assert_eq!(iter.next(), Some(&99));
+        }
+    });
+    assert_eq!(rewound, 0);
+
+    let reiterate = CopyReiterate::new(0..100);
+    let mut iter = reiterate.into_iter();
+    assert_eq!(iter.by_ref().count(), 100);
+    let rewound = allocations(|| {
+        for _ in 0..1000 {
+            iter.rewind();
+            assert_eq!(iter.nth(50), Some(50));
+        }
+    });
+    assert_eq!(rewound, 0);
+}
+
+#[test]
+fn rewound_handle_sees_growth_and_truncation() {
+    let reiterate = Reiterate::new((0..10).map(Box::new));
+    let mut iter = reiterate.into_iter();
+    assert_eq!(iter.nth(2), Some(&2));
+    // another handle grows the cache past this one
+    assert_eq!(reiterate.into_iter().count(), 10);
+    iter.rewind_to(9);
+    assert_eq!(iter.next(), Some(&9));
+    assert_eq!(iter.next(), None);
+
+    reiterate.truncate(4);
+    iter.rewind();
+    assert_eq!(iter.by_ref().count(), 4);
+    iter.rewind_to(6);
+    assert_eq!(iter.next(), None);
+
+    let reiterate = CopyReiterate::new(0..10);
+    let mut iter = reiterate.into_iter();
+    assert_eq!(iter.nth(8), Some(8));
+    reiterate.truncate(3);
+    assert_eq!(iter.next(), None);
+    iter.rewind();
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn rewind_forward_pulls_up_to_position() {
+    let reiterate = CopyReiterate::new(0..10);
+    let mut iter = reiterate.into_iter();
+    iter.rewind_to(4);
+    assert_eq!(reiterate.cached_len().ready(), Some(4));
+    assert_eq!(iter.next(), Some(4));
+    iter.rewind_to(20);
+    assert_eq!(iter.next(), None);
+    assert_eq!(reiterate.cached_len().ready(), Some(10));
+}
+
+#[test]
+fn compact_gives_back_reserved_memory() {
+    let mut reiterate = CopyReiterate::new(0..1000u32);
+    assert_eq!(reiterate.into_iter().count(), 1000);
+    // the cache had room for more
+    assert!(freed_bytes(|| reiterate.compact()) > 0);
+    // and is already of the exact size the second time
+    assert_eq!(allocations(|| reiterate.compact()), 0);
+    assert_eq!(freed_bytes(|| reiterate.compact()), 0);
+    assert!(reiterate.into_iter().eq(0..1000));
+    // the cache grows again from there
+    reiterate.queue_source(1000..1010);
+    assert!(reiterate.into_iter().eq(0..1010));
+}
+
+#[test]
+fn preallocated_cache_never_grows() {
+    let reiterate = Reiterate::with_capacity((0..100).map(Box::new), 100);
+    // one allocation per box, and none for the cache
+    assert_eq!(allocations(|| reiterate.into_iter().count()), 100);
+    // growing past the capacity reallocates as usual
+    reiterate.queue_source((100..101).map(Box::new));
+    assert!(allocations(|| reiterate.into_iter().count()) > 1);
+
+    let reiterate = CopyReiterate::with_capacity(0..100, 100);
+    assert_eq!(allocations(|| reiterate.into_iter().count()), 0);
+    assert_eq!(allocations(|| CopyReiterate::with_capacity(0..100, 0)), 0);
+}
@@ -0,0 +1,83 @@
+//! Reiterates generated by `Arbitrary` from random bytes replay the items generated from
+//! the same bytes, whatever state partway through their life they were generated in.
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use reiterate::{CopyReiterate, Reiterate};
+use std::vec;
+
+const CASES: usize = 2000;
+
+/// Random inputs of random lengths, the same on every run
+fn inputs() -> impl Iterator<Item = Vec<u8>> {
+    let mut rng = ChaCha8Rng::seed_from_u64(177);
+    (0..CASES).map(move |_| {
+        let mut bytes = vec![0; (rng.next_u32() % 256) as usize];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    })
+}
+
+#[test]
+fn copy_reiterate_states_replay_their_items() {
+    let mut mid_life = 0;
+    for bytes in inputs() {
+        let items = Vec::<u16>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let reiterate =
+            CopyReiterate::<vec::IntoIter<u16>>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let cached = reiterate.cached_len().ready().unwrap();
+        assert!(cached <= items.len());
+        if cached > 0 {
+            mid_life += 1;
+        }
+        assert!(!reiterate.is_poisoned());
+
+        // handles interleave from the generated state as from a fresh one
+        let (a, mut b) = (reiterate.into_iter(), reiterate.into_iter());
+        let mut first = Vec::new();
+        for item in a {
+            first.push(item);
+            assert_eq!(b.next(), Some(item));
+        }
+        assert_eq!(b.next(), None);
+        assert_eq!(first, items);
+        assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), items);
+        assert_eq!(reiterate.cached_len().ready(), Some(items.len()));
+    }
+    assert!(
+        mid_life > CASES / 4,
+        "only {} states were mid-life",
+        mid_life
+    );
+}
+
+#[test]
+fn reiterate_states_replay_their_items() {
+    let mut mid_life = 0;
+    for bytes in inputs() {
+        let items = Vec::<Box<String>>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let reiterate =
+            Reiterate::<vec::IntoIter<Box<String>>>::arbitrary(&mut Unstructured::new(&bytes))
+                .unwrap();
+        let cached = reiterate.cached_len();
+        assert!(cached <= items.len());
+        if cached > 0 {
+            mid_life += 1;
+        }
+        assert!(!reiterate.is_poisoned());
+
+        // the tail picks up right after the cached prefix
+        let expected: Vec<&String> = items.iter().map(|item| &**item).collect();
+        let tail: Vec<_> = reiterate.iter_tail().collect();
+        assert_eq!(tail, expected[cached..]);
+        assert_eq!(reiterate.into_iter().collect::<Vec<_>>(), expected);
+        assert_eq!(reiterate.cached_len(), items.len());
+    }
+    assert!(
+        mid_life > CASES / 4,
+        "only {} states were mid-life",
+        mid_life
+    );
+}
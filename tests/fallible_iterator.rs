@@ -0,0 +1,72 @@
+//! `FallibleReiterate` replays the rows of a cursor that fails partway, without pulling
+//! from it again after the error.
+#![cfg(feature = "fallible-iterator")]
+
+use fallible_iterator::FallibleIterator;
+use reiterate::FallibleReiterate;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A cursor yielding `rows` boxed rows then an error, counting the calls to `next()`
+struct Cursor {
+    rows: u32,
+    calls: Rc<Cell<u32>>,
+}
+
+impl FallibleIterator for Cursor {
+    type Item = Box<u32>;
+    type Error = &'static str;
+
+    fn next(&mut self) -> Result<Option<Box<u32>>, &'static str> {
+        let call = self.calls.get();
+        self.calls.set(call + 1);
+        if call == self.rows {
+            Err("lost connection")
+        } else {
+            Ok(Some(Box::new(call)))
+        }
+    }
+}
+
+#[test]
+fn errors_are_replayed_without_pulling_again() {
+    let calls = Rc::new(Cell::new(0));
+    let rows = FallibleReiterate::new(Cursor {
+        rows: 3,
+        calls: calls.clone(),
+    });
+    let mut ahead = rows.iter();
+    let mut behind = rows.iter();
+    assert_eq!(ahead.next(), Ok(Some(&0)));
+    assert_eq!(rows.error().ready(), Some(None));
+
+    assert_eq!(ahead.nth(1), Ok(Some(&2)));
+    let err = ahead.next().unwrap_err();
+    assert_eq!(*err, "lost connection");
+    assert_eq!(ahead.next(), Ok(None));
+    assert!(Rc::ptr_eq(&rows.error().ready().unwrap().unwrap(), &err));
+    assert_eq!(calls.get(), 4);
+
+    // a handle behind gets the items, then the same error
+    assert_eq!(behind.next(), Ok(Some(&0)));
+    assert_eq!(behind.by_ref().take(2).count(), Ok(2));
+    assert!(Rc::ptr_eq(&behind.next().unwrap_err(), &err));
+    assert_eq!(behind.next(), Ok(None));
+
+    let items: Result<Vec<u32>, _> = rows.iter().take(3).map(|&row| Ok(row)).collect();
+    assert_eq!(items, Ok(vec![0, 1, 2]));
+    assert_eq!(rows.iter().count().unwrap_err(), err);
+    assert_eq!(calls.get(), 4);
+    assert_eq!(rows.cached_len(), 3);
+}
+
+#[test]
+fn sources_without_errors_end_normally() {
+    let rows = FallibleReiterate::new(fallible_iterator::convert(
+        vec![Ok::<_, ()>("a".to_string()), Ok("b".to_string())].into_iter(),
+    ));
+    for _ in 0..2 {
+        assert_eq!(rows.iter().collect::<Vec<_>>(), Ok(vec!["a", "b"]));
+    }
+    assert_eq!(rows.error().ready(), Some(None));
+}
@@ -0,0 +1,63 @@
+//! A `TrackedReiterate` streaming millions of items through consumers that move forward
+//! together keeps a bounded number of them cached, and so does one with a maximum
+//! cache size over an endless source.
+
+use reiterate::TrackedReiterate;
+
+const ITEMS: u64 = 4_000_000;
+// How far ahead of the other the fast consumer gets between two `forget_consumed()`
+const LAG: u64 = 1000;
+
+#[test]
+fn bounded_memory_with_two_consumers() {
+    let buffer = TrackedReiterate::new(0..ITEMS);
+    let (mut fast, mut slow) = (buffer.into_iter(), buffer.into_iter());
+    let (mut fast_sum, mut slow_sum) = (0, 0);
+    let mut peak = 0;
+    for item in fast.by_ref() {
+        fast_sum += item;
+        // `slow` follows `LAG` items behind
+        if item >= LAG {
+            slow_sum += slow.next().unwrap();
+        }
+        if item % LAG == 0 {
+            buffer.forget_consumed();
+        }
+        peak = peak.max(buffer.cached_len());
+    }
+    slow_sum += slow.by_ref().sum::<u64>();
+
+    let expected = ITEMS * (ITEMS - 1) / 2;
+    assert_eq!((fast_sum, slow_sum), (expected, expected));
+    assert!(
+        peak <= 2 * LAG as usize,
+        "{} items were cached at once",
+        peak
+    );
+    buffer.forget_consumed();
+    assert_eq!(buffer.cached_len(), 0);
+    assert_eq!(buffer.forgotten() as u64, ITEMS);
+}
+
+#[test]
+fn sliding_window_over_an_endless_source() {
+    const WINDOW: usize = 16;
+    let telemetry = TrackedReiterate::with_max_cached((0u64..).map(|i| i * 10), WINDOW);
+    let mut live = telemetry.into_iter();
+    for step in 1..=ITEMS / 100 {
+        live.next().unwrap();
+        assert!(telemetry.cached_len() <= WINDOW);
+        if step % 10_000 == 0 {
+            // A late cursor replays the last `WINDOW` items, up to the live one
+            let late: Vec<_> = telemetry.into_iter().take(WINDOW).collect();
+            let first = (step - WINDOW as u64) * 10;
+            assert_eq!(
+                late,
+                (0..WINDOW as u64)
+                    .map(|i| first + i * 10)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+    assert_eq!(telemetry.forgotten() as u64, ITEMS / 100 - WINDOW as u64);
+}
@@ -0,0 +1,146 @@
+//! Handles driven in random interleavings all observe the same sequence: the items of
+//! the source and of the queued sources, in order, each pulled once.
+
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use reiterate::{CopyReiterate, Reiterate};
+use std::cell::{Cell, OnceCell};
+use std::rc::{Rc, Weak};
+
+const SOURCE_LEN: u32 = 200;
+
+/// Positions and items observed by one handle
+type Observed = Vec<(usize, u32)>;
+
+fn schedule_seeds() -> impl Iterator<Item = u64> {
+    0..64
+}
+
+#[test]
+fn random_interleavings() {
+    for seed in schedule_seeds() {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let pulls = Cell::new(0);
+        let counted = |i| {
+            pulls.set(pulls.get() + 1);
+            Box::new(i)
+        };
+        let reiterate = Reiterate::new((0..SOURCE_LEN).map(&counted as &dyn Fn(u32) -> Box<u32>));
+        let mut handles = vec![(reiterate.into_iter(), 0, Observed::new())];
+        let mut expected: Vec<u32> = (0..SOURCE_LEN).collect();
+
+        for _ in 0..1000 {
+            let pick = rng.next_u32() as usize % handles.len();
+            let (handle, pos, observed) = &mut handles[pick];
+            match rng.next_u32() % 16 {
+                0 => handles.push((reiterate.into_iter(), 0, Observed::new())),
+                1 => {
+                    let start = expected.len() as u32;
+                    let len = rng.next_u32() % 20;
+                    reiterate.queue_source(
+                        (start..start + len).map(&counted as &dyn Fn(u32) -> Box<u32>),
+                    );
+                    expected.extend(start..start + len);
+                }
+                2..=4 => {
+                    let n = rng.next_u32() as usize % 8;
+                    if let Some(&item) = handle.nth(n) {
+                        observed.push((*pos + n, item));
+                    }
+                    *pos += n + 1;
+                }
+                _ => {
+                    if let Some(&item) = handle.next() {
+                        observed.push((*pos, item));
+                    }
+                    *pos += 1;
+                }
+            }
+        }
+
+        let cached: Vec<u32> = reiterate.into_iter().copied().collect();
+        assert_eq!(cached, expected, "seed {}", seed);
+        assert_eq!(pulls.get(), expected.len(), "seed {}", seed);
+        for (_, _, observed) in &handles {
+            for &(pos, item) in observed {
+                assert_eq!(cached[pos], item, "seed {}", seed);
+            }
+        }
+    }
+}
+
+#[test]
+fn random_interleavings_copy() {
+    for seed in schedule_seeds() {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let reiterate = CopyReiterate::new((0..SOURCE_LEN).collect::<Vec<_>>());
+        let mut handles = vec![(reiterate.into_iter(), 0, Observed::new())];
+        let mut expected: Vec<u32> = (0..SOURCE_LEN).collect();
+
+        for _ in 0..1000 {
+            let pick = rng.next_u32() as usize % handles.len();
+            let (handle, pos, observed) = &mut handles[pick];
+            match rng.next_u32() % 16 {
+                0 => handles.push((reiterate.into_iter(), 0, Observed::new())),
+                1 => {
+                    let start = expected.len() as u32;
+                    let len = rng.next_u32() % 20;
+                    reiterate.queue_source((start..start + len).collect::<Vec<_>>());
+                    expected.extend(start..start + len);
+                }
+                2..=4 => {
+                    let n = rng.next_u32() as usize % 8;
+                    if let Some(item) = handle.nth(n) {
+                        observed.push((*pos + n, item));
+                    }
+                    *pos += n + 1;
+                }
+                _ => {
+                    if let Some(item) = handle.next() {
+                        observed.push((*pos, item));
+                    }
+                    *pos += 1;
+                }
+            }
+        }
+
+        let cached = reiterate.into_iter().collect_vec();
+        assert_eq!(cached, expected, "seed {}", seed);
+        for (_, _, observed) in &handles {
+            for &(pos, item) in observed {
+                assert_eq!(cached[pos], item, "seed {}", seed);
+            }
+        }
+    }
+}
+
+type Page = Box<dyn Iterator<Item = Box<u32>>>;
+type Link = Rc<OnceCell<Weak<Reiterate<Page>>>>;
+
+/// A queued source that pulls through another handle while it is being pulled from, so
+/// that the item it returns is cached after the one that handle pulls
+#[test]
+fn reentrant_pull() {
+    let link = Link::default();
+    let reentrant: Page = {
+        let link = link.clone();
+        Box::new((0..2).map(move |i| {
+            if i == 0 {
+                let reiterate = link.get().unwrap().upgrade().unwrap();
+                assert_eq!(reiterate.iter_tail().next(), Some(&10));
+            }
+            Box::new(i)
+        }))
+    };
+    let reiterate = Rc::new(Reiterate::new(Box::new(std::iter::empty()) as Page));
+    link.set(Rc::downgrade(&reiterate)).unwrap();
+    reiterate.queue_source(reentrant);
+    reiterate.queue_source(Box::new((10..12u32).map(Box::new)) as Page);
+
+    let mut outer = reiterate.into_iter();
+    let first: Vec<u32> = outer.by_ref().take(2).copied().collect();
+    let all: Vec<u32> = reiterate.into_iter().copied().collect();
+    assert_eq!(first, all[..2]);
+    assert_eq!(outer.copied().collect::<Vec<_>>(), all[2..]);
+    assert_eq!(all.len(), 4);
+}
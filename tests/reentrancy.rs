@@ -0,0 +1,114 @@
+//! Read-only methods called from inside a pull, by a closure of the underlying iterator,
+//! neither panic nor pull, and give either an answer or `Contended`.
+
+use reiterate::{ContentionAware, CopyReiterate, Reiterate, SkipErrors, Timestamped};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::Instant;
+
+type Source<T> = Box<dyn Iterator<Item = T>>;
+
+/// Build a reiterate whose source calls `probe` on it before yielding each item
+fn probed<R, T>(
+    items: impl Iterator<Item = T> + 'static,
+    make: impl FnOnce(Source<T>) -> R,
+    probe: impl Fn(&R) + 'static,
+) -> Rc<R>
+where
+    R: 'static,
+    T: 'static,
+{
+    Rc::new_cyclic(|me: &Weak<R>| {
+        let me = me.clone();
+        make(Box::new(
+            items.inspect(move |_| probe(&me.upgrade().unwrap())),
+        ))
+    })
+}
+
+#[test]
+fn reiterate_read_only_during_pull() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = seen.clone();
+    let reiterate = probed((0..5u32).map(Box::new), Reiterate::new, move |r| {
+        assert!(!r.is_poisoned());
+        let debug = format!("{:?}", r);
+        assert!(debug.contains("pulling: true"), "{}", debug);
+        let handle = r.into_iter();
+        assert!(handle.size_hint().0 >= r.cached_len());
+        if let Some(last) = (r.cached_len() > 0).then(|| r.cached_len() - 1) {
+            let item = r.into_iter().nth(last).unwrap();
+            assert_eq!(r.index_of(item), Some(last));
+        }
+        assert_eq!(r.watch().poll_new().count(), 0);
+        assert!(!r.is_exhausted());
+        assert!(r.remaining_hint().is_contended());
+        log.borrow_mut()
+            .push((r.cached_len(), r.cache_size_bytes()));
+    });
+
+    let mut watcher = reiterate.watch();
+    assert_eq!(reiterate.into_iter().count(), 5);
+    let expected: Vec<_> = (0..5)
+        .map(|n| (n, n * std::mem::size_of::<Box<u32>>()))
+        .collect();
+    assert_eq!(*seen.borrow(), expected);
+    assert_eq!(watcher.poll_new().count(), 5);
+    assert!(reiterate.is_exhausted());
+    assert_eq!(
+        format!("{:?}", reiterate),
+        "Reiterate { cached: [0, 1, 2, 3, 4], exhausted: true, poisoned: false, pulling: false }"
+    );
+}
+
+#[test]
+fn copy_reiterate_read_only_during_pull() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = seen.clone();
+    let reiterate = probed(0..5u32, CopyReiterate::new, move |r| {
+        assert!(!r.is_poisoned());
+        assert_eq!(
+            format!("{:?}", r),
+            "CopyReiterate { cached: Contended, exhausted: Contended, poisoned: false }"
+        );
+        assert_eq!(r.cache_size_bytes(), ContentionAware::Contended);
+        assert_eq!(r.into_iter().size_hint(), (0, None));
+        assert!(r.is_exhausted().is_contended());
+        assert!(r.remaining_hint().is_contended());
+        // a watcher made during a pull starts at its first uncontended poll
+        assert_eq!(r.watch().poll_new().count(), 0);
+        log.borrow_mut().push(r.cached_len());
+    });
+
+    let mut watcher = reiterate.watch();
+    assert_eq!(reiterate.into_iter().count(), 5);
+    assert_eq!(*seen.borrow(), [ContentionAware::Contended; 5]);
+    assert_eq!(reiterate.cached_len(), ContentionAware::Ready(5));
+    assert_eq!(reiterate.cache_size_bytes(), ContentionAware::Ready(20));
+    assert_eq!(reiterate.is_exhausted(), ContentionAware::Ready(true));
+    assert_eq!(watcher.poll_new().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn source_adapters_read_only_during_pull() {
+    type Errors = Reiterate<SkipErrors<Source<Result<Box<u32>, &'static str>>, &'static str>>;
+    let results = vec![Ok(Box::new(1)), Err("bad"), Ok(Box::new(2))];
+    let reiterate = probed(results.into_iter(), Reiterate::skip_errors, |r: &Errors| {
+        assert!(r.errors().is_contended());
+        assert!(r.source_ended().is_contended());
+    });
+    assert_eq!(reiterate.into_iter().count(), 2);
+    assert_eq!(reiterate.errors().ready().unwrap().len(), 1);
+    assert_eq!(reiterate.source_ended().ready(), Some(true));
+
+    type Timed = Reiterate<Timestamped<Source<Box<u32>>>>;
+    let reiterate = probed(
+        (0..3).map(Box::new),
+        Reiterate::with_timestamps,
+        |r: &Timed| {
+            assert!(r.pulled_at(0).is_contended());
+        },
+    );
+    assert_eq!(reiterate.into_iter().count(), 3);
+    assert!(reiterate.pulled_at(2).ready().flatten() <= Some(Instant::now()));
+}
@@ -0,0 +1,61 @@
+//! Replacing the underlying iterator once the stream has ended, one way or another,
+//! carries on with the new one.
+
+use reiterate::{Closable, CopyReiterate, Reiterate};
+
+#[test]
+fn replace_after_exhaust() {
+    let mut reiterate = Reiterate::new(vec![Box::new(1), Box::new(2)]);
+    reiterate.exhaust();
+    assert!(reiterate.is_exhausted());
+    reiterate.replace_inner(vec![Box::new(3)]);
+    assert!(!reiterate.is_exhausted());
+    assert_eq!(
+        reiterate.into_iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+
+    let mut copy = CopyReiterate::new(vec![1, 2]);
+    copy.exhaust();
+    copy.replace_inner(vec![3]);
+    assert_eq!(copy.is_exhausted().ready(), Some(false));
+    assert_eq!(copy.into_iter().collect_vec(), [1, 2, 3]);
+}
+
+#[test]
+fn replace_after_truncate() {
+    let mut reiterate = Reiterate::new((1..4).map(Box::new));
+    reiterate.exhaust();
+    reiterate.truncate(1);
+    let old = reiterate.replace_inner((5..6).map(Box::new));
+    assert_eq!(old.count(), 0);
+    assert_eq!(reiterate.into_iter().copied().collect::<Vec<_>>(), [1, 5]);
+    assert_eq!(reiterate.cached_len(), 2);
+
+    let mut copy = CopyReiterate::new(1..4);
+    copy.exhaust();
+    copy.truncate(1);
+    copy.replace_inner(5..6);
+    assert_eq!(copy.into_iter().collect_vec(), [1, 5]);
+    assert_eq!(copy.cached_len().ready(), Some(2));
+}
+
+#[test]
+fn replace_after_close_source() {
+    let mut reiterate = Reiterate::closable((1..4).map(Box::new));
+    reiterate.into_iter().next();
+    reiterate.close_source();
+    assert!(reiterate.is_exhausted());
+    reiterate.replace_inner(Closable::new((7..9).map(Box::new)));
+    assert!(!reiterate.is_closed());
+    assert_eq!(
+        reiterate.into_iter().copied().collect::<Vec<_>>(),
+        [1, 7, 8]
+    );
+
+    let mut copy = CopyReiterate::closable(1..4);
+    copy.into_iter().next();
+    copy.close_source();
+    copy.replace_inner(Closable::new(7..9));
+    assert_eq!(copy.into_iter().collect_vec(), [1, 7, 8]);
+}
@@ -0,0 +1,5 @@
+use reiterate::Reiterate;
+
+fn main() {
+    let _reiterate = Reiterate::new(vec![1, 2, 3]);
+}
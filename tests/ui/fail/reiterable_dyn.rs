@@ -0,0 +1,6 @@
+use reiterate::{Reiterable, Reiterate};
+
+fn main() {
+    let reiterate = Reiterate::new(vec![Box::new(1)]);
+    let _erased: &dyn Reiterable = &reiterate;
+}
@@ -0,0 +1,48 @@
+use reiterate::{CopyReiterate, Reiterate, Reiterator};
+use std::vec;
+
+#[derive(Debug, PartialEq)]
+struct Event(u32);
+
+// The concrete source type doesn't appear in the API
+pub struct Log {
+    events: Box<Reiterate<dyn Iterator<Item = Box<Event>>>>,
+    ids: Box<CopyReiterate<dyn Iterator<Item = u32>>>,
+}
+
+impl Log {
+    fn new(source: impl Iterator<Item = u32> + Clone + 'static) -> Self {
+        Log {
+            events: Reiterate::new(source.clone().map(|i| Box::new(Event(i)))).into(),
+            ids: CopyReiterate::new(source).into(),
+        }
+    }
+}
+
+// Generic code works with both forms
+fn count<I: Iterator<Item = Box<Event>> + ?Sized>(reiterate: &Reiterate<I>) -> usize {
+    reiterate.into_iter().count()
+}
+
+// Non-`'static` sources can be erased too
+fn borrowed(ids: &[u32]) -> Box<CopyReiterate<dyn Iterator<Item = u32> + '_>> {
+    CopyReiterate::new(ids.iter().copied()).into()
+}
+
+fn main() {
+    let log = Log::new(0..3);
+    for _ in 0..2 {
+        assert_eq!(log.events.into_iter().last(), Some(&Event(2)));
+        assert_eq!(log.ids.into_iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+    assert_eq!(count(&log.events), 3);
+
+    let ids = vec![4, 5];
+    assert_eq!(borrowed(&ids).into_iter().sum::<u32>(), 9);
+
+    // The sized form is unchanged, and its handles still call the source statically
+    let sized = Reiterate::new(vec![Box::new(Event(7))]);
+    let handle: Reiterator<'_, vec::IntoIter<Box<Event>>> = sized.into_iter();
+    assert_eq!(handle.count(), 1);
+    assert_eq!(count(&sized), 1);
+}
@@ -0,0 +1,46 @@
+use reiterate::{CopyReiterate, CopyReiterator, Reiterate, Reiterator};
+use std::marker::PhantomData;
+
+// Wrappers only need `I: Iterator`: the structs store `I::Item`, and a field type can
+// only name that projection when the bound is declared, so it can't be dropped too
+struct Holder<I: Iterator, J: Iterator> {
+    reiterate: Reiterate<I>,
+    copy: CopyReiterate<J>,
+}
+
+struct Handles<'a, I: Iterator, J: Iterator> {
+    reiterator: Reiterator<'a, I>,
+    copy: CopyReiterator<'a, J>,
+}
+
+// The item bounds only come in when the adaptors are used
+struct Later<I: Iterator> {
+    _reiterate: PhantomData<Reiterate<I>>,
+    _copy: PhantomData<CopyReiterate<I>>,
+}
+
+impl<I: Iterator> Later<I> {
+    fn new() -> Self {
+        Later {
+            _reiterate: PhantomData,
+            _copy: PhantomData,
+        }
+    }
+}
+
+fn main() {
+    let holder = Holder {
+        reiterate: Reiterate::new(vec![Box::new(1), Box::new(2)]),
+        copy: CopyReiterate::new(vec![1, 2, 3]),
+    };
+    let handles = Handles {
+        reiterator: holder.reiterate.into_iter(),
+        copy: holder.copy.into_iter(),
+    };
+    assert_eq!(handles.reiterator.count(), 2);
+    assert_eq!(handles.copy.count(), 3);
+
+    // `i32` isn't `StableDeref` and `String` isn't `Copy`, but naming these types is fine
+    let _later: Later<std::vec::IntoIter<i32>> = Later::new();
+    let _later: Later<std::vec::IntoIter<String>> = Later::new();
+}